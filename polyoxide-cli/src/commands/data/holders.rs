@@ -1,14 +1,15 @@
 use clap::Args;
 use color_eyre::eyre::Result;
-use polyoxide_data::DataApi;
-
-use crate::commands::common::parsing::parse_comma_separated;
+use polyoxide_data::{
+    api::holders::{MarketHolders, WhaleImbalance},
+    DataApi,
+};
 
 /// Get top holders for markets
 #[derive(Args)]
 pub struct HoldersCommand {
     /// Market condition IDs (comma-separated, required)
-    #[arg(short, long, value_parser = parse_comma_separated)]
+    #[arg(short, long, value_delimiter = ',')]
     market: Vec<String>,
     /// Maximum number of holders per market between 0 and 500
     #[arg(short, long, default_value = "100")]
@@ -16,6 +17,15 @@ pub struct HoldersCommand {
     /// Minimum balance filter between 0 and 999999
     #[arg(long, default_value = "1")]
     min_balance: u32,
+    /// Add concentration stats per token (and whale imbalance for a single market)
+    #[arg(long)]
+    stats: bool,
+    /// Minimum amount for a holder to count as a whale
+    #[arg(long, default_value = "10000")]
+    whale_amount: f64,
+    /// Look up each holder's total position value and flag those above this USD value
+    #[arg(long)]
+    large_exposure: Option<f64>,
 }
 
 impl HoldersCommand {
@@ -28,7 +38,73 @@ impl HoldersCommand {
             .min_balance(self.min_balance);
 
         let holders = request.send().await?;
-        println!("{}", serde_json::to_string_pretty(&holders)?);
+        if !self.stats && self.large_exposure.is_none() {
+            println!("{}", serde_json::to_string_pretty(&holders)?);
+            return Ok(());
+        }
+
+        let mut output = serde_json::json!({ "holders": holders });
+        if self.stats {
+            let concentration: Vec<_> = holders.iter().map(MarketHolders::concentration).collect();
+            output["concentration"] = serde_json::to_value(concentration)?;
+            // Tokens carry no market ID, so sides can only be paired for one market
+            if self.market.len() == 1 {
+                let imbalance = WhaleImbalance::from_holders(&holders, self.whale_amount);
+                output["whale_imbalance"] = serde_json::json!({
+                    "yes_whales": imbalance.yes_whales,
+                    "no_whales": imbalance.no_whales,
+                    "yes_amount": imbalance.yes_amount,
+                    "no_amount": imbalance.no_amount,
+                    "imbalance": imbalance.imbalance(),
+                });
+            }
+        }
+        if let Some(threshold) = self.large_exposure {
+            let exposure = data.holders().exposure(&holders, threshold).await?;
+            output["exposure"] = serde_json::to_value(exposure)?;
+        }
+
+        println!("{}", serde_json::to_string_pretty(&output)?);
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+
+    #[derive(Parser)]
+    struct TestHolders {
+        #[command(flatten)]
+        cmd: HoldersCommand,
+    }
+
+    #[test]
+    fn holders_analytics_defaults() {
+        let parsed = TestHolders::try_parse_from(["test", "--market", "0xabc"]).unwrap();
+        assert!(!parsed.cmd.stats);
+        assert_eq!(parsed.cmd.whale_amount, 10_000.0);
+        assert!(parsed.cmd.large_exposure.is_none());
+    }
+
+    #[test]
+    fn holders_analytics_options() {
+        let parsed = TestHolders::try_parse_from([
+            "test",
+            "--market",
+            "0xabc,0xdef",
+            "--stats",
+            "--whale-amount",
+            "500",
+            "--large-exposure",
+            "250000",
+        ])
+        .unwrap();
+        assert_eq!(parsed.cmd.market, vec!["0xabc", "0xdef"]);
+        assert!(parsed.cmd.stats);
+        assert_eq!(parsed.cmd.whale_amount, 500.0);
+        assert_eq!(parsed.cmd.large_exposure, Some(250_000.0));
+    }
+}
@@ -0,0 +1,181 @@
+use clap::{Args, ValueEnum};
+use color_eyre::eyre::Result;
+use polyoxide_data::{
+    api::leaderboard::{LeaderboardCategory, LeaderboardOrderBy},
+    DataApi,
+};
+
+use crate::commands::data::builders::CliTimePeriod;
+
+/// Get the trader leaderboard, or one trader's rank
+#[derive(Args)]
+pub struct LeaderboardCommand {
+    /// Time period for aggregation
+    #[arg(short, long, default_value = "day")]
+    pub time_period: CliTimePeriod,
+    /// Market category
+    #[arg(short, long, value_enum, default_value = "overall")]
+    pub category: CliLeaderboardCategory,
+    /// Ranking metric
+    #[arg(long, value_enum, default_value = "pnl")]
+    pub order_by: CliLeaderboardOrderBy,
+    /// Look up the rank of a single trader address
+    #[arg(short, long)]
+    pub user: Option<String>,
+    /// Maximum number of results (1-50)
+    #[arg(short, long, default_value = "25")]
+    pub limit: u32,
+    /// Pagination offset (0-1000)
+    #[arg(short, long, default_value = "0")]
+    pub offset: u32,
+}
+
+impl LeaderboardCommand {
+    pub async fn run(self, data: &DataApi) -> Result<()> {
+        if let Some(user) = self.user {
+            let rank = data
+                .leaderboard()
+                .user(user)
+                .time_period(self.time_period.into())
+                .category(self.category.into())
+                .order_by(self.order_by.into())
+                .send()
+                .await?;
+            println!("{}", serde_json::to_string_pretty(&rank)?);
+            return Ok(());
+        }
+
+        let traders = data
+            .leaderboard()
+            .traders()
+            .time_period(self.time_period.into())
+            .category(self.category.into())
+            .order_by(self.order_by.into())
+            .limit(self.limit)
+            .offset(self.offset)
+            .send()
+            .await?;
+        println!("{}", serde_json::to_string_pretty(&traders)?);
+        Ok(())
+    }
+}
+
+/// Leaderboard market category
+#[derive(Debug, Clone, Copy, ValueEnum, Default)]
+pub enum CliLeaderboardCategory {
+    /// All markets
+    #[default]
+    Overall,
+    Politics,
+    Sports,
+    Crypto,
+    Culture,
+    Mentions,
+    Weather,
+    Economics,
+    Tech,
+    Finance,
+}
+
+impl From<CliLeaderboardCategory> for LeaderboardCategory {
+    fn from(category: CliLeaderboardCategory) -> Self {
+        match category {
+            CliLeaderboardCategory::Overall => Self::Overall,
+            CliLeaderboardCategory::Politics => Self::Politics,
+            CliLeaderboardCategory::Sports => Self::Sports,
+            CliLeaderboardCategory::Crypto => Self::Crypto,
+            CliLeaderboardCategory::Culture => Self::Culture,
+            CliLeaderboardCategory::Mentions => Self::Mentions,
+            CliLeaderboardCategory::Weather => Self::Weather,
+            CliLeaderboardCategory::Economics => Self::Economics,
+            CliLeaderboardCategory::Tech => Self::Tech,
+            CliLeaderboardCategory::Finance => Self::Finance,
+        }
+    }
+}
+
+/// Leaderboard ranking metric
+#[derive(Debug, Clone, Copy, ValueEnum, Default)]
+pub enum CliLeaderboardOrderBy {
+    /// Profit and loss
+    #[default]
+    Pnl,
+    /// Trading volume
+    Vol,
+}
+
+impl From<CliLeaderboardOrderBy> for LeaderboardOrderBy {
+    fn from(order_by: CliLeaderboardOrderBy) -> Self {
+        match order_by {
+            CliLeaderboardOrderBy::Pnl => Self::Pnl,
+            CliLeaderboardOrderBy::Vol => Self::Vol,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+
+    #[derive(Parser)]
+    struct TestLeaderboard {
+        #[command(flatten)]
+        cmd: LeaderboardCommand,
+    }
+
+    #[test]
+    fn leaderboard_defaults() {
+        let parsed = TestLeaderboard::try_parse_from(["test"]).unwrap();
+        assert!(matches!(parsed.cmd.time_period, CliTimePeriod::Day));
+        assert!(matches!(
+            parsed.cmd.category,
+            CliLeaderboardCategory::Overall
+        ));
+        assert!(matches!(parsed.cmd.order_by, CliLeaderboardOrderBy::Pnl));
+        assert!(parsed.cmd.user.is_none());
+        assert_eq!(parsed.cmd.limit, 25);
+        assert_eq!(parsed.cmd.offset, 0);
+    }
+
+    #[test]
+    fn leaderboard_all_options() {
+        let parsed = TestLeaderboard::try_parse_from([
+            "test",
+            "--time-period",
+            "month",
+            "--category",
+            "crypto",
+            "--order-by",
+            "vol",
+            "--user",
+            "0xabc",
+        ])
+        .unwrap();
+        assert!(matches!(parsed.cmd.time_period, CliTimePeriod::Month));
+        assert!(matches!(
+            parsed.cmd.category,
+            CliLeaderboardCategory::Crypto
+        ));
+        assert!(matches!(parsed.cmd.order_by, CliLeaderboardOrderBy::Vol));
+        assert_eq!(parsed.cmd.user.as_deref(), Some("0xabc"));
+    }
+
+    #[test]
+    fn leaderboard_invalid_category_errors() {
+        assert!(TestLeaderboard::try_parse_from(["test", "--category", "memes"]).is_err());
+    }
+
+    #[test]
+    fn category_from_conversions() {
+        assert!(matches!(
+            LeaderboardCategory::from(CliLeaderboardCategory::Politics),
+            LeaderboardCategory::Politics
+        ));
+        assert!(matches!(
+            LeaderboardOrderBy::from(CliLeaderboardOrderBy::Vol),
+            LeaderboardOrderBy::Vol
+        ));
+    }
+}
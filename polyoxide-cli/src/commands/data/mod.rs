@@ -1,6 +1,7 @@
 mod activity;
 mod builders;
 mod holders;
+mod leaderboard;
 mod live_volume;
 mod open_interest;
 mod positions;
@@ -12,8 +13,9 @@ use color_eyre::eyre::Result;
 use polyoxide_data::DataApi;
 
 use crate::commands::data::{
-    activity::UserActivityCommand, holders::HoldersCommand, live_volume::LiveVolumeCommand,
-    open_interest::OpenInterestCommand, positions::PositionsCommand, traded::TradedCommand,
+    activity::UserActivityCommand, holders::HoldersCommand, leaderboard::LeaderboardCommand,
+    live_volume::LiveVolumeCommand, open_interest::OpenInterestCommand,
+    positions::PositionsCommand, traded::TradedCommand,
 };
 
 #[derive(Subcommand)]
@@ -29,6 +31,8 @@ pub enum DataCommand {
     },
     /// Query top holders for markets
     Holders(HoldersCommand),
+    /// Query the trader leaderboard
+    Leaderboard(LeaderboardCommand),
     /// Query trades
     Trades {
         #[command(subcommand)]
@@ -57,6 +61,7 @@ impl DataCommand {
             Self::Activity(cmd) => cmd.run(&data).await,
             Self::Builders { command } => command.run(&data).await,
             Self::Holders(cmd) => cmd.run(&data).await,
+            Self::Leaderboard(cmd) => cmd.run(&data).await,
             Self::Trades { command } => command.run(&data).await,
             Self::Traded(cmd) => cmd.run(&data).await,
             Self::Positions(cmd) => cmd.run(&data).await,
@@ -199,6 +204,12 @@ mod tests {
         assert!(matches!(cmd, DataCommand::Builders { .. }));
     }
 
+    #[test]
+    fn leaderboard_parses() {
+        let cmd = try_parse(&["test", "leaderboard", "--order-by", "vol"]).unwrap();
+        assert!(matches!(cmd, DataCommand::Leaderboard(_)));
+    }
+
     #[test]
     fn builders_volume_parses() {
         let cmd = try_parse(&["test", "builders", "volume"]).unwrap();
@@ -1,6 +1,7 @@
 mod comments;
 mod events;
 mod markets;
+mod search;
 mod series;
 mod sports;
 mod tags;
@@ -41,6 +42,8 @@ pub enum GammaCommand {
         #[command(subcommand)]
         command: comments::CommentsCommand,
     },
+    /// Search events, markets, tags and profiles
+    Search(search::SearchCommand),
 }
 
 impl GammaCommand {
@@ -54,6 +57,7 @@ impl GammaCommand {
             Self::Series { command } => command.run(&gamma).await,
             Self::Sports { command } => command.run(&gamma).await,
             Self::Comments { command } => command.run(&gamma).await,
+            Self::Search(cmd) => cmd.run(&gamma).await,
         }
     }
 }
@@ -0,0 +1,135 @@
+use clap::{Args, ValueEnum};
+use color_eyre::eyre::Result;
+use polyoxide_gamma::{api::search::SearchStatus, Gamma};
+
+use crate::commands::gamma::SortOrder;
+
+/// Event status filter for search
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum SearchStatusFilter {
+    /// Only active events
+    Active,
+    /// Only closed events
+    Closed,
+}
+
+impl From<SearchStatusFilter> for SearchStatus {
+    fn from(status: SearchStatusFilter) -> Self {
+        match status {
+            SearchStatusFilter::Active => Self::Active,
+            SearchStatusFilter::Closed => Self::Closed,
+        }
+    }
+}
+
+#[derive(Args)]
+pub struct SearchCommand {
+    /// Search query
+    pub query: String,
+    /// Maximum number of results per type
+    #[arg(short, long, default_value = "10")]
+    limit: u32,
+    /// Results page (1-based)
+    #[arg(short, long, default_value = "1")]
+    page: u32,
+    /// Filter events by status
+    #[arg(short, long, value_enum)]
+    status: Option<SearchStatusFilter>,
+    /// Filter events by tag slugs (comma-separated)
+    #[arg(short, long, value_delimiter = ',')]
+    tag: Vec<String>,
+    /// Include matching tags
+    #[arg(long)]
+    tags: bool,
+    /// Include matching user profiles
+    #[arg(long)]
+    profiles: bool,
+    /// Sort field (e.g. volume, liquidity, startDate, endDate)
+    #[arg(long)]
+    order: Option<String>,
+    /// Sort order
+    #[arg(long, value_enum, default_value = "desc")]
+    sort: SortOrder,
+}
+
+impl SearchCommand {
+    pub async fn run(self, gamma: &Gamma) -> Result<()> {
+        let mut request = gamma
+            .search()
+            .query(self.query)
+            .limit_per_type(self.limit)
+            .page(self.page)
+            .search_tags(self.tags)
+            .search_profiles(self.profiles)
+            .ascending(matches!(self.sort, SortOrder::Asc));
+
+        if let Some(status) = self.status {
+            request = request.events_status(status.into());
+        }
+        if !self.tag.is_empty() {
+            request = request.events_tag(self.tag);
+        }
+        if let Some(order) = self.order {
+            request = request.sort(order);
+        }
+
+        let results = request.send().await?;
+        println!("{}", serde_json::to_string_pretty(&results)?);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser)]
+    struct Wrapper {
+        #[command(flatten)]
+        cmd: SearchCommand,
+    }
+
+    fn try_parse(args: &[&str]) -> Result<SearchCommand, clap::Error> {
+        Wrapper::try_parse_from(args).map(|w| w.cmd)
+    }
+
+    #[test]
+    fn search_defaults() {
+        let cmd = try_parse(&["test", "bitcoin"]).unwrap();
+        assert_eq!(cmd.query, "bitcoin");
+        assert_eq!(cmd.limit, 10);
+        assert_eq!(cmd.page, 1);
+        assert!(cmd.status.is_none());
+        assert!(cmd.tag.is_empty());
+        assert!(!cmd.tags && !cmd.profiles);
+    }
+
+    #[test]
+    fn search_requires_query() {
+        assert!(try_parse(&["test"]).is_err());
+    }
+
+    #[test]
+    fn search_all_options() {
+        let cmd = try_parse(&[
+            "test",
+            "election",
+            "--limit",
+            "5",
+            "--status",
+            "closed",
+            "--tag",
+            "politics,us",
+            "--tags",
+            "--profiles",
+            "--order",
+            "volume",
+        ])
+        .unwrap();
+        assert!(matches!(cmd.status, Some(SearchStatusFilter::Closed)));
+        assert_eq!(cmd.tag, vec!["politics", "us"]);
+        assert!(cmd.tags && cmd.profiles);
+        assert_eq!(cmd.order.as_deref(), Some("volume"));
+    }
+}
@@ -1,8 +1,12 @@
-use polyoxide_core::{HttpClient, QueryBuilder};
+use std::collections::HashMap;
+use std::time::Duration;
+
+use polyoxide_core::{Candle, HttpClient, QueryBuilder, Tick};
 use rust_decimal::Decimal;
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 use crate::{
+    error::ClobError,
     request::{AuthMode, Request},
     types::OrderSide,
 };
@@ -59,6 +63,70 @@ impl Markets {
         .query("token_id", token_id.into())
     }
 
+    /// Get order books for several tokens, [`BATCH_LIMIT`] per request
+    pub fn order_books(
+        &self,
+        token_ids: impl IntoIterator<Item = impl Into<String>>,
+    ) -> BatchRequest<ByToken<OrderBook>> {
+        self.batch("/books", token_params(token_ids))
+    }
+
+    /// Get best prices for several token/side pairs, [`BATCH_LIMIT`] per request
+    ///
+    /// The response maps each token ID to its price on every requested side.
+    pub fn prices(
+        &self,
+        tokens: impl IntoIterator<Item = (impl Into<String>, OrderSide)>,
+    ) -> BatchRequest<HashMap<String, HashMap<OrderSide, Decimal>>> {
+        let params = tokens
+            .into_iter()
+            .map(|(id, side)| serde_json::json!({ "token_id": id.into(), "side": side.as_str() }))
+            .collect();
+        self.batch("/prices", params)
+    }
+
+    /// Get midpoint prices for several tokens, [`BATCH_LIMIT`] per request
+    pub fn midpoints(
+        &self,
+        token_ids: impl IntoIterator<Item = impl Into<String>>,
+    ) -> BatchRequest<HashMap<String, Decimal>> {
+        self.batch("/midpoints", token_params(token_ids))
+    }
+
+    /// Get bid-ask spreads for several tokens, [`BATCH_LIMIT`] per request
+    pub fn spreads(
+        &self,
+        token_ids: impl IntoIterator<Item = impl Into<String>>,
+    ) -> BatchRequest<HashMap<String, Decimal>> {
+        self.batch("/spreads", token_params(token_ids))
+    }
+
+    /// Get the last trade price and side for several tokens, [`BATCH_LIMIT`]
+    /// per request
+    pub fn last_trade_prices(
+        &self,
+        token_ids: impl IntoIterator<Item = impl Into<String>>,
+    ) -> BatchRequest<ByToken<LastTradePrice>> {
+        self.batch("/last-trades-prices", token_params(token_ids))
+    }
+
+    fn batch<T>(&self, path: &str, params: Vec<serde_json::Value>) -> BatchRequest<T> {
+        let requests = params
+            .chunks(BATCH_LIMIT)
+            .map(|chunk| {
+                let mut request = Request::post(
+                    self.http_client.clone(),
+                    path.to_string(),
+                    AuthMode::None,
+                    self.chain_id,
+                );
+                request.body = Some(serde_json::Value::Array(chunk.to_vec()));
+                request
+            })
+            .collect();
+        BatchRequest { requests }
+    }
+
     /// Get price for a token and side
     pub fn price(&self, token_id: impl Into<String>, side: OrderSide) -> Request<PriceResponse> {
         Request::get(
@@ -83,6 +151,10 @@ impl Markets {
     }
 
     /// Get historical prices for a token
+    ///
+    /// Narrow the series with [`Request::interval`] or
+    /// [`Request::start_ts`]/[`Request::end_ts`], and set its sampling with
+    /// [`Request::fidelity`].
     pub fn prices_history(&self, token_id: impl Into<String>) -> Request<PricesHistoryResponse> {
         Request::get(
             self.http_client.clone(),
@@ -173,6 +245,137 @@ pub struct OrderLevel {
     pub size: Decimal,
 }
 
+/// Body entries of a batch request that only takes token IDs
+fn token_params(token_ids: impl IntoIterator<Item = impl Into<String>>) -> Vec<serde_json::Value> {
+    token_ids
+        .into_iter()
+        .map(|id| serde_json::json!({ "token_id": id.into() }))
+        .collect()
+}
+
+/// A batch response entry that identifies its token
+pub trait TokenKeyed {
+    /// Token ID the entry belongs to
+    fn token_id(&self) -> &str;
+}
+
+/// Most tokens the CLOB accepts in one batch request
+pub const BATCH_LIMIT: usize = 500;
+
+/// Request to a batch endpoint, split into requests of at most
+/// [`BATCH_LIMIT`] tokens whose responses are merged
+pub struct BatchRequest<T> {
+    requests: Vec<Request<T>>,
+}
+
+impl<T: BatchResponse> BatchRequest<T> {
+    /// Send each chunk in turn and merge the responses; an empty batch sends
+    /// nothing
+    pub async fn send(self) -> Result<T, ClobError> {
+        let mut merged = T::default();
+        for request in self.requests {
+            merged.merge(request.send().await?);
+        }
+        Ok(merged)
+    }
+}
+
+/// Response of a batch endpoint, merged across chunked requests
+pub trait BatchResponse: DeserializeOwned + Default {
+    /// Add the entries of another chunk's response
+    fn merge(&mut self, other: Self);
+}
+
+impl<T: DeserializeOwned + TokenKeyed> BatchResponse for ByToken<T> {
+    fn merge(&mut self, other: Self) {
+        self.0.extend(other.0);
+    }
+}
+
+impl BatchResponse for HashMap<String, Decimal> {
+    fn merge(&mut self, other: Self) {
+        self.extend(other);
+    }
+}
+
+impl BatchResponse for HashMap<String, HashMap<OrderSide, Decimal>> {
+    fn merge(&mut self, other: Self) {
+        // A token's sides may be split across chunks
+        for (token_id, sides) in other {
+            self.entry(token_id).or_default().extend(sides);
+        }
+    }
+}
+
+/// Batch response keyed by token ID, for endpoints that return a list
+#[derive(Debug, Clone, PartialEq)]
+pub struct ByToken<T>(pub HashMap<String, T>);
+
+impl<T> Default for ByToken<T> {
+    fn default() -> Self {
+        Self(HashMap::new())
+    }
+}
+
+impl<T> ByToken<T> {
+    /// Unwrap into the underlying map
+    pub fn into_inner(self) -> HashMap<String, T> {
+        self.0
+    }
+}
+
+impl<T> std::ops::Deref for ByToken<T> {
+    type Target = HashMap<String, T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> IntoIterator for ByToken<T> {
+    type Item = (String, T);
+    type IntoIter = std::collections::hash_map::IntoIter<String, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'de, T> Deserialize<'de> for ByToken<T>
+where
+    T: Deserialize<'de> + TokenKeyed,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let entries = Vec::<T>::deserialize(deserializer)?;
+        Ok(Self(
+            entries
+                .into_iter()
+                .map(|entry| (entry.token_id().to_string(), entry))
+                .collect(),
+        ))
+    }
+}
+
+impl<T: Serialize> Serialize for ByToken<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.values())
+    }
+}
+
+/// Last trade of a token, as returned by the batch last-trades-prices endpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LastTradePrice {
+    pub token_id: String,
+    pub price: Decimal,
+    pub side: OrderSide,
+}
+
+impl TokenKeyed for LastTradePrice {
+    fn token_id(&self) -> &str {
+        &self.token_id
+    }
+}
+
 /// Order book data
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrderBook {
@@ -184,6 +387,12 @@ pub struct OrderBook {
     pub hash: String,
 }
 
+impl TokenKeyed for OrderBook {
+    fn token_id(&self) -> &str {
+        &self.asset_id
+    }
+}
+
 /// Price response
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PriceResponse {
@@ -207,12 +416,90 @@ pub struct PriceHistoryPoint {
     pub price: f64,
 }
 
+impl From<&PriceHistoryPoint> for Tick {
+    fn from(point: &PriceHistoryPoint) -> Self {
+        Self {
+            timestamp: point.timestamp,
+            price: point.price,
+            volume: 0.0,
+        }
+    }
+}
+
 /// Response from the prices-history endpoint
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PricesHistoryResponse {
     pub history: Vec<PriceHistoryPoint>,
 }
 
+impl PricesHistoryResponse {
+    /// Aggregate the series into OHLC bars of `resolution`
+    ///
+    /// Price history carries no volume, so every bar has zero volume; use
+    /// Data API trades for volume-weighted bars.
+    pub fn candles(&self, resolution: Duration) -> Vec<Candle> {
+        polyoxide_core::candles(self.history.iter().map(Tick::from), resolution)
+    }
+}
+
+/// Time span of a prices-history request, ending now
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PriceHistoryInterval {
+    /// Last minute
+    #[serde(rename = "1m")]
+    OneMinute,
+    /// Last hour
+    #[serde(rename = "1h")]
+    OneHour,
+    /// Last six hours
+    #[serde(rename = "6h")]
+    SixHours,
+    /// Last day
+    #[serde(rename = "1d")]
+    OneDay,
+    /// Last week
+    #[serde(rename = "1w")]
+    OneWeek,
+    /// Entire history
+    #[serde(rename = "max")]
+    Max,
+}
+
+impl std::fmt::Display for PriceHistoryInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::OneMinute => write!(f, "1m"),
+            Self::OneHour => write!(f, "1h"),
+            Self::SixHours => write!(f, "6h"),
+            Self::OneDay => write!(f, "1d"),
+            Self::OneWeek => write!(f, "1w"),
+            Self::Max => write!(f, "max"),
+        }
+    }
+}
+
+impl Request<PricesHistoryResponse> {
+    /// Fetch the given span ending now (mutually exclusive with `start_ts`/`end_ts`)
+    pub fn interval(self, interval: PriceHistoryInterval) -> Self {
+        self.query("interval", interval)
+    }
+
+    /// Start of the series, as a Unix timestamp (seconds)
+    pub fn start_ts(self, timestamp: i64) -> Self {
+        self.query("startTs", timestamp)
+    }
+
+    /// End of the series, as a Unix timestamp (seconds)
+    pub fn end_ts(self, timestamp: i64) -> Self {
+        self.query("endTs", timestamp)
+    }
+
+    /// Resolution of the series, in minutes
+    pub fn fidelity(self, minutes: u32) -> Self {
+        self.query("fidelity", minutes)
+    }
+}
+
 /// Response from the neg-risk endpoint
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NegRiskResponse {
@@ -250,6 +537,183 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Clob;
+
+    #[test]
+    fn test_prices_history_query_params() {
+        let clob = Clob::public();
+        let request = clob
+            .markets()
+            .prices_history("111")
+            .interval(PriceHistoryInterval::OneWeek)
+            .start_ts(1_700_000_000)
+            .end_ts(1_700_086_400)
+            .fidelity(60);
+        assert_eq!(request.path, "/prices-history");
+        assert_eq!(
+            request.query,
+            vec![
+                ("market".to_string(), "111".to_string()),
+                ("interval".to_string(), "1w".to_string()),
+                ("startTs".to_string(), "1700000000".to_string()),
+                ("endTs".to_string(), "1700086400".to_string()),
+                ("fidelity".to_string(), "60".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_price_history_interval_display_matches_serde() {
+        for interval in [
+            PriceHistoryInterval::OneMinute,
+            PriceHistoryInterval::OneHour,
+            PriceHistoryInterval::SixHours,
+            PriceHistoryInterval::OneDay,
+            PriceHistoryInterval::OneWeek,
+            PriceHistoryInterval::Max,
+        ] {
+            let serialized = serde_json::to_value(interval).unwrap();
+            assert_eq!(format!("\"{}\"", interval), serialized.to_string());
+        }
+    }
+
+    #[test]
+    fn test_prices_history_candles() {
+        let response: PricesHistoryResponse = serde_json::from_str(
+            r#"{"history": [{"t": 0, "p": 0.5}, {"t": 1800, "p": 0.6}, {"t": 3600, "p": 0.55}]}"#,
+        )
+        .unwrap();
+        let bars = response.candles(Duration::from_secs(3600));
+        assert_eq!(bars.len(), 2);
+        assert_eq!(bars[0].open, 0.5);
+        assert_eq!(bars[0].close, 0.6);
+        assert_eq!(bars[0].high, 0.6);
+        assert_eq!(bars[0].volume, 0.0);
+        assert_eq!(bars[1].start, 3600);
+    }
+
+    // ── Batch endpoints ─────────────────────────────────────────
+
+    #[test]
+    fn test_order_books_request_body() {
+        let clob = Clob::public();
+        let batch = clob.markets().order_books(["111", "222"]);
+        let request = &batch.requests[0];
+        assert_eq!(batch.requests.len(), 1);
+        assert_eq!(request.path, "/books");
+        assert_eq!(request.method, reqwest::Method::POST);
+        assert_eq!(
+            request.body,
+            Some(serde_json::json!([{"token_id": "111"}, {"token_id": "222"}]))
+        );
+    }
+
+    #[test]
+    fn test_batch_request_bodies() {
+        let markets = Clob::public().markets();
+
+        let batch = markets.prices([("111", OrderSide::Buy), ("222", OrderSide::Sell)]);
+        let request = &batch.requests[0];
+        assert_eq!(request.path, "/prices");
+        assert_eq!(request.method, reqwest::Method::POST);
+        assert_eq!(
+            request.body,
+            Some(serde_json::json!([
+                {"token_id": "111", "side": "BUY"},
+                {"token_id": "222", "side": "SELL"}
+            ]))
+        );
+
+        let tokens = || ["111"];
+        let expected = Some(serde_json::json!([{"token_id": "111"}]));
+        let midpoints = markets.midpoints(tokens());
+        assert_eq!(midpoints.requests[0].path, "/midpoints");
+        assert_eq!(midpoints.requests[0].body, expected);
+        let spreads = markets.spreads(tokens());
+        assert_eq!(spreads.requests[0].path, "/spreads");
+        assert_eq!(spreads.requests[0].body, expected);
+        let last_trades = markets.last_trade_prices(tokens());
+        assert_eq!(last_trades.requests[0].path, "/last-trades-prices");
+        assert_eq!(last_trades.requests[0].body, expected);
+    }
+
+    #[test]
+    fn test_batch_is_split_at_the_limit() {
+        let markets = Clob::public().markets();
+        let tokens: Vec<String> = (0..BATCH_LIMIT + 1).map(|i| i.to_string()).collect();
+        let batch = markets.midpoints(tokens);
+        let sizes: Vec<usize> = batch
+            .requests
+            .iter()
+            .map(|r| r.body.as_ref().unwrap().as_array().unwrap().len())
+            .collect();
+        assert_eq!(sizes, [BATCH_LIMIT, 1]);
+
+        let empty = markets.midpoints(Vec::<String>::new());
+        assert!(empty.requests.is_empty());
+    }
+
+    #[test]
+    fn test_batch_responses_merge_across_chunks() {
+        let mut prices: HashMap<String, HashMap<OrderSide, Decimal>> =
+            serde_json::from_str(r#"{"111": {"BUY": "0.52"}}"#).unwrap();
+        prices.merge(
+            serde_json::from_str(r#"{"111": {"SELL": "0.55"}, "222": {"BUY": "0.45"}}"#).unwrap(),
+        );
+        assert_eq!(prices["111"].len(), 2);
+        assert_eq!(prices["222"][&OrderSide::Buy], Decimal::new(45, 2));
+
+        let mut trades: ByToken<LastTradePrice> =
+            serde_json::from_str(r#"[{"token_id": "111", "price": "0.5", "side": "BUY"}]"#)
+                .unwrap();
+        trades.merge(
+            serde_json::from_str(r#"[{"token_id": "222", "price": "0.48", "side": "SELL"}]"#)
+                .unwrap(),
+        );
+        assert_eq!(trades.len(), 2);
+    }
+
+    #[test]
+    fn test_order_books_keyed_by_token() {
+        let json = r#"[
+            {"market": "0xabc", "asset_id": "111", "bids": [], "asks": [{"price": "0.55", "size": "10"}], "timestamp": "1", "hash": "h1"},
+            {"market": "0xabc", "asset_id": "222", "bids": [], "asks": [], "timestamp": "1", "hash": "h2"}
+        ]"#;
+        let books: ByToken<OrderBook> = serde_json::from_str(json).unwrap();
+        assert_eq!(books.len(), 2);
+        assert_eq!(books["111"].asks.len(), 1);
+        assert_eq!(books["222"].hash, "h2");
+    }
+
+    #[test]
+    fn test_prices_response_deserializes() {
+        let json = r#"{"111": {"BUY": "0.52", "SELL": "0.55"}, "222": {"BUY": "0.45"}}"#;
+        let prices: HashMap<String, HashMap<OrderSide, Decimal>> =
+            serde_json::from_str(json).unwrap();
+        assert_eq!(prices["111"][&OrderSide::Buy], Decimal::new(52, 2));
+        assert_eq!(prices["111"][&OrderSide::Sell], Decimal::new(55, 2));
+        assert!(!prices["222"].contains_key(&OrderSide::Sell));
+    }
+
+    #[test]
+    fn test_midpoints_and_spreads_deserialize() {
+        let json = r#"{"111": "0.535", "222": "0.01"}"#;
+        let values: HashMap<String, Decimal> = serde_json::from_str(json).unwrap();
+        assert_eq!(values["111"], Decimal::new(535, 3));
+        assert_eq!(values["222"], Decimal::new(1, 2));
+    }
+
+    #[test]
+    fn test_last_trade_prices_keyed_by_token() {
+        let json = r#"[
+            {"token_id": "111", "price": "0.5", "side": "BUY"},
+            {"token_id": "222", "price": "0.48", "side": "SELL"}
+        ]"#;
+        let trades: ByToken<LastTradePrice> = serde_json::from_str(json).unwrap();
+        assert_eq!(trades["111"].price, Decimal::new(5, 1));
+        assert_eq!(trades["222"].side, OrderSide::Sell);
+        assert_eq!(trades.into_inner().len(), 2);
+    }
 
     #[test]
     fn test_fee_rate_response_deserializes() {
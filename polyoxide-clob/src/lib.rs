@@ -65,13 +65,15 @@ pub use api::{
     account::{BalanceAllowanceResponse, Trade},
     health::Health,
     markets::{
-        ListMarketsResponse, Market, MarketToken, MidpointResponse, OrderBook, OrderLevel,
-        PriceResponse,
+        BatchRequest, BatchResponse, ByToken, LastTradePrice, ListMarketsResponse, Market,
+        MarketToken, MidpointResponse, OrderBook, OrderLevel, PriceHistoryInterval,
+        PriceHistoryPoint, PriceResponse, PricesHistoryResponse, BATCH_LIMIT,
     },
     orders::{CancelResponse, OpenOrder, OrderResponse},
 };
 pub use client::{Clob, ClobBuilder, CreateOrderParams};
 pub use error::ClobError;
+pub use polyoxide_core::{Candle, Tick};
 pub use types::{
     Order, OrderKind, OrderSide, ParseTickSizeError, PartialCreateOrderOptions, SignatureType,
     SignedOrder, TickSize,
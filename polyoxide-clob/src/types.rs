@@ -9,7 +9,7 @@ use thiserror::Error;
 #[error("invalid tick size: {0}. Valid values are 0.1, 0.01, 0.001, or 0.0001")]
 pub struct ParseTickSizeError(String);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum OrderSide {
     Buy,
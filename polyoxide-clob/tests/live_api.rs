@@ -9,7 +9,6 @@
 //! ```
 
 use polyoxide_clob::{Clob, OrderSide};
-use polyoxide_core::QueryBuilder;
 use polyoxide_gamma::Gamma;
 use std::time::Duration;
 
@@ -33,14 +32,7 @@ async fn find_active_token_id() -> String {
 
     markets
         .iter()
-        .find_map(|m| {
-            // clob_token_ids is a JSON-encoded array string: '["id1", "id2"]'
-            m.clob_token_ids.as_ref().and_then(|ids| {
-                serde_json::from_str::<Vec<String>>(ids)
-                    .ok()
-                    .and_then(|v| v.into_iter().next())
-            })
-        })
+        .find_map(|m| m.clob_token_ids().into_iter().next())
         .expect("should find at least one active market with a token_id via Gamma")
 }
 
@@ -108,6 +100,35 @@ async fn live_midpoint() {
     );
 }
 
+#[tokio::test]
+#[ignore]
+async fn live_batch_market_data() {
+    let token_id = find_active_token_id().await;
+    let client = public_client();
+    let markets = client.markets();
+
+    let midpoints = markets
+        .midpoints([token_id.clone()])
+        .send()
+        .await
+        .expect("midpoints should succeed");
+    assert!(midpoints.contains_key(&token_id), "midpoint for {token_id}");
+
+    let books = markets
+        .order_books([token_id.clone()])
+        .send()
+        .await
+        .expect("order_books should succeed");
+    assert!(books.contains_key(&token_id), "book for {token_id}");
+
+    let prices = markets
+        .prices([(token_id.clone(), OrderSide::Buy)])
+        .send()
+        .await
+        .expect("prices should succeed");
+    assert!(prices.contains_key(&token_id), "price for {token_id}");
+}
+
 #[tokio::test]
 #[ignore]
 async fn live_order_book() {
@@ -156,7 +177,8 @@ async fn live_prices_history() {
     let resp = client
         .markets()
         .prices_history(&token_id)
-        .query("interval", "max")
+        .interval(polyoxide_clob::PriceHistoryInterval::Max)
+        .fidelity(60)
         .send()
         .await
         .expect("prices_history should succeed");
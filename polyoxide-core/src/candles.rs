@@ -0,0 +1,143 @@
+//! OHLCV aggregation of price ticks into fixed-resolution bars
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// A single observation to aggregate: a price and the volume traded at it
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Tick {
+    /// Unix timestamp (seconds)
+    pub timestamp: i64,
+    /// Price
+    pub price: f64,
+    /// Volume traded (zero for sampled prices)
+    pub volume: f64,
+}
+
+/// An OHLCV bar
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Candle {
+    /// Bar open time, Unix seconds aligned to the resolution
+    pub start: i64,
+    /// First price in the bar
+    pub open: f64,
+    /// Highest price in the bar
+    pub high: f64,
+    /// Lowest price in the bar
+    pub low: f64,
+    /// Last price in the bar
+    pub close: f64,
+    /// Total volume in the bar
+    pub volume: f64,
+    /// Number of ticks in the bar
+    pub ticks: usize,
+}
+
+impl Candle {
+    fn open_with(start: i64, tick: &Tick) -> Self {
+        Self {
+            start,
+            open: tick.price,
+            high: tick.price,
+            low: tick.price,
+            close: tick.price,
+            volume: tick.volume,
+            ticks: 1,
+        }
+    }
+
+    fn push(&mut self, tick: &Tick) {
+        self.high = self.high.max(tick.price);
+        self.low = self.low.min(tick.price);
+        self.close = tick.price;
+        self.volume += tick.volume;
+        self.ticks += 1;
+    }
+}
+
+/// Aggregate ticks into OHLCV bars of `resolution`, oldest first.
+///
+/// Bars are aligned to multiples of the resolution since the Unix epoch, so
+/// daily bars start at 00:00 UTC. Ticks may be given in any order. Intervals
+/// without ticks produce no bar. A zero resolution yields no bars.
+pub fn candles(ticks: impl IntoIterator<Item = Tick>, resolution: Duration) -> Vec<Candle> {
+    let step = resolution.as_secs() as i64;
+    if step == 0 {
+        return Vec::new();
+    }
+
+    let mut ticks: Vec<Tick> = ticks.into_iter().filter(|t| t.price.is_finite()).collect();
+    ticks.sort_by_key(|t| t.timestamp);
+
+    let mut bars: Vec<Candle> = Vec::new();
+    for tick in &ticks {
+        let start = tick.timestamp.div_euclid(step) * step;
+        match bars.last_mut() {
+            Some(bar) if bar.start == start => bar.push(tick),
+            _ => bars.push(Candle::open_with(start, tick)),
+        }
+    }
+    bars
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tick(timestamp: i64, price: f64, volume: f64) -> Tick {
+        Tick {
+            timestamp,
+            price,
+            volume,
+        }
+    }
+
+    #[test]
+    fn test_candles_aggregate_ohlcv() {
+        let bars = candles(
+            vec![
+                tick(125, 0.45, 2.0),
+                tick(60, 0.40, 1.0),
+                tick(90, 0.50, 3.0),
+                tick(119, 0.35, 0.0),
+            ],
+            Duration::from_secs(60),
+        );
+
+        assert_eq!(bars.len(), 2);
+        assert_eq!(
+            bars[0],
+            Candle {
+                start: 60,
+                open: 0.40,
+                high: 0.50,
+                low: 0.35,
+                close: 0.35,
+                volume: 4.0,
+                ticks: 3,
+            }
+        );
+        assert_eq!(bars[1].start, 120);
+        assert_eq!(bars[1].open, 0.45);
+        assert_eq!(bars[1].ticks, 1);
+    }
+
+    #[test]
+    fn test_candles_skip_empty_intervals() {
+        let bars = candles(
+            vec![tick(0, 0.1, 0.0), tick(7200, 0.2, 0.0)],
+            Duration::from_secs(3600),
+        );
+        assert_eq!(
+            bars.iter().map(|b| b.start).collect::<Vec<_>>(),
+            vec![0, 7200]
+        );
+    }
+
+    #[test]
+    fn test_candles_zero_resolution() {
+        assert!(candles(vec![tick(0, 0.1, 0.0)], Duration::ZERO).is_empty());
+    }
+}
@@ -6,6 +6,8 @@
 //! - Shared error types and error handling
 //! - HTTP client configuration
 //! - Request builder utilities
+//! - Offset-based auto-pagination for list endpoints
+//! - OHLCV candle aggregation
 //!
 //! ## HTTP Client
 //!
@@ -28,8 +30,10 @@
 pub mod macros;
 
 pub mod auth;
+pub mod candles;
 pub mod client;
 pub mod error;
+pub mod pagination;
 pub mod rate_limit;
 pub mod request;
 
@@ -50,10 +54,12 @@ pub fn truncate_for_log(s: &str) -> std::borrow::Cow<'_, str> {
 }
 
 pub use auth::{current_timestamp, Base64Format, Signer};
+pub use candles::{candles, Candle, Tick};
 pub use client::{
     retry_after_header, HttpClient, HttpClientBuilder, DEFAULT_POOL_SIZE, DEFAULT_TIMEOUT_MS,
 };
 pub use error::ApiError;
+pub use pagination::{PageStream, Paginate, Paginator, DEFAULT_PAGE_SIZE};
 pub use rate_limit::{RateLimiter, RetryConfig};
 pub use request::{QueryBuilder, Request, RequestError};
 
@@ -0,0 +1,441 @@
+//! Offset-based auto-pagination for list request builders
+
+use futures_util::stream::{self, BoxStream, StreamExt, TryStreamExt};
+use serde::de::DeserializeOwned;
+
+use crate::client::HttpClient;
+use crate::request::{Request, RequestError};
+
+/// Page size used when the builder has no explicit `limit`
+pub const DEFAULT_PAGE_SIZE: u32 = 100;
+
+/// Stream of items yielded by a [`Paginator`]
+pub type PageStream<T, E> = BoxStream<'static, Result<T, E>>;
+
+/// List request builders that page through results with `limit`/`offset`.
+///
+/// The builder's own `limit` becomes the page size and its `offset` the
+/// starting point. Each page goes through the client's rate limiter and retry
+/// logic like any other request, and pagination stops at the first short page.
+///
+/// # Example
+///
+/// ```ignore
+/// use futures_util::TryStreamExt;
+/// use polyoxide_core::Paginate;
+///
+/// // Every market, 500 per request
+/// let markets = gamma.markets().list().closed(false).limit(500).all().await?;
+///
+/// // At most 1,000 trades, streamed as pages arrive
+/// let mut trades = data.trades().list().max_items(1_000).into_stream();
+/// while let Some(trade) = trades.try_next().await? {
+///     println!("{:?}", trade.transaction_hash);
+/// }
+/// ```
+pub trait Paginate: Sized {
+    /// Item type of each page
+    type Item: DeserializeOwned + Send + 'static;
+    /// Error type of the underlying request
+    type Error: RequestError + Send + 'static;
+
+    /// Page size used when the builder has no explicit `limit`
+    const PAGE_SIZE: u32 = DEFAULT_PAGE_SIZE;
+
+    /// Consume the builder and return the underlying page request
+    fn into_request(self) -> Request<Vec<Self::Item>, Self::Error>;
+
+    /// Convert the builder into a [`Paginator`] for further configuration
+    fn paginate(self) -> Paginator<Self::Item, Self::Error> {
+        Paginator::new(self.into_request(), Self::PAGE_SIZE)
+    }
+
+    /// Stop after yielding at most `max_items` items
+    fn max_items(self, max_items: usize) -> Paginator<Self::Item, Self::Error> {
+        self.paginate().max_items(max_items)
+    }
+
+    /// Stream every item across all pages
+    fn into_stream(self) -> PageStream<Self::Item, Self::Error> {
+        self.paginate().into_stream()
+    }
+
+    /// Fetch every item across all pages
+    fn all(self) -> impl std::future::Future<Output = Result<Vec<Self::Item>, Self::Error>> + Send {
+        self.paginate().all()
+    }
+}
+
+/// Drives offset-based pagination for a list request
+pub struct Paginator<T, E> {
+    request: Request<Vec<T>, E>,
+    cursor: PageCursor,
+}
+
+impl<T, E> Paginator<T, E> {
+    /// Create a paginator, taking `limit` and `offset` from the request's query if present
+    pub fn new(mut request: Request<Vec<T>, E>, default_page_size: u32) -> Self {
+        let limit = take_query_param(&mut request.query, "limit");
+        let offset = take_query_param(&mut request.query, "offset");
+        Self {
+            request,
+            cursor: PageCursor {
+                offset: offset.unwrap_or(0),
+                page_size: limit.filter(|&l| l > 0).unwrap_or(default_page_size),
+                remaining: None,
+            },
+        }
+    }
+
+    /// Override the number of items requested per page
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.cursor.page_size = page_size.max(1);
+        self
+    }
+
+    /// Stop after yielding at most `max_items` items
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.cursor.remaining = Some(max_items);
+        self
+    }
+}
+
+impl<T, E> Paginator<T, E>
+where
+    T: DeserializeOwned + Send + 'static,
+    E: RequestError + Send + 'static,
+{
+    /// Stream each page as it is fetched
+    pub fn pages(self) -> PageStream<Vec<T>, E> {
+        let Request {
+            http_client,
+            path,
+            query,
+            ..
+        } = self.request;
+        let state = Some((http_client, path, query, self.cursor));
+
+        stream::try_unfold(state, |state| async move {
+            let Some((http_client, path, query, mut cursor)) = state else {
+                return Ok(None);
+            };
+            let Some(limit) = cursor.next_limit() else {
+                return Ok(None);
+            };
+
+            let page = page_request::<T, E>(&http_client, &path, &query, limit, cursor.offset)
+                .send()
+                .await?;
+            let more = cursor.advance(page.len(), limit);
+            if page.is_empty() {
+                return Ok(None);
+            }
+            let next = more.then_some((http_client, path, query, cursor));
+            Ok(Some((page, next)))
+        })
+        .boxed()
+    }
+
+    /// Stream every item across all pages
+    pub fn into_stream(self) -> PageStream<T, E> {
+        self.pages()
+            .map_ok(|page| stream::iter(page.into_iter().map(Ok)))
+            .try_flatten()
+            .boxed()
+    }
+
+    /// Fetch every item across all pages
+    pub async fn all(self) -> Result<Vec<T>, E> {
+        self.pages()
+            .try_fold(Vec::new(), |mut items, page| async move {
+                items.extend(page);
+                Ok(items)
+            })
+            .await
+    }
+}
+
+/// Offset bookkeeping between pages
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct PageCursor {
+    offset: u32,
+    page_size: u32,
+    remaining: Option<usize>,
+}
+
+impl PageCursor {
+    /// Limit for the next page, or `None` once the item cap is reached
+    fn next_limit(&self) -> Option<u32> {
+        match self.remaining {
+            Some(0) => None,
+            Some(remaining) => Some(self.page_size.min(remaining.try_into().unwrap_or(u32::MAX))),
+            None => Some(self.page_size),
+        }
+    }
+
+    /// Record a page of `len` items fetched with `limit`; returns whether to keep going
+    fn advance(&mut self, len: usize, limit: u32) -> bool {
+        self.offset = self
+            .offset
+            .saturating_add(len.try_into().unwrap_or(u32::MAX));
+        if let Some(remaining) = self.remaining.as_mut() {
+            *remaining = remaining.saturating_sub(len);
+        }
+        len >= limit as usize && self.remaining != Some(0)
+    }
+}
+
+fn page_request<T, E>(
+    http_client: &HttpClient,
+    path: &str,
+    query: &[(String, String)],
+    limit: u32,
+    offset: u32,
+) -> Request<Vec<T>, E> {
+    let mut request = Request::new(http_client.clone(), path);
+    request.query = query.to_vec();
+    request.query.push(("limit".into(), limit.to_string()));
+    request.query.push(("offset".into(), offset.to_string()));
+    request
+}
+
+/// Remove every occurrence of `key`, returning the last value that parses
+fn take_query_param(query: &mut Vec<(String, String)>, key: &str) -> Option<u32> {
+    let mut value = None;
+    query.retain(|(k, v)| {
+        if k == key {
+            value = v.parse().ok().or(value);
+            false
+        } else {
+            true
+        }
+    });
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ApiError, HttpClientBuilder, QueryBuilder};
+    use reqwest::Response;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    #[derive(Debug)]
+    struct TestError(#[allow(dead_code)] ApiError);
+
+    impl From<ApiError> for TestError {
+        fn from(err: ApiError) -> Self {
+            Self(err)
+        }
+    }
+
+    impl RequestError for TestError {
+        async fn from_response(response: Response) -> Self {
+            Self(ApiError::from_response(response).await)
+        }
+    }
+
+    fn make_request(base_url: &str) -> Request<Vec<u32>, TestError> {
+        let http = HttpClientBuilder::new(base_url).build().unwrap();
+        Request::new(http, "/items")
+    }
+
+    /// Serve `total` sequential integers, honouring `limit` and `offset` query params.
+    ///
+    /// Returns the base URL and a receiver of the request lines seen by the server.
+    async fn serve_items(total: u32) -> (String, tokio::sync::mpsc::UnboundedReceiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let mut buf = vec![0u8; 4096];
+                let n = socket.read(&mut buf).await.unwrap_or(0);
+                let head = String::from_utf8_lossy(&buf[..n]).to_string();
+                let line = head.lines().next().unwrap_or_default().to_string();
+                let url = url::Url::parse("http://localhost")
+                    .unwrap()
+                    .join(line.split(' ').nth(1).unwrap_or("/"))
+                    .unwrap();
+                let param = |key: &str| {
+                    url.query_pairs()
+                        .find(|(k, _)| k == key)
+                        .and_then(|(_, v)| v.parse::<u32>().ok())
+                };
+                let offset = param("offset").unwrap_or(0);
+                let limit = param("limit").unwrap_or(100);
+                let items: Vec<u32> = (offset..total.min(offset.saturating_add(limit))).collect();
+                let body = serde_json::to_string(&items).unwrap();
+                let _ = tx.send(line);
+
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+            }
+        });
+
+        (format!("http://{addr}"), rx)
+    }
+
+    fn paginator(request: Request<Vec<u32>, TestError>) -> Paginator<u32, TestError> {
+        Paginator::new(request, DEFAULT_PAGE_SIZE)
+    }
+
+    fn drain(rx: &mut tokio::sync::mpsc::UnboundedReceiver<String>) -> Vec<String> {
+        let mut lines = Vec::new();
+        while let Ok(line) = rx.try_recv() {
+            lines.push(line);
+        }
+        lines
+    }
+
+    // ── Paginator::new ──────────────────────────────────────────
+
+    #[test]
+    fn test_new_takes_limit_and_offset_from_query() {
+        let request = make_request("https://example.com")
+            .query("active", true)
+            .query("limit", 25)
+            .query("offset", 50);
+        let paginator = Paginator::new(request, DEFAULT_PAGE_SIZE);
+        assert_eq!(paginator.cursor.page_size, 25);
+        assert_eq!(paginator.cursor.offset, 50);
+        assert_eq!(
+            paginator.request.query,
+            vec![("active".into(), "true".into())]
+        );
+    }
+
+    #[test]
+    fn test_new_defaults_without_limit() {
+        let paginator = Paginator::new(make_request("https://example.com"), 42);
+        assert_eq!(paginator.cursor.page_size, 42);
+        assert_eq!(paginator.cursor.offset, 0);
+    }
+
+    #[test]
+    fn test_new_ignores_zero_limit() {
+        let request = make_request("https://example.com").query("limit", 0);
+        let paginator = Paginator::new(request, 10);
+        assert_eq!(paginator.cursor.page_size, 10);
+    }
+
+    #[test]
+    fn test_take_query_param_last_value_wins() {
+        let mut query = vec![
+            ("limit".to_string(), "10".to_string()),
+            ("limit".to_string(), "20".to_string()),
+        ];
+        assert_eq!(take_query_param(&mut query, "limit"), Some(20));
+        assert!(query.is_empty());
+    }
+
+    // ── PageCursor ──────────────────────────────────────────────
+
+    #[test]
+    fn test_cursor_full_page_continues() {
+        let mut cursor = PageCursor {
+            offset: 0,
+            page_size: 10,
+            remaining: None,
+        };
+        assert_eq!(cursor.next_limit(), Some(10));
+        assert!(cursor.advance(10, 10));
+        assert_eq!(cursor.offset, 10);
+    }
+
+    #[test]
+    fn test_cursor_short_page_stops() {
+        let mut cursor = PageCursor {
+            offset: 0,
+            page_size: 10,
+            remaining: None,
+        };
+        assert!(!cursor.advance(3, 10));
+    }
+
+    #[test]
+    fn test_cursor_max_items_shrinks_last_page() {
+        let mut cursor = PageCursor {
+            offset: 0,
+            page_size: 10,
+            remaining: Some(15),
+        };
+        assert_eq!(cursor.next_limit(), Some(10));
+        assert!(cursor.advance(10, 10));
+        assert_eq!(cursor.next_limit(), Some(5));
+        assert!(!cursor.advance(5, 5));
+        assert_eq!(cursor.next_limit(), None);
+    }
+
+    // ── Streaming ───────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_all_walks_every_page() {
+        let (base_url, mut rx) = serve_items(25).await;
+        let items = paginator(
+            make_request(&base_url)
+                .query("limit", 10)
+                .query_opt("tag", Some("x")),
+        )
+        .all()
+        .await
+        .unwrap();
+        assert_eq!(items, (0..25).collect::<Vec<_>>());
+
+        let lines = drain(&mut rx);
+        assert_eq!(lines.len(), 3, "requests: {lines:?}");
+        assert!(lines[0].contains("tag=x&limit=10&offset=0"));
+        assert!(lines[2].contains("offset=20"));
+    }
+
+    #[tokio::test]
+    async fn test_exact_multiple_stops_on_empty_page() {
+        let (base_url, mut rx) = serve_items(20).await;
+        let items = paginator(make_request(&base_url).query("limit", 10))
+            .all()
+            .await
+            .unwrap();
+        assert_eq!(items.len(), 20);
+        assert_eq!(drain(&mut rx).len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_max_items_caps_stream() {
+        let (base_url, mut rx) = serve_items(1_000).await;
+        let items: Vec<u32> = paginator(make_request(&base_url).query("offset", 5))
+            .page_size(4)
+            .max_items(10)
+            .into_stream()
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(items, (5..15).collect::<Vec<_>>());
+
+        let lines = drain(&mut rx);
+        assert_eq!(lines.len(), 3);
+        assert!(lines[2].contains("limit=2&offset=13"));
+    }
+
+    #[tokio::test]
+    async fn test_max_items_zero_makes_no_requests() {
+        let items = paginator(make_request("http://127.0.0.1:1"))
+            .max_items(0)
+            .all()
+            .await
+            .unwrap();
+        assert!(items.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_error_ends_stream() {
+        let mut stream = paginator(make_request("http://127.0.0.1:1")).into_stream();
+        assert!(stream.next().await.unwrap().is_err());
+        assert!(stream.next().await.is_none());
+    }
+}
@@ -110,6 +110,7 @@ impl RateLimiter {
     /// - POST /order: 3,500/10s burst + 36,000/10min sustained
     /// - DELETE /order: 3,000/10s
     /// - Market data (/markets, /book, /price, /midpoint, /prices-history, /neg-risk, /tick-size): 1,500/10s
+    /// - Batch market data (/books, /prices, /midpoints, /spreads, /last-trades-prices): 500/10s
     /// - Ledger (/trades, /data/): 900/10s
     /// - Auth (/auth): 100/10s
     pub fn clob_default() -> Self {
@@ -182,6 +183,13 @@ impl RateLimiter {
                         burst: DirectLimiter::direct(quota(1_500, ten_sec)),
                         sustained: None,
                     },
+                    EndpointLimit {
+                        path_prefix: "/books",
+                        method: None,
+                        match_mode: MatchMode::Prefix,
+                        burst: DirectLimiter::direct(quota(500, ten_sec)),
+                        sustained: None,
+                    },
                     EndpointLimit {
                         path_prefix: "/price",
                         method: None,
@@ -196,6 +204,34 @@ impl RateLimiter {
                         burst: DirectLimiter::direct(quota(1_500, ten_sec)),
                         sustained: None,
                     },
+                    EndpointLimit {
+                        path_prefix: "/prices",
+                        method: None,
+                        match_mode: MatchMode::Prefix,
+                        burst: DirectLimiter::direct(quota(500, ten_sec)),
+                        sustained: None,
+                    },
+                    EndpointLimit {
+                        path_prefix: "/midpoints",
+                        method: None,
+                        match_mode: MatchMode::Prefix,
+                        burst: DirectLimiter::direct(quota(500, ten_sec)),
+                        sustained: None,
+                    },
+                    EndpointLimit {
+                        path_prefix: "/spreads",
+                        method: None,
+                        match_mode: MatchMode::Prefix,
+                        burst: DirectLimiter::direct(quota(500, ten_sec)),
+                        sustained: None,
+                    },
+                    EndpointLimit {
+                        path_prefix: "/last-trades-prices",
+                        method: None,
+                        match_mode: MatchMode::Prefix,
+                        burst: DirectLimiter::direct(quota(500, ten_sec)),
+                        sustained: None,
+                    },
                     EndpointLimit {
                         path_prefix: "/neg-risk",
                         method: None,
@@ -477,7 +513,7 @@ mod tests {
     #[test]
     fn test_clob_default_construction() {
         let rl = RateLimiter::clob_default();
-        assert_eq!(rl.inner.limits.len(), 12);
+        assert_eq!(rl.inner.limits.len(), 17);
         assert!(format!("{:?}", rl).contains("endpoints"));
     }
 
@@ -504,7 +540,7 @@ mod tests {
         let rl = RateLimiter::clob_default();
         let dbg = format!("{:?}", rl);
         assert!(dbg.contains("RateLimiter"), "missing struct name: {dbg}");
-        assert!(dbg.contains("endpoints: 12"), "missing count: {dbg}");
+        assert!(dbg.contains("endpoints: 17"), "missing count: {dbg}");
     }
 
     // ── Endpoint matching internals ──────────────────────────────
@@ -77,6 +77,16 @@ impl<T, E> Request<T, E> {
             _marker: PhantomData,
         }
     }
+
+    /// Keep the path and query but deserialize the response as `U`
+    pub fn cast<U>(self) -> Request<U, E> {
+        Request {
+            http_client: self.http_client,
+            path: self.path,
+            query: self.query,
+            _marker: PhantomData,
+        }
+    }
 }
 
 impl<T, E> QueryBuilder for Request<T, E> {
@@ -1,4 +1,4 @@
-use polyoxide_core::{HttpClient, QueryBuilder, Request};
+use polyoxide_core::{HttpClient, Paginate, QueryBuilder, Request};
 use serde::{Deserialize, Serialize};
 
 use crate::error::DataApiError;
@@ -55,6 +55,17 @@ impl GetBuilderLeaderboard {
     }
 }
 
+impl Paginate for GetBuilderLeaderboard {
+    type Item = BuilderRanking;
+    type Error = DataApiError;
+
+    const PAGE_SIZE: u32 = 50;
+
+    fn into_request(self) -> Request<Vec<BuilderRanking>, DataApiError> {
+        self.request
+    }
+}
+
 /// Time period for aggregation
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "UPPERCASE")]
@@ -1,7 +1,13 @@
+use std::collections::{HashMap, HashSet};
+
+use futures_util::future::try_join_all;
 use polyoxide_core::{HttpClient, QueryBuilder, Request};
 use serde::{Deserialize, Serialize};
 
-use crate::error::DataApiError;
+use crate::{api::users::UserApi, error::DataApiError};
+
+/// Wallet value lookups run concurrently by [`Holders::exposure`]
+const EXPOSURE_CONCURRENCY: usize = 8;
 
 /// Holders namespace for holder-related operations
 #[derive(Clone)]
@@ -20,6 +26,48 @@ impl Holders {
 
         ListHolders { request }
     }
+
+    /// Join holders with each wallet's total open position value.
+    ///
+    /// Every distinct wallet in `holders` (compared case-insensitively, as in
+    /// [`HolderOverlap::between`]) is looked up with
+    /// [`UserApi::positions_value`]. Wallets worth at least
+    /// `large_exposure` are flagged. Results are sorted by total value,
+    /// largest first.
+    pub async fn exposure(
+        &self,
+        holders: &[MarketHolders],
+        large_exposure: f64,
+    ) -> Result<Vec<HolderExposure>, DataApiError> {
+        let amounts = wallet_amounts(holders);
+        let wallets: Vec<&str> = amounts.keys().map(String::as_str).collect();
+
+        let mut exposures = Vec::with_capacity(wallets.len());
+        for chunk in wallets.chunks(EXPOSURE_CONCURRENCY) {
+            let values = try_join_all(chunk.iter().map(|wallet| {
+                UserApi {
+                    http_client: self.http_client.clone(),
+                    user_address: wallet.to_string(),
+                }
+                .positions_value()
+                .send()
+            }))
+            .await?;
+
+            for (wallet, value) in chunk.iter().zip(values) {
+                let total_value = value.first().map(|v| v.value).unwrap_or_default();
+                exposures.push(HolderExposure {
+                    proxy_wallet: wallet.to_string(),
+                    amount: amounts[*wallet],
+                    total_value,
+                    large: total_value >= large_exposure,
+                });
+            }
+        }
+
+        exposures.sort_by(|a, b| b.total_value.total_cmp(&a.total_value));
+        Ok(exposures)
+    }
 }
 
 /// Request builder for getting top holders
@@ -44,6 +92,12 @@ impl ListHolders {
     pub async fn send(self) -> Result<Vec<MarketHolders>, DataApiError> {
         self.request.send().await
     }
+
+    /// Execute the request, deserializing into [`typed::MarketHolders`](crate::typed::MarketHolders)
+    #[cfg(feature = "typed")]
+    pub async fn send_typed(self) -> Result<Vec<crate::typed::MarketHolders>, DataApiError> {
+        self.request.cast().send().await
+    }
 }
 
 /// Market holders response containing token and its holders
@@ -82,6 +136,189 @@ pub struct Holder {
     pub profile_image_optimized: Option<String>,
 }
 
+impl MarketHolders {
+    /// Total amount held by the listed holders
+    pub fn total_amount(&self) -> f64 {
+        self.holders.iter().map(|h| h.amount).sum()
+    }
+
+    /// Fraction of the listed amount held by the `n` largest holders
+    ///
+    /// The endpoint only returns the top holders (up to `limit`), so shares
+    /// are relative to that set rather than the token's full supply.
+    pub fn top_share(&self, n: usize) -> f64 {
+        let total = self.total_amount();
+        if total <= 0.0 {
+            return 0.0;
+        }
+        let mut amounts: Vec<f64> = self.holders.iter().map(|h| h.amount).collect();
+        amounts.sort_by(|a, b| b.total_cmp(a));
+        amounts.iter().take(n).sum::<f64>() / total
+    }
+
+    /// Herfindahl-Hirschman index of the listed holdings, from near 0.0
+    /// (evenly spread) to 1.0 (a single holder)
+    pub fn herfindahl(&self) -> f64 {
+        let total = self.total_amount();
+        if total <= 0.0 {
+            return 0.0;
+        }
+        self.holders
+            .iter()
+            .map(|h| (h.amount / total).powi(2))
+            .sum()
+    }
+
+    /// Summary of how concentrated the holdings are
+    pub fn concentration(&self) -> Concentration {
+        Concentration {
+            token: self.token.clone(),
+            holders: self.holders.len(),
+            total_amount: self.total_amount(),
+            top1_share: self.top_share(1),
+            top5_share: self.top_share(5),
+            top10_share: self.top_share(10),
+            herfindahl: self.herfindahl(),
+        }
+    }
+}
+
+/// Holder concentration of one token, as returned by [`MarketHolders::concentration`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Concentration {
+    /// Token identifier
+    pub token: String,
+    /// Number of listed holders
+    pub holders: usize,
+    /// Total amount held by the listed holders
+    pub total_amount: f64,
+    /// Share held by the largest holder
+    pub top1_share: f64,
+    /// Share held by the five largest holders
+    pub top5_share: f64,
+    /// Share held by the ten largest holders
+    pub top10_share: f64,
+    /// Herfindahl-Hirschman index
+    pub herfindahl: f64,
+}
+
+/// Whale positioning on the YES (outcome 0) and NO (outcome 1) sides of a market
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct WhaleImbalance {
+    /// Holders of at least the whale threshold on YES
+    pub yes_whales: usize,
+    /// Holders of at least the whale threshold on NO
+    pub no_whales: usize,
+    /// Amount held by YES whales
+    pub yes_amount: f64,
+    /// Amount held by NO whales
+    pub no_amount: f64,
+}
+
+impl WhaleImbalance {
+    /// Tally holders of at least `min_amount` across a market's tokens
+    pub fn from_holders(tokens: &[MarketHolders], min_amount: f64) -> Self {
+        tokens
+            .iter()
+            .flat_map(|m| &m.holders)
+            .filter(|h| h.amount >= min_amount)
+            .fold(Self::default(), |mut acc, h| {
+                match h.outcome_index {
+                    0 => {
+                        acc.yes_whales += 1;
+                        acc.yes_amount += h.amount;
+                    }
+                    _ => {
+                        acc.no_whales += 1;
+                        acc.no_amount += h.amount;
+                    }
+                }
+                acc
+            })
+    }
+
+    /// `(yes - no) / (yes + no)` by amount: +1.0 is all YES, -1.0 all NO,
+    /// 0.0 balanced or no whales
+    pub fn imbalance(&self) -> f64 {
+        let total = self.yes_amount + self.no_amount;
+        if total <= 0.0 {
+            0.0
+        } else {
+            (self.yes_amount - self.no_amount) / total
+        }
+    }
+}
+
+/// Wallets holding in two sets of markets
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct HolderOverlap {
+    /// Wallets present on both sides, sorted
+    pub shared: Vec<String>,
+    /// Distinct wallets on the left side
+    pub left: usize,
+    /// Distinct wallets on the right side
+    pub right: usize,
+}
+
+impl HolderOverlap {
+    /// Compare the holders of two markets (or groups of markets)
+    pub fn between(left: &[MarketHolders], right: &[MarketHolders]) -> Self {
+        let wallets = |markets: &[MarketHolders]| -> HashSet<String> {
+            markets
+                .iter()
+                .flat_map(|m| &m.holders)
+                .map(|h| wallet_key(&h.proxy_wallet))
+                .collect()
+        };
+        let (left, right) = (wallets(left), wallets(right));
+        let mut shared: Vec<String> = left.intersection(&right).cloned().collect();
+        shared.sort_unstable();
+        Self {
+            shared,
+            left: left.len(),
+            right: right.len(),
+        }
+    }
+
+    /// Jaccard similarity of the two wallet sets (0.0 to 1.0)
+    pub fn jaccard(&self) -> f64 {
+        let union = self.left + self.right - self.shared.len();
+        if union == 0 {
+            0.0
+        } else {
+            self.shared.len() as f64 / union as f64
+        }
+    }
+}
+
+/// A holder joined with their total open position value, as returned by
+/// [`Holders::exposure`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HolderExposure {
+    /// Proxy wallet address (lowercased)
+    pub proxy_wallet: String,
+    /// Amount held across the queried tokens
+    pub amount: f64,
+    /// Total value of all the wallet's open positions
+    pub total_value: f64,
+    /// Whether `total_value` reached the large-exposure threshold
+    pub large: bool,
+}
+
+/// Holders' wallets are compared case-insensitively
+fn wallet_key(wallet: &str) -> String {
+    wallet.to_ascii_lowercase()
+}
+
+/// Amount held per wallet, summed across all tokens of `markets`
+fn wallet_amounts(markets: &[MarketHolders]) -> HashMap<String, f64> {
+    let mut amounts: HashMap<String, f64> = HashMap::new();
+    for holder in markets.iter().flat_map(|m| &m.holders) {
+        *amounts.entry(wallet_key(&holder.proxy_wallet)).or_default() += holder.amount;
+    }
+    amounts
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -147,4 +384,82 @@ mod tests {
         assert_eq!(mh.token, "empty_token");
         assert!(mh.holders.is_empty());
     }
+
+    // ── Analytics ───────────────────────────────────────────────
+
+    fn holders(token: &str, amounts: &[(&str, f64, u32)]) -> MarketHolders {
+        MarketHolders {
+            token: token.to_string(),
+            holders: amounts
+                .iter()
+                .map(|(wallet, amount, outcome_index)| Holder {
+                    proxy_wallet: wallet.to_string(),
+                    bio: None,
+                    asset: Some(token.to_string()),
+                    pseudonym: None,
+                    amount: *amount,
+                    display_username_public: None,
+                    outcome_index: *outcome_index,
+                    name: None,
+                    profile_image: None,
+                    profile_image_optimized: None,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn concentration_metrics() {
+        let yes = holders(
+            "yes",
+            &[("0xa", 50.0, 0), ("0xb", 30.0, 0), ("0xc", 20.0, 0)],
+        );
+        assert!((yes.total_amount() - 100.0).abs() < 1e-9);
+        assert!((yes.top_share(1) - 0.5).abs() < 1e-9);
+        assert!((yes.top_share(2) - 0.8).abs() < 1e-9);
+        assert!((yes.top_share(10) - 1.0).abs() < 1e-9);
+        assert!((yes.herfindahl() - 0.38).abs() < 1e-9);
+
+        let summary = yes.concentration();
+        assert_eq!(summary.holders, 3);
+        assert_eq!(summary.token, "yes");
+
+        let empty = holders("none", &[]);
+        assert_eq!(empty.top_share(1), 0.0);
+        assert_eq!(empty.herfindahl(), 0.0);
+    }
+
+    #[test]
+    fn whale_imbalance_by_outcome() {
+        let market = [
+            holders("yes", &[("0xa", 900.0, 0), ("0xb", 10.0, 0)]),
+            holders("no", &[("0xc", 300.0, 1)]),
+        ];
+        let imbalance = WhaleImbalance::from_holders(&market, 100.0);
+        assert_eq!(imbalance.yes_whales, 1);
+        assert_eq!(imbalance.no_whales, 1);
+        assert!((imbalance.imbalance() - 0.5).abs() < 1e-9);
+        assert_eq!(WhaleImbalance::default().imbalance(), 0.0);
+    }
+
+    #[test]
+    fn holder_overlap_between_markets() {
+        let a = [holders("a", &[("0xA", 1.0, 0), ("0xb", 1.0, 1)])];
+        let b = [holders("b", &[("0xa", 1.0, 0), ("0xc", 1.0, 0)])];
+        let overlap = HolderOverlap::between(&a, &b);
+        assert_eq!(overlap.shared, vec!["0xa".to_string()]);
+        assert!((overlap.jaccard() - 1.0 / 3.0).abs() < 1e-9);
+        assert_eq!(HolderOverlap::between(&[], &[]).jaccard(), 0.0);
+    }
+
+    #[test]
+    fn wallet_amounts_ignore_address_case() {
+        let market = [
+            holders("yes", &[("0xAbC", 10.0, 0), ("0xdef", 1.0, 0)]),
+            holders("no", &[("0xabc", 5.0, 1)]),
+        ];
+        let amounts = wallet_amounts(&market);
+        assert_eq!(amounts.len(), 2);
+        assert!((amounts["0xabc"] - 15.0).abs() < 1e-9);
+    }
 }
@@ -0,0 +1,283 @@
+use polyoxide_core::{HttpClient, Paginate, QueryBuilder, Request};
+use serde::{Deserialize, Serialize};
+
+use crate::{api::builders::TimePeriod, error::DataApiError};
+
+/// Leaderboard namespace for trader rankings
+#[derive(Clone)]
+pub struct LeaderboardApi {
+    pub(crate) http_client: HttpClient,
+}
+
+impl LeaderboardApi {
+    /// Get the trader leaderboard
+    pub fn traders(&self) -> GetTraderLeaderboard {
+        let request = Request::new(self.http_client.clone(), "/v1/leaderboard");
+
+        GetTraderLeaderboard { request }
+    }
+
+    /// Look up a single trader's rank
+    pub fn user(&self, user_address: impl Into<String>) -> GetTraderRank {
+        let request = Request::new(self.http_client.clone(), "/v1/leaderboard")
+            .query("user", user_address.into());
+
+        GetTraderRank { request }
+    }
+}
+
+/// Request builder for getting the trader leaderboard
+pub struct GetTraderLeaderboard {
+    request: Request<Vec<TraderRanking>, DataApiError>,
+}
+
+impl GetTraderLeaderboard {
+    /// Set the aggregation time period (default: DAY)
+    pub fn time_period(mut self, period: TimePeriod) -> Self {
+        self.request = self.request.query("timePeriod", period);
+        self
+    }
+
+    /// Set the market category (default: OVERALL)
+    pub fn category(mut self, category: LeaderboardCategory) -> Self {
+        self.request = self.request.query("category", category);
+        self
+    }
+
+    /// Set the ranking metric (default: PNL)
+    pub fn order_by(mut self, order_by: LeaderboardOrderBy) -> Self {
+        self.request = self.request.query("orderBy", order_by);
+        self
+    }
+
+    /// Set maximum number of results (1-50, default: 25)
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.request = self.request.query("limit", limit);
+        self
+    }
+
+    /// Set pagination offset (0-1000, default: 0)
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.request = self.request.query("offset", offset);
+        self
+    }
+
+    /// Execute the request
+    pub async fn send(self) -> Result<Vec<TraderRanking>, DataApiError> {
+        self.request.send().await
+    }
+}
+
+impl Paginate for GetTraderLeaderboard {
+    type Item = TraderRanking;
+    type Error = DataApiError;
+
+    const PAGE_SIZE: u32 = 50;
+
+    fn into_request(self) -> Request<Vec<TraderRanking>, DataApiError> {
+        self.request
+    }
+}
+
+/// Request builder for looking up a single trader's rank
+pub struct GetTraderRank {
+    request: Request<Vec<TraderRanking>, DataApiError>,
+}
+
+impl GetTraderRank {
+    /// Set the aggregation time period (default: DAY)
+    pub fn time_period(mut self, period: TimePeriod) -> Self {
+        self.request = self.request.query("timePeriod", period);
+        self
+    }
+
+    /// Set the market category (default: OVERALL)
+    pub fn category(mut self, category: LeaderboardCategory) -> Self {
+        self.request = self.request.query("category", category);
+        self
+    }
+
+    /// Set the ranking metric (default: PNL)
+    pub fn order_by(mut self, order_by: LeaderboardOrderBy) -> Self {
+        self.request = self.request.query("orderBy", order_by);
+        self
+    }
+
+    /// Execute the request, returning `None` if the trader is unranked
+    pub async fn send(self) -> Result<Option<TraderRanking>, DataApiError> {
+        Ok(self.request.send().await?.into_iter().next())
+    }
+}
+
+/// Market category of a leaderboard
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum LeaderboardCategory {
+    /// All markets (default)
+    #[default]
+    Overall,
+    Politics,
+    Sports,
+    Crypto,
+    Culture,
+    Mentions,
+    Weather,
+    Economics,
+    Tech,
+    Finance,
+}
+
+impl std::fmt::Display for LeaderboardCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Overall => write!(f, "OVERALL"),
+            Self::Politics => write!(f, "POLITICS"),
+            Self::Sports => write!(f, "SPORTS"),
+            Self::Crypto => write!(f, "CRYPTO"),
+            Self::Culture => write!(f, "CULTURE"),
+            Self::Mentions => write!(f, "MENTIONS"),
+            Self::Weather => write!(f, "WEATHER"),
+            Self::Economics => write!(f, "ECONOMICS"),
+            Self::Tech => write!(f, "TECH"),
+            Self::Finance => write!(f, "FINANCE"),
+        }
+    }
+}
+
+/// Metric the leaderboard is ranked by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum LeaderboardOrderBy {
+    /// Profit and loss (default)
+    #[default]
+    Pnl,
+    /// Trading volume
+    Vol,
+}
+
+impl std::fmt::Display for LeaderboardOrderBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pnl => write!(f, "PNL"),
+            Self::Vol => write!(f, "VOL"),
+        }
+    }
+}
+
+/// Trader ranking entry in the leaderboard
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all(deserialize = "camelCase"))]
+pub struct TraderRanking {
+    /// Trader's ranking position
+    pub rank: String,
+    /// Trader's proxy wallet address
+    pub proxy_wallet: String,
+    /// Display name
+    pub user_name: Option<String>,
+    /// Trading volume over the period
+    #[serde(default)]
+    pub vol: f64,
+    /// Profit and loss over the period
+    #[serde(default)]
+    pub pnl: f64,
+    /// Profile image URL
+    pub profile_image: Option<String>,
+    /// X (Twitter) username
+    pub x_username: Option<String>,
+    /// Verification status
+    #[serde(default)]
+    pub verified_badge: bool,
+}
+
+impl TraderRanking {
+    /// Rank as a number, if the API reported a numeric rank
+    pub fn rank_number(&self) -> Option<u32> {
+        self.rank.trim().parse().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DataApi;
+
+    #[test]
+    fn leaderboard_builders_chain() {
+        let leaderboard = DataApi::new().unwrap().leaderboard();
+        let _traders = leaderboard
+            .traders()
+            .time_period(TimePeriod::Week)
+            .category(LeaderboardCategory::Crypto)
+            .order_by(LeaderboardOrderBy::Vol)
+            .limit(50)
+            .offset(100);
+        let _rank = leaderboard
+            .user("0xabc")
+            .time_period(TimePeriod::All)
+            .category(LeaderboardCategory::Overall)
+            .order_by(LeaderboardOrderBy::Pnl);
+    }
+
+    #[test]
+    fn category_display_matches_serde() {
+        let variants = [
+            LeaderboardCategory::Overall,
+            LeaderboardCategory::Politics,
+            LeaderboardCategory::Sports,
+            LeaderboardCategory::Crypto,
+            LeaderboardCategory::Culture,
+            LeaderboardCategory::Mentions,
+            LeaderboardCategory::Weather,
+            LeaderboardCategory::Economics,
+            LeaderboardCategory::Tech,
+            LeaderboardCategory::Finance,
+        ];
+        for variant in variants {
+            let serialized = serde_json::to_value(variant).unwrap();
+            assert_eq!(
+                format!("\"{}\"", variant),
+                serialized.to_string(),
+                "Display mismatch for {:?}",
+                variant
+            );
+        }
+    }
+
+    #[test]
+    fn order_by_display_matches_serde() {
+        for variant in [LeaderboardOrderBy::Pnl, LeaderboardOrderBy::Vol] {
+            let serialized = serde_json::to_value(variant).unwrap();
+            assert_eq!(format!("\"{}\"", variant), serialized.to_string());
+        }
+    }
+
+    #[test]
+    fn deserialize_trader_ranking() {
+        let json = r#"{
+            "rank": "1",
+            "proxyWallet": "0x56687bf447db6ffa42ffe2204a05edaa20f55839",
+            "userName": "Theo4",
+            "vol": 43013258.5,
+            "pnl": 22053933.75,
+            "profileImage": "",
+            "xUsername": "",
+            "verifiedBadge": true
+        }"#;
+
+        let ranking: TraderRanking = serde_json::from_str(json).unwrap();
+        assert_eq!(ranking.rank_number(), Some(1));
+        assert_eq!(ranking.user_name.as_deref(), Some("Theo4"));
+        assert!((ranking.pnl - 22053933.75).abs() < f64::EPSILON);
+        assert!(ranking.verified_badge);
+    }
+
+    #[test]
+    fn deserialize_trader_ranking_minimal() {
+        let json = r#"{"rank": "12", "proxyWallet": "0xabc"}"#;
+        let ranking: TraderRanking = serde_json::from_str(json).unwrap();
+        assert_eq!(ranking.rank_number(), Some(12));
+        assert!(ranking.user_name.is_none());
+        assert_eq!(ranking.vol, 0.0);
+        assert!(!ranking.verified_badge);
+    }
+}
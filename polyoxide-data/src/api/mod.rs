@@ -1,6 +1,7 @@
 pub mod builders;
 pub mod health;
 pub mod holders;
+pub mod leaderboard;
 pub mod live_volume;
 pub mod open_interest;
 pub mod trades;
@@ -1,4 +1,4 @@
-use polyoxide_core::{HttpClient, QueryBuilder, Request};
+use polyoxide_core::{HttpClient, Paginate, QueryBuilder, Request};
 
 use crate::{
     error::DataApiError,
@@ -92,4 +92,19 @@ impl ListTrades {
     pub async fn send(self) -> Result<Vec<Trade>, DataApiError> {
         self.request.send().await
     }
+
+    /// Execute the request, deserializing into [`typed::Trade`](crate::typed::Trade)
+    #[cfg(feature = "typed")]
+    pub async fn send_typed(self) -> Result<Vec<crate::typed::Trade>, DataApiError> {
+        self.request.cast().send().await
+    }
+}
+
+impl Paginate for ListTrades {
+    type Item = Trade;
+    type Error = DataApiError;
+
+    fn into_request(self) -> Request<Vec<Trade>, DataApiError> {
+        self.request
+    }
 }
@@ -1,8 +1,13 @@
-use polyoxide_core::{HttpClient, QueryBuilder, Request};
+use polyoxide_core::{HttpClient, Paginate, QueryBuilder, Request};
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    api::{
+        builders::TimePeriod,
+        leaderboard::{LeaderboardApi, TraderRanking},
+    },
     error::DataApiError,
+    portfolio::Portfolio,
     types::{
         Activity, ActivitySortBy, ActivityType, ClosedPosition, ClosedPositionSortBy, Position,
         PositionSortBy, SortDirection, Trade, TradeFilterType, TradeSide, UserValue,
@@ -64,6 +69,62 @@ impl UserApi {
             .send()
             .await
     }
+
+    /// Replay this user's full activity history into a [`Portfolio`]
+    ///
+    /// Holdings are marked at the current price of the user's open positions.
+    /// Pass the same positions to [`Portfolio::reconcile`] to audit the
+    /// server-computed PnL.
+    pub async fn portfolio(&self) -> Result<(Portfolio, Vec<Position>), DataApiError> {
+        let (activity, positions) =
+            tokio::try_join!(self.activity().all(), self.list_positions().all())?;
+        let mut portfolio = Portfolio::from_activity(activity);
+        portfolio.mark_positions(&positions);
+        Ok((portfolio, positions))
+    }
+
+    /// Summarize this user's all-time volume and PnL, markets traded and open
+    /// position value
+    ///
+    /// Volume, PnL and rank come from the all-time leaderboard entry for this
+    /// user; they are zero (and `rank` is `None`) when the user is unranked.
+    /// The three lookups run concurrently.
+    pub async fn summary(&self) -> Result<UserSummary, DataApiError> {
+        let leaderboard = LeaderboardApi {
+            http_client: self.http_client.clone(),
+        };
+        let ranking = leaderboard
+            .user(&self.user_address)
+            .time_period(TimePeriod::All)
+            .send();
+        let (traded, value, ranking) =
+            tokio::try_join!(self.traded(), self.positions_value().send(), ranking)?;
+        Ok(UserSummary {
+            user: self.user_address.clone(),
+            volume: ranking.as_ref().map(|r| r.vol).unwrap_or_default(),
+            pnl: ranking.as_ref().map(|r| r.pnl).unwrap_or_default(),
+            rank: ranking.as_ref().and_then(TraderRanking::rank_number),
+            markets_traded: traded.traded,
+            positions_value: value.first().map(|v| v.value).unwrap_or_default(),
+        })
+    }
+}
+
+/// Headline figures for a user, as returned by [`UserApi::summary`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserSummary {
+    /// User address
+    pub user: String,
+    /// All-time trading volume
+    pub volume: f64,
+    /// All-time profit and loss
+    pub pnl: f64,
+    /// All-time leaderboard rank by PnL, if ranked
+    pub rank: Option<u32>,
+    /// Total count of distinct markets traded
+    pub markets_traded: u64,
+    /// Current value of open positions
+    pub positions_value: f64,
 }
 
 /// User's total markets traded count
@@ -151,6 +212,21 @@ impl ListPositions {
     pub async fn send(self) -> Result<Vec<Position>, DataApiError> {
         self.request.send().await
     }
+
+    /// Execute the request, deserializing into [`typed::Position`](crate::typed::Position)
+    #[cfg(feature = "typed")]
+    pub async fn send_typed(self) -> Result<Vec<crate::typed::Position>, DataApiError> {
+        self.request.cast().send().await
+    }
+}
+
+impl Paginate for ListPositions {
+    type Item = Position;
+    type Error = DataApiError;
+
+    fn into_request(self) -> Request<Vec<Position>, DataApiError> {
+        self.request
+    }
 }
 
 /// Request builder for getting total position value
@@ -234,6 +310,17 @@ impl ListClosedPositions {
     }
 }
 
+impl Paginate for ListClosedPositions {
+    type Item = ClosedPosition;
+    type Error = DataApiError;
+
+    const PAGE_SIZE: u32 = 50;
+
+    fn into_request(self) -> Request<Vec<ClosedPosition>, DataApiError> {
+        self.request
+    }
+}
+
 /// Request builder for listing user trades
 pub struct ListUserTrades {
     request: Request<Vec<Trade>, DataApiError>,
@@ -300,6 +387,21 @@ impl ListUserTrades {
     pub async fn send(self) -> Result<Vec<Trade>, DataApiError> {
         self.request.send().await
     }
+
+    /// Execute the request, deserializing into [`typed::Trade`](crate::typed::Trade)
+    #[cfg(feature = "typed")]
+    pub async fn send_typed(self) -> Result<Vec<crate::typed::Trade>, DataApiError> {
+        self.request.cast().send().await
+    }
+}
+
+impl Paginate for ListUserTrades {
+    type Item = Trade;
+    type Error = DataApiError;
+
+    fn into_request(self) -> Request<Vec<Trade>, DataApiError> {
+        self.request
+    }
 }
 
 /// Request builder for listing user activity
@@ -381,12 +483,37 @@ impl ListActivity {
     pub async fn send(self) -> Result<Vec<Activity>, DataApiError> {
         self.request.send().await
     }
+
+    /// Execute the request, deserializing into [`typed::Activity`](crate::typed::Activity)
+    #[cfg(feature = "typed")]
+    pub async fn send_typed(self) -> Result<Vec<crate::typed::Activity>, DataApiError> {
+        self.request.cast().send().await
+    }
+}
+
+impl Paginate for ListActivity {
+    type Item = Activity;
+    type Error = DataApiError;
+
+    fn into_request(self) -> Request<Vec<Activity>, DataApiError> {
+        self.request
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn list_builders_paginate() {
+        let data = crate::DataApi::new().unwrap();
+        let user = data.user("0x0000000000000000000000000000000000000001");
+        let _positions = user.list_positions().max_items(1_000).into_stream();
+        let _closed = user.closed_positions().paginate();
+        let _trades = user.trades().limit(500).into_stream();
+        let _activity = user.activity().max_items(10);
+    }
+
     #[test]
     fn deserialize_user_traded() {
         let json = r#"{"user": "0xabcdef1234567890", "traded": 42}"#;
@@ -7,6 +7,7 @@ use crate::{
         builders::BuildersApi,
         health::Health,
         holders::Holders,
+        leaderboard::LeaderboardApi,
         live_volume::LiveVolumeApi,
         open_interest::OpenInterestApi,
         trades::Trades,
@@ -95,6 +96,13 @@ impl DataApi {
             http_client: self.http_client.clone(),
         }
     }
+
+    /// Get trader leaderboard namespace
+    pub fn leaderboard(&self) -> LeaderboardApi {
+        LeaderboardApi {
+            http_client: self.http_client.clone(),
+        }
+    }
 }
 
 /// Builder for configuring Data API client
@@ -5,6 +5,8 @@
 //! ## Features
 //!
 //! - User position data retrieval with filtering and pagination
+//! - Automatic pagination of list endpoints via [`Paginate`]
+//! - FIFO and average-cost PnL replayed from activity via [`Portfolio`]
 //! - Type-safe API with idiomatic Rust patterns
 //! - Request builder pattern for flexible, composable queries
 //!
@@ -36,7 +38,12 @@
 pub mod api;
 pub mod client;
 pub mod error;
+pub mod portfolio;
+#[cfg(feature = "typed")]
+pub mod typed;
 pub mod types;
 
 pub use client::{DataApi, DataApiBuilder};
 pub use error::DataApiError;
+pub use polyoxide_core::{candles, Candle, Paginate, Paginator, Tick};
+pub use portfolio::{CostBasis, Portfolio};
@@ -0,0 +1,817 @@
+//! Portfolio PnL engine replaying a wallet's Data API activity.
+//!
+//! [`Portfolio`] rebuilds per-outcome holdings from [`Activity`] records and
+//! tracks realized and unrealized PnL under both FIFO and average-cost
+//! accounting, so the server-computed figures on [`Position`] and
+//! [`UserValue`] can be audited with [`Portfolio::reconcile`].
+//!
+//! Holdings are keyed by `(condition_id, outcome_index)` rather than token ID
+//! because SPLIT and MERGE activity does not carry an asset. Conditions are
+//! assumed to be binary, which holds for every Polymarket market (neg-risk
+//! events are groups of binary conditions).
+
+use std::collections::{BTreeMap, VecDeque};
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::{Activity, ActivityType, Position, UserValue};
+
+/// Quantities below this are treated as zero when matching lots
+const DUST: f64 = 1e-9;
+
+/// Default absolute tolerance used by [`Portfolio::reconcile`]
+pub const DEFAULT_TOLERANCE: f64 = 0.01;
+
+/// Cost-basis accounting method
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum CostBasis {
+    /// First-in, first-out lot matching
+    Fifo,
+    /// Weighted average cost of all shares held (the method the Data API uses)
+    #[default]
+    AverageCost,
+}
+
+/// Key identifying one outcome of a condition
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutcomeKey {
+    /// Condition ID (lowercased)
+    pub condition_id: String,
+    /// Outcome index within the condition
+    pub outcome_index: u32,
+}
+
+impl OutcomeKey {
+    /// Create a key, normalizing the condition ID
+    pub fn new(condition_id: impl AsRef<str>, outcome_index: u32) -> Self {
+        Self {
+            condition_id: condition_id.as_ref().to_ascii_lowercase(),
+            outcome_index,
+        }
+    }
+}
+
+/// Shares acquired at a single unit cost
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Lot {
+    size: f64,
+    unit_cost: f64,
+}
+
+/// Replayed holdings and PnL for one outcome
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AssetPnl {
+    /// Token ID, once seen on a TRADE or REDEEM
+    pub asset: Option<String>,
+    /// Market title, once seen
+    pub title: Option<String>,
+    /// Outcome name, once seen
+    pub outcome: Option<String>,
+    /// Shares currently held
+    pub size: f64,
+    /// Total shares acquired (buys, splits)
+    pub bought: f64,
+    /// Total shares disposed of (sells, merges, redemptions, conversions)
+    pub sold: f64,
+    /// Shares disposed of beyond what the replayed history had acquired,
+    /// usually because activity before the replay window is missing
+    pub unmatched: f64,
+    /// Fees implied by trade prices, already included in the realized figures
+    pub fees: f64,
+    /// Realized PnL under FIFO
+    pub realized_fifo: f64,
+    /// Realized PnL under average cost
+    pub realized_avg: f64,
+    /// Last known price, set by [`Portfolio::mark`] or the last trade
+    pub price: Option<f64>,
+    lots: VecDeque<Lot>,
+    avg_cost: f64,
+}
+
+impl AssetPnl {
+    /// Remaining cost of the shares held
+    pub fn cost_basis(&self, basis: CostBasis) -> f64 {
+        match basis {
+            CostBasis::Fifo => self.lots.iter().map(|l| l.size * l.unit_cost).sum(),
+            CostBasis::AverageCost => self.avg_cost,
+        }
+    }
+
+    /// Average entry price of the shares held, or `None` if flat
+    pub fn avg_price(&self, basis: CostBasis) -> Option<f64> {
+        (self.size > DUST).then(|| self.cost_basis(basis) / self.size)
+    }
+
+    /// Realized PnL under the given method
+    pub fn realized(&self, basis: CostBasis) -> f64 {
+        match basis {
+            CostBasis::Fifo => self.realized_fifo,
+            CostBasis::AverageCost => self.realized_avg,
+        }
+    }
+
+    /// Unrealized PnL at the last known price (zero if unpriced or flat)
+    pub fn unrealized(&self, basis: CostBasis) -> f64 {
+        match self.price {
+            Some(price) if self.size > DUST => self.size * price - self.cost_basis(basis),
+            _ => 0.0,
+        }
+    }
+
+    /// Market value of the shares held at the last known price
+    pub fn value(&self) -> f64 {
+        self.price.map_or(0.0, |p| self.size * p)
+    }
+
+    fn acquire(&mut self, size: f64, cost: f64) {
+        if size <= DUST {
+            return;
+        }
+        self.size += size;
+        self.bought += size;
+        self.avg_cost += cost;
+        self.lots.push_back(Lot {
+            size,
+            unit_cost: cost / size,
+        });
+    }
+
+    fn dispose(&mut self, size: f64, proceeds: f64) {
+        if size <= DUST {
+            return;
+        }
+        let matched = size.min(self.size.max(0.0));
+        let excess = size - matched;
+
+        // FIFO: consume lots oldest first
+        let mut remaining = matched;
+        let mut fifo_cost = 0.0;
+        while remaining > DUST {
+            let Some(lot) = self.lots.front_mut() else {
+                break;
+            };
+            let take = remaining.min(lot.size);
+            fifo_cost += take * lot.unit_cost;
+            lot.size -= take;
+            remaining -= take;
+            if lot.size <= DUST {
+                self.lots.pop_front();
+            }
+        }
+
+        // Average cost: release a pro-rata share of the pooled cost
+        let avg_cost = if self.size > DUST {
+            self.avg_cost * matched / self.size
+        } else {
+            0.0
+        };
+        self.avg_cost -= avg_cost;
+
+        self.size -= matched;
+        if self.size <= DUST {
+            self.size = 0.0;
+            self.avg_cost = 0.0;
+            self.lots.clear();
+        }
+        self.sold += size;
+        self.unmatched += excess;
+        // Unmatched shares carry no known cost, so their proceeds are all gain
+        self.realized_fifo += proceeds - fifo_cost;
+        self.realized_avg += proceeds - avg_cost;
+    }
+
+    fn describe(&mut self, activity: &Activity) {
+        if self.asset.is_none() {
+            self.asset = activity.asset.clone().filter(|a| !a.is_empty());
+        }
+        if self.title.is_none() {
+            self.title = activity.title.clone();
+        }
+        if self.outcome.is_none() {
+            self.outcome = activity.outcome.clone().filter(|o| !o.is_empty());
+        }
+    }
+}
+
+/// Portfolio-wide PnL totals, as returned by [`Portfolio::summary`]
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PnlSummary {
+    /// Realized trading PnL
+    pub realized: f64,
+    /// Unrealized PnL of priced holdings
+    pub unrealized: f64,
+    /// Fees implied by trade prices (already included in `realized`/`unrealized`)
+    pub fees: f64,
+    /// Rewards received
+    pub rewards: f64,
+    /// Market value of priced holdings
+    pub value: f64,
+}
+
+impl PnlSummary {
+    /// Realized + unrealized + rewards
+    pub fn total(&self) -> f64 {
+        self.realized + self.unrealized + self.rewards
+    }
+}
+
+/// Wallet holdings and PnL rebuilt from activity history
+#[derive(Debug, Clone, Default)]
+pub struct Portfolio {
+    assets: BTreeMap<OutcomeKey, AssetPnl>,
+    /// Rewards received (REWARD activity)
+    pub rewards: f64,
+    /// Activity records that could not be attributed to an outcome
+    pub skipped: usize,
+}
+
+impl Portfolio {
+    /// Create an empty portfolio
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replay activity in chronological order
+    ///
+    /// The Data API returns activity newest first; records are reversed and
+    /// then stably sorted by timestamp, so records sharing a timestamp are
+    /// applied oldest first.
+    pub fn from_activity(activity: impl IntoIterator<Item = Activity>) -> Self {
+        let mut activity: Vec<Activity> = activity.into_iter().collect();
+        activity.reverse();
+        activity.sort_by_key(|a| a.timestamp);
+
+        let mut portfolio = Self::new();
+        for record in &activity {
+            portfolio.apply(record);
+        }
+        portfolio
+    }
+
+    /// Apply a single activity record
+    ///
+    /// Records must be applied in chronological order.
+    pub fn apply(&mut self, activity: &Activity) {
+        match activity.activity_type {
+            ActivityType::Trade => self.apply_trade(activity),
+            ActivityType::Split => {
+                // Collateral mints one share of every outcome
+                for index in 0..2 {
+                    self.entry(&activity.condition_id, index)
+                        .acquire(activity.size, activity.usdc_size / 2.0);
+                }
+            }
+            ActivityType::Merge => {
+                // One share of every outcome burns back into collateral
+                for index in 0..2 {
+                    self.entry(&activity.condition_id, index)
+                        .dispose(activity.size, activity.usdc_size / 2.0);
+                }
+            }
+            ActivityType::Redeem => self.apply_redeem(activity),
+            ActivityType::Reward => self.rewards += activity.usdc_size,
+            ActivityType::Conversion => {
+                // Neg-risk conversion burns NO shares for collateral plus YES
+                // shares in sibling markets, which arrive as their own records
+                let asset = self.entry(&activity.condition_id, activity.outcome_index.unwrap_or(1));
+                asset.describe(activity);
+                asset.dispose(activity.size, activity.usdc_size);
+            }
+        }
+    }
+
+    fn apply_trade(&mut self, activity: &Activity) {
+        let Some(index) = self.outcome_index(activity) else {
+            self.skipped += 1;
+            return;
+        };
+        let side = activity.side.as_deref().unwrap_or_default();
+        let asset = self.entry(&activity.condition_id, index);
+        asset.describe(activity);
+        if let Some(price) = activity.price {
+            asset.price = Some(price);
+        }
+
+        // Fees are the gap between the notional and the cash that moved
+        let notional = activity.price.map(|p| p * activity.size);
+        if side.eq_ignore_ascii_case("BUY") {
+            if let Some(notional) = notional {
+                asset.fees += (activity.usdc_size - notional).max(0.0);
+            }
+            asset.acquire(activity.size, activity.usdc_size);
+        } else if side.eq_ignore_ascii_case("SELL") {
+            if let Some(notional) = notional {
+                asset.fees += (notional - activity.usdc_size).max(0.0);
+            }
+            asset.dispose(activity.size, activity.usdc_size);
+        } else {
+            self.skipped += 1;
+        }
+    }
+
+    /// Redemption burns every outcome of the condition; the payout goes to
+    /// the outcome whose holding matches the redeemed size (the winner)
+    fn apply_redeem(&mut self, activity: &Activity) {
+        let condition_id = activity.condition_id.to_ascii_lowercase();
+        let held: Vec<(u32, f64)> = self
+            .assets
+            .iter()
+            .filter(|(key, asset)| key.condition_id == condition_id && asset.size > DUST)
+            .map(|(key, asset)| (key.outcome_index, asset.size))
+            .collect();
+
+        let winner = activity.outcome_index.or_else(|| {
+            held.iter()
+                .min_by(|a, b| {
+                    (a.1 - activity.size)
+                        .abs()
+                        .total_cmp(&(b.1 - activity.size).abs())
+                })
+                .map(|(index, _)| *index)
+        });
+
+        let Some(winner) = winner else {
+            // Nothing held and no outcome given: book the payout as a gain
+            if activity.usdc_size > 0.0 {
+                self.entry(&condition_id, 0)
+                    .dispose(activity.size, activity.usdc_size);
+            }
+            return;
+        };
+
+        for (index, size) in held.iter().filter(|(index, _)| *index != winner) {
+            self.entry(&condition_id, *index).dispose(*size, 0.0);
+        }
+        let asset = self.entry(&condition_id, winner);
+        asset.describe(activity);
+        let size = asset.size.max(activity.size);
+        asset.dispose(size, activity.usdc_size);
+    }
+
+    fn outcome_index(&self, activity: &Activity) -> Option<u32> {
+        activity.outcome_index.or_else(|| {
+            let asset = activity.asset.as_deref()?;
+            self.assets
+                .iter()
+                .find(|(_, a)| a.asset.as_deref() == Some(asset))
+                .map(|(key, _)| key.outcome_index)
+        })
+    }
+
+    fn entry(&mut self, condition_id: &str, outcome_index: u32) -> &mut AssetPnl {
+        self.assets
+            .entry(OutcomeKey::new(condition_id, outcome_index))
+            .or_default()
+    }
+
+    /// Iterate over every outcome the wallet has touched
+    pub fn assets(&self) -> impl Iterator<Item = (&OutcomeKey, &AssetPnl)> {
+        self.assets.iter()
+    }
+
+    /// Look up the replayed PnL of one outcome
+    pub fn asset(&self, condition_id: &str, outcome_index: u32) -> Option<&AssetPnl> {
+        self.assets
+            .get(&OutcomeKey::new(condition_id, outcome_index))
+    }
+
+    /// Set the mark price of an outcome, used for unrealized PnL
+    pub fn mark(&mut self, condition_id: &str, outcome_index: u32, price: f64) {
+        if let Some(asset) = self
+            .assets
+            .get_mut(&OutcomeKey::new(condition_id, outcome_index))
+        {
+            asset.price = Some(price);
+        }
+    }
+
+    /// Mark every held outcome at the `cur_price` of the matching position
+    pub fn mark_positions(&mut self, positions: &[Position]) {
+        for position in positions {
+            self.mark(
+                &position.condition_id,
+                position.outcome_index,
+                position.cur_price,
+            );
+        }
+    }
+
+    /// Portfolio-wide totals under the given method
+    pub fn summary(&self, basis: CostBasis) -> PnlSummary {
+        self.assets.values().fold(
+            PnlSummary {
+                rewards: self.rewards,
+                ..Default::default()
+            },
+            |mut summary, asset| {
+                summary.realized += asset.realized(basis);
+                summary.unrealized += asset.unrealized(basis);
+                summary.fees += asset.fees;
+                summary.value += asset.value();
+                summary
+            },
+        )
+    }
+
+    /// Compare the replayed holdings with server-reported positions and value
+    ///
+    /// Each position's size, average price, realized PnL and cash PnL are
+    /// checked against the average-cost figures (the method the Data API
+    /// uses), priced at the position's `cur_price`. Differences larger than
+    /// `tolerance` are reported, as are positions missing on either side.
+    pub fn reconcile(
+        &self,
+        positions: &[Position],
+        value: Option<&UserValue>,
+        tolerance: f64,
+    ) -> Reconciliation {
+        let basis = CostBasis::AverageCost;
+        let mut discrepancies = Vec::new();
+        let mut computed_value = 0.0;
+        let mut reported = std::collections::HashSet::new();
+
+        for position in positions {
+            let key = OutcomeKey::new(&position.condition_id, position.outcome_index);
+            let mut report = |kind, reported: f64, computed: f64| {
+                if (reported - computed).abs() > tolerance {
+                    discrepancies.push(Discrepancy {
+                        key: Some(key.clone()),
+                        kind,
+                        reported,
+                        computed,
+                    });
+                }
+            };
+
+            let Some(asset) = self.assets.get(&key).filter(|a| a.size > DUST) else {
+                report(DiscrepancyKind::MissingLocally, position.size, 0.0);
+                reported.insert(key);
+                continue;
+            };
+
+            let mut asset = asset.clone();
+            asset.price = Some(position.cur_price);
+            computed_value += asset.value();
+
+            report(DiscrepancyKind::Size, position.size, asset.size);
+            report(
+                DiscrepancyKind::AvgPrice,
+                position.avg_price,
+                asset.avg_price(basis).unwrap_or_default(),
+            );
+            report(
+                DiscrepancyKind::RealizedPnl,
+                position.realized_pnl,
+                asset.realized(basis),
+            );
+            report(
+                DiscrepancyKind::CashPnl,
+                position.cash_pnl,
+                asset.unrealized(basis),
+            );
+            reported.insert(key);
+        }
+
+        for (key, asset) in &self.assets {
+            if asset.size > tolerance && !reported.contains(key) {
+                discrepancies.push(Discrepancy {
+                    key: Some(key.clone()),
+                    kind: DiscrepancyKind::MissingRemotely,
+                    reported: 0.0,
+                    computed: asset.size,
+                });
+            }
+        }
+
+        if let Some(value) = value {
+            if (value.value - computed_value).abs() > tolerance {
+                discrepancies.push(Discrepancy {
+                    key: None,
+                    kind: DiscrepancyKind::TotalValue,
+                    reported: value.value,
+                    computed: computed_value,
+                });
+            }
+        }
+
+        Reconciliation {
+            checked: positions.len(),
+            discrepancies,
+        }
+    }
+}
+
+/// Field that disagreed during reconciliation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DiscrepancyKind {
+    /// Share count
+    Size,
+    /// Average entry price
+    AvgPrice,
+    /// Realized PnL
+    RealizedPnl,
+    /// Unrealized (cash) PnL at the position's current price
+    CashPnl,
+    /// The API reports a position the replay does not hold
+    MissingLocally,
+    /// The replay holds a position the API does not report
+    MissingRemotely,
+    /// Total portfolio value differs from [`UserValue`]
+    TotalValue,
+}
+
+/// A single disagreement between the replay and the Data API
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Discrepancy {
+    /// Outcome concerned, or `None` for portfolio-wide checks
+    pub key: Option<OutcomeKey>,
+    /// What disagreed
+    pub kind: DiscrepancyKind,
+    /// Value reported by the Data API
+    pub reported: f64,
+    /// Value computed from activity
+    pub computed: f64,
+}
+
+impl Discrepancy {
+    /// Computed minus reported
+    pub fn difference(&self) -> f64 {
+        self.computed - self.reported
+    }
+}
+
+/// Result of [`Portfolio::reconcile`]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Reconciliation {
+    /// Number of API positions checked
+    pub checked: usize,
+    /// Disagreements found
+    pub discrepancies: Vec<Discrepancy>,
+}
+
+impl Reconciliation {
+    /// Whether the replay agrees with the API within tolerance
+    pub fn is_clean(&self) -> bool {
+        self.discrepancies.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn activity(
+        activity_type: &str,
+        timestamp: i64,
+        size: f64,
+        usdc_size: f64,
+        extra: serde_json::Value,
+    ) -> Activity {
+        let mut json = serde_json::json!({
+            "proxyWallet": "0xwallet",
+            "timestamp": timestamp,
+            "conditionId": "0xABC",
+            "type": activity_type,
+            "size": size,
+            "usdcSize": usdc_size,
+        });
+        json.as_object_mut()
+            .unwrap()
+            .extend(extra.as_object().unwrap().clone());
+        serde_json::from_value(json).unwrap()
+    }
+
+    fn trade(timestamp: i64, side: &str, size: f64, price: f64) -> Activity {
+        activity(
+            "TRADE",
+            timestamp,
+            size,
+            size * price,
+            serde_json::json!({
+                "side": side,
+                "price": price,
+                "asset": "111",
+                "outcomeIndex": 0,
+                "outcome": "Yes",
+            }),
+        )
+    }
+
+    fn position(size: f64, avg_price: f64, cur_price: f64, cash_pnl: f64) -> Position {
+        serde_json::from_value(serde_json::json!({
+            "proxyWallet": "0xwallet",
+            "asset": "111",
+            "conditionId": "0xabc",
+            "size": size,
+            "avgPrice": avg_price,
+            "initialValue": size * avg_price,
+            "currentValue": size * cur_price,
+            "cashPnl": cash_pnl,
+            "percentPnl": 0.0,
+            "totalBought": size,
+            "realizedPnl": 0.0,
+            "percentRealizedPnl": 0.0,
+            "curPrice": cur_price,
+            "redeemable": false,
+            "mergeable": false,
+            "title": "Test?",
+            "slug": "test",
+            "outcome": "Yes",
+            "outcomeIndex": 0,
+            "oppositeOutcome": "No",
+            "oppositeAsset": "222",
+            "negativeRisk": false,
+        }))
+        .unwrap()
+    }
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    // ── Trades ──────────────────────────────────────────────────
+
+    #[test]
+    fn fifo_and_average_cost_diverge() {
+        // Newest first, as the API returns them
+        let portfolio = Portfolio::from_activity(vec![
+            trade(3, "SELL", 10.0, 0.6),
+            trade(2, "BUY", 10.0, 0.5),
+            trade(1, "BUY", 10.0, 0.3),
+        ]);
+        let asset = portfolio.asset("0xabc", 0).unwrap();
+
+        assert!(close(asset.size, 10.0));
+        assert_eq!(asset.asset.as_deref(), Some("111"));
+        // FIFO sells the 0.30 lot first
+        assert!(close(asset.realized(CostBasis::Fifo), 3.0));
+        assert!(close(asset.avg_price(CostBasis::Fifo).unwrap(), 0.5));
+        // Average cost sells at 0.40
+        assert!(close(asset.realized(CostBasis::AverageCost), 2.0));
+        assert!(close(asset.avg_price(CostBasis::AverageCost).unwrap(), 0.4));
+        // Marked at the last trade price
+        assert!(close(asset.unrealized(CostBasis::Fifo), 1.0));
+        assert!(close(asset.unrealized(CostBasis::AverageCost), 2.0));
+    }
+
+    #[test]
+    fn same_timestamp_applied_oldest_first() {
+        // A fill and its immediate exit land in the same second
+        let portfolio = Portfolio::from_activity(vec![
+            trade(1, "SELL", 10.0, 0.6),
+            trade(1, "BUY", 10.0, 0.5),
+        ]);
+        let asset = portfolio.asset("0xabc", 0).unwrap();
+        assert_eq!(asset.size, 0.0);
+        assert_eq!(asset.unmatched, 0.0);
+        assert!(close(asset.realized(CostBasis::Fifo), 1.0));
+    }
+
+    #[test]
+    fn implied_fees_tracked() {
+        let mut buy = trade(1, "BUY", 10.0, 0.5);
+        buy.usdc_size = 5.1;
+        let portfolio = Portfolio::from_activity(vec![buy]);
+        let asset = portfolio.asset("0xabc", 0).unwrap();
+        assert!(close(asset.fees, 0.1));
+        assert!(close(asset.avg_price(CostBasis::Fifo).unwrap(), 0.51));
+    }
+
+    #[test]
+    fn oversell_is_unmatched_gain() {
+        let portfolio =
+            Portfolio::from_activity(vec![trade(1, "BUY", 5.0, 0.5), trade(2, "SELL", 8.0, 0.5)]);
+        let asset = portfolio.asset("0xabc", 0).unwrap();
+        assert_eq!(asset.size, 0.0);
+        assert!(close(asset.unmatched, 3.0));
+        assert!(close(asset.realized(CostBasis::Fifo), 1.5));
+        assert!(close(asset.realized(CostBasis::AverageCost), 1.5));
+    }
+
+    #[test]
+    fn unattributable_trade_skipped() {
+        let mut portfolio = Portfolio::new();
+        portfolio.apply(&activity(
+            "TRADE",
+            1,
+            1.0,
+            0.5,
+            serde_json::json!({"side": "BUY", "asset": "999"}),
+        ));
+        assert_eq!(portfolio.skipped, 1);
+        assert_eq!(portfolio.assets().count(), 0);
+    }
+
+    // ── Splits, merges, redemptions ─────────────────────────────
+
+    #[test]
+    fn split_then_merge_is_flat() {
+        let portfolio = Portfolio::from_activity(vec![
+            activity("SPLIT", 1, 10.0, 10.0, serde_json::json!({})),
+            activity("MERGE", 2, 10.0, 10.0, serde_json::json!({})),
+        ]);
+        for index in 0..2 {
+            let asset = portfolio.asset("0xabc", index).unwrap();
+            assert_eq!(asset.size, 0.0);
+            assert!(close(asset.realized(CostBasis::Fifo), 0.0));
+        }
+    }
+
+    #[test]
+    fn redeem_pays_winner_and_writes_off_loser() {
+        let portfolio = Portfolio::from_activity(vec![
+            activity("SPLIT", 1, 10.0, 10.0, serde_json::json!({})),
+            trade(2, "SELL", 4.0, 0.7),
+            activity("REDEEM", 3, 6.0, 6.0, serde_json::json!({})),
+        ]);
+        let yes = portfolio.asset("0xabc", 0).unwrap();
+        let no = portfolio.asset("0xabc", 1).unwrap();
+
+        assert_eq!(yes.size, 0.0);
+        assert_eq!(no.size, 0.0);
+        // YES: 4 sold at 0.7 and 6 redeemed at 1.0 against a 0.5 basis
+        assert!(close(yes.realized(CostBasis::Fifo), 0.8 + 3.0));
+        assert!(close(no.realized(CostBasis::Fifo), -5.0));
+        assert!(close(portfolio.summary(CostBasis::Fifo).realized, -1.2));
+    }
+
+    #[test]
+    fn rewards_and_conversions() {
+        let portfolio = Portfolio::from_activity(vec![
+            activity("SPLIT", 1, 10.0, 10.0, serde_json::json!({})),
+            activity("REWARD", 2, 0.0, 1.25, serde_json::json!({})),
+            activity("CONVERSION", 3, 10.0, 0.0, serde_json::json!({})),
+        ]);
+        let summary = portfolio.summary(CostBasis::AverageCost);
+        assert!(close(summary.rewards, 1.25));
+        assert_eq!(portfolio.asset("0xabc", 1).unwrap().size, 0.0);
+        assert!(close(summary.realized, -5.0));
+    }
+
+    // ── Reconciliation ──────────────────────────────────────────
+
+    #[test]
+    fn reconcile_matching_positions_is_clean() {
+        let portfolio = Portfolio::from_activity(vec![trade(1, "BUY", 10.0, 0.4)]);
+        let positions = vec![position(10.0, 0.4, 0.6, 2.0)];
+        let value = UserValue {
+            user: "0xwallet".into(),
+            value: 6.0,
+        };
+
+        let result = portfolio.reconcile(&positions, Some(&value), DEFAULT_TOLERANCE);
+        assert_eq!(result.checked, 1);
+        assert!(result.is_clean(), "{:?}", result.discrepancies);
+    }
+
+    #[test]
+    fn reconcile_reports_discrepancies() {
+        let portfolio = Portfolio::from_activity(vec![
+            trade(1, "BUY", 10.0, 0.4),
+            activity(
+                "TRADE",
+                2,
+                5.0,
+                2.5,
+                serde_json::json!({"side": "BUY", "price": 0.5, "conditionId": "0xdef", "outcomeIndex": 1}),
+            ),
+        ]);
+        let positions = vec![position(12.0, 0.4, 0.6, 2.0)];
+        let value = UserValue {
+            user: "0xwallet".into(),
+            value: 100.0,
+        };
+
+        let result = portfolio.reconcile(&positions, Some(&value), DEFAULT_TOLERANCE);
+        let kinds: Vec<DiscrepancyKind> = result.discrepancies.iter().map(|d| d.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                DiscrepancyKind::Size,
+                DiscrepancyKind::MissingRemotely,
+                DiscrepancyKind::TotalValue,
+            ]
+        );
+        assert!(close(result.discrepancies[0].difference(), -2.0));
+        assert_eq!(
+            result.discrepancies[1].key,
+            Some(OutcomeKey::new("0xDEF", 1))
+        );
+    }
+
+    #[test]
+    fn reconcile_reports_missing_locally() {
+        let portfolio = Portfolio::new();
+        let result = portfolio.reconcile(&[position(3.0, 0.5, 0.5, 0.0)], None, DEFAULT_TOLERANCE);
+        assert_eq!(
+            result.discrepancies[0].kind,
+            DiscrepancyKind::MissingLocally
+        );
+    }
+}
@@ -0,0 +1,301 @@
+//! Typed Data API models with exact amounts and real timestamps.
+//!
+//! Parallel to [`crate::types`]: the same responses deserialized with
+//! [`Decimal`] sizes, prices and USD values, [`DateTime<Utc>`] timestamps and
+//! [`TradeSide`] sides. Request builders return these from `send_typed()`.
+//!
+//! Requires the `typed` feature.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::types::{ActivityType, TradeSide};
+
+/// Trade record
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Trade {
+    /// Proxy wallet address
+    pub proxy_wallet: String,
+    /// Trade side (BUY or SELL)
+    pub side: TradeSide,
+    /// Asset identifier (token ID)
+    pub asset: String,
+    /// Condition ID of the market
+    pub condition_id: String,
+    /// Trade size (number of shares)
+    pub size: Decimal,
+    /// Trade price
+    pub price: Decimal,
+    /// Trade time
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub timestamp: DateTime<Utc>,
+    /// Market title
+    pub title: String,
+    /// Market slug
+    pub slug: String,
+    /// Market icon URL
+    pub icon: Option<String>,
+    /// Event slug
+    pub event_slug: Option<String>,
+    /// Outcome name (e.g., "Yes", "No")
+    pub outcome: String,
+    /// Outcome index (0 or 1 for binary markets)
+    pub outcome_index: u32,
+    /// User display name
+    pub name: Option<String>,
+    /// User pseudonym
+    pub pseudonym: Option<String>,
+    /// User bio
+    pub bio: Option<String>,
+    /// User profile image URL
+    pub profile_image: Option<String>,
+    /// Optimized profile image URL
+    pub profile_image_optimized: Option<String>,
+    /// Transaction hash
+    pub transaction_hash: Option<String>,
+}
+
+impl Trade {
+    /// USDC notional, `size * price`
+    pub fn notional(&self) -> Decimal {
+        self.size * self.price
+    }
+}
+
+/// User activity record
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Activity {
+    /// Proxy wallet address
+    pub proxy_wallet: String,
+    /// Activity time
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub timestamp: DateTime<Utc>,
+    /// Condition ID of the market
+    pub condition_id: String,
+    /// Activity type
+    #[serde(rename = "type")]
+    pub activity_type: ActivityType,
+    /// Token quantity
+    pub size: Decimal,
+    /// USD value
+    pub usdc_size: Decimal,
+    /// On-chain transaction hash
+    pub transaction_hash: Option<String>,
+    /// Execution price
+    pub price: Option<Decimal>,
+    /// Asset identifier (token ID)
+    pub asset: Option<String>,
+    /// Trade side, `None` for non-trade activity
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub side: Option<TradeSide>,
+    /// Outcome index (0 or 1 for binary markets)
+    pub outcome_index: Option<u32>,
+    /// Market title
+    pub title: Option<String>,
+    /// Market slug
+    pub slug: Option<String>,
+    /// Market icon URL
+    pub icon: Option<String>,
+    /// Outcome name (e.g., "Yes", "No")
+    pub outcome: Option<String>,
+    /// User display name
+    pub name: Option<String>,
+    /// User pseudonym
+    pub pseudonym: Option<String>,
+    /// User bio
+    pub bio: Option<String>,
+    /// User profile image URL
+    pub profile_image: Option<String>,
+    /// Optimized profile image URL
+    pub profile_image_optimized: Option<String>,
+}
+
+/// User position in a market
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Position {
+    /// Proxy wallet address
+    pub proxy_wallet: String,
+    /// Asset identifier (token ID)
+    pub asset: String,
+    /// Condition ID of the market
+    pub condition_id: String,
+    /// Position size (number of shares)
+    pub size: Decimal,
+    /// Average entry price
+    pub avg_price: Decimal,
+    /// Initial value of position
+    pub initial_value: Decimal,
+    /// Current value of position
+    pub current_value: Decimal,
+    /// Cash profit and loss
+    pub cash_pnl: Decimal,
+    /// Percentage profit and loss
+    pub percent_pnl: Decimal,
+    /// Total amount bought
+    pub total_bought: Decimal,
+    /// Realized profit and loss
+    pub realized_pnl: Decimal,
+    /// Percentage realized P&L
+    pub percent_realized_pnl: Decimal,
+    /// Current market price
+    pub cur_price: Decimal,
+    /// Whether position is redeemable
+    pub redeemable: bool,
+    /// Whether position is mergeable
+    pub mergeable: bool,
+    /// Market title
+    pub title: String,
+    /// Market slug
+    pub slug: String,
+    /// Market icon URL
+    pub icon: Option<String>,
+    /// Event slug
+    pub event_slug: Option<String>,
+    /// Outcome name (e.g., "Yes", "No")
+    pub outcome: String,
+    /// Outcome index (0 or 1 for binary markets)
+    pub outcome_index: u32,
+    /// Opposite outcome name
+    pub opposite_outcome: String,
+    /// Opposite outcome asset ID
+    pub opposite_asset: String,
+    /// Market end date
+    pub end_date: Option<String>,
+    /// Whether this is a negative risk market
+    pub negative_risk: bool,
+}
+
+/// Market holders response containing token and its holders
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all(deserialize = "camelCase"))]
+pub struct MarketHolders {
+    /// Token identifier
+    pub token: String,
+    /// List of holders for this token
+    pub holders: Vec<Holder>,
+}
+
+/// Individual holder of a market token
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all(deserialize = "camelCase"))]
+pub struct Holder {
+    /// Proxy wallet address
+    pub proxy_wallet: String,
+    /// User bio
+    pub bio: Option<String>,
+    /// Asset identifier (token ID)
+    pub asset: Option<String>,
+    /// User pseudonym
+    pub pseudonym: Option<String>,
+    /// Amount held
+    pub amount: Decimal,
+    /// Whether username is displayed publicly
+    pub display_username_public: Option<bool>,
+    /// Outcome index (0 or 1 for binary markets)
+    pub outcome_index: u32,
+    /// User display name
+    pub name: Option<String>,
+    /// User profile image URL
+    pub profile_image: Option<String>,
+    /// Optimized profile image URL
+    pub profile_image_optimized: Option<String>,
+}
+
+/// The API sends `""` as the side of non-trade activity
+fn empty_as_none<'de, D>(deserializer: D) -> Result<Option<TradeSide>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<String>::deserialize(deserializer)?.as_deref() {
+        None | Some("") => Ok(None),
+        Some(side) if side.eq_ignore_ascii_case("BUY") => Ok(Some(TradeSide::Buy)),
+        Some(side) if side.eq_ignore_ascii_case("SELL") => Ok(Some(TradeSide::Sell)),
+        Some(other) => Err(serde::de::Error::unknown_variant(other, &["BUY", "SELL"])),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn activity(side: serde_json::Value) -> Result<Activity, serde_json::Error> {
+        serde_json::from_value(serde_json::json!({
+            "proxyWallet": "0xabc",
+            "timestamp": 1_700_000_000,
+            "conditionId": "0xcond",
+            "type": "REDEEM",
+            "size": 12.5,
+            "usdcSize": 12.5,
+            "side": side,
+        }))
+    }
+
+    #[test]
+    fn trade_amounts_are_exact() {
+        let trade: Trade = serde_json::from_value(serde_json::json!({
+            "proxyWallet": "0xabc",
+            "side": "SELL",
+            "asset": "111",
+            "conditionId": "0xcond",
+            "size": 1234.56,
+            "price": 0.47,
+            "timestamp": 1_700_000_000,
+            "title": "Market",
+            "slug": "market",
+            "outcome": "No",
+            "outcomeIndex": 1,
+        }))
+        .unwrap();
+
+        assert_eq!(trade.side, TradeSide::Sell);
+        assert_eq!(trade.size, Decimal::from_str("1234.56").unwrap());
+        assert_eq!(trade.price, Decimal::from_str("0.47").unwrap());
+        assert_eq!(trade.notional(), Decimal::from_str("580.2432").unwrap());
+        assert_eq!(trade.timestamp.timestamp(), 1_700_000_000);
+    }
+
+    #[test]
+    fn activity_side_maps_empty_to_none() {
+        assert_eq!(activity(serde_json::json!("")).unwrap().side, None);
+        assert_eq!(activity(serde_json::Value::Null).unwrap().side, None);
+        assert_eq!(
+            activity(serde_json::json!("BUY")).unwrap().side,
+            Some(TradeSide::Buy)
+        );
+        assert!(activity(serde_json::json!("HOLD")).is_err());
+    }
+
+    #[test]
+    fn activity_side_may_be_absent() {
+        let activity: Activity = serde_json::from_value(serde_json::json!({
+            "proxyWallet": "0xabc",
+            "timestamp": 1_700_000_000,
+            "conditionId": "0xcond",
+            "type": "REWARD",
+            "size": 0,
+            "usdcSize": 1.25,
+        }))
+        .unwrap();
+        assert_eq!(activity.side, None);
+        assert_eq!(activity.usdc_size, Decimal::from_str("1.25").unwrap());
+    }
+
+    #[test]
+    fn holder_amount_is_decimal() {
+        let holders: Vec<MarketHolders> = serde_json::from_value(serde_json::json!([{
+            "token": "111",
+            "holders": [{ "proxyWallet": "0xabc", "amount": 1500.25, "outcomeIndex": 0 }],
+        }]))
+        .unwrap();
+        assert_eq!(
+            holders[0].holders[0].amount,
+            Decimal::from_str("1500.25").unwrap()
+        );
+    }
+}
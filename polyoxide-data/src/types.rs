@@ -1,3 +1,4 @@
+use polyoxide_core::Tick;
 use serde::{Deserialize, Serialize};
 
 /// User's total position value
@@ -240,6 +241,19 @@ pub struct Trade {
     pub transaction_hash: Option<String>,
 }
 
+/// A trade as a candle tick, with its size as volume
+///
+/// Aggregate with [`candles`](crate::candles) for volume-weighted OHLCV bars.
+impl From<&Trade> for Tick {
+    fn from(trade: &Trade) -> Self {
+        Self {
+            timestamp: trade.timestamp,
+            price: trade.price,
+            volume: trade.size,
+        }
+    }
+}
+
 /// Activity type
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -789,6 +803,11 @@ mod tests {
         assert_eq!(trade.side, TradeSide::Sell);
         assert!(trade.name.is_none());
         assert!(trade.transaction_hash.is_none());
+
+        let tick = Tick::from(&trade);
+        assert_eq!(tick.timestamp, 1700002000);
+        assert_eq!(tick.price, 0.30);
+        assert_eq!(tick.volume, 25.0);
     }
 
     #[test]
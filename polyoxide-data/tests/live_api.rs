@@ -71,6 +71,24 @@ async fn live_list_trades() {
     assert!(!trades.is_empty(), "should return at least one trade");
 }
 
+#[cfg(feature = "typed")]
+#[tokio::test]
+#[ignore]
+async fn live_list_trades_typed() {
+    let client = client();
+    let trades = client
+        .trades()
+        .list()
+        .limit(5)
+        .send_typed()
+        .await
+        .expect("list typed trades");
+    assert!(!trades.is_empty(), "should return at least one trade");
+    assert!(trades
+        .iter()
+        .all(|t| t.notional() >= rust_decimal::Decimal::ZERO));
+}
+
 // ── User endpoints ───────────────────────────────────────────────
 
 #[tokio::test]
@@ -86,6 +104,20 @@ async fn live_user_traded() {
     assert_eq!(traded.user, TEST_USER, "should echo back the user address");
 }
 
+#[tokio::test]
+#[ignore]
+async fn live_user_summary() {
+    let client = client();
+    let summary = client
+        .user(TEST_USER)
+        .summary()
+        .await
+        .expect("user summary should deserialize");
+    assert_eq!(summary.user, TEST_USER);
+    assert!(summary.positions_value >= 0.0);
+    assert!(summary.volume >= 0.0);
+}
+
 #[tokio::test]
 #[ignore]
 async fn live_user_positions() {
@@ -119,6 +151,30 @@ async fn live_builder_leaderboard() {
     );
 }
 
+// ── Leaderboard ──────────────────────────────────────────────────
+
+#[tokio::test]
+#[ignore]
+async fn live_trader_leaderboard() {
+    let client = client();
+    let traders = client
+        .leaderboard()
+        .traders()
+        .limit(5)
+        .send()
+        .await
+        .expect("trader leaderboard");
+    assert!(!traders.is_empty(), "should return at least one trader");
+
+    let rank = client
+        .leaderboard()
+        .user(&traders[0].proxy_wallet)
+        .send()
+        .await
+        .expect("trader rank");
+    assert!(rank.is_some(), "top trader should have a rank");
+}
+
 // ── User: positions_value ───────────────────────────────────────
 
 #[tokio::test]
@@ -246,3 +302,19 @@ async fn live_builder_volume() {
         "should return at least one builder volume entry"
     );
 }
+
+// ── Portfolio ────────────────────────────────────────────────────
+
+#[tokio::test]
+#[ignore]
+async fn live_portfolio_replay() {
+    let client = client();
+    let user = client.user(TEST_USER);
+    let (portfolio, positions) = user.portfolio().await.expect("portfolio replay");
+    let report = portfolio.reconcile(
+        &positions,
+        None,
+        polyoxide_data::portfolio::DEFAULT_TOLERANCE,
+    );
+    assert_eq!(report.checked, positions.len());
+}
@@ -1,6 +1,9 @@
-use polyoxide_core::{HttpClient, QueryBuilder, Request};
+use polyoxide_core::{HttpClient, Paginate, QueryBuilder, Request};
 
-use crate::{error::GammaError, types::Comment};
+use crate::{
+    error::GammaError,
+    types::{Comment, CommentRecord, CommentThread},
+};
 
 /// Comments namespace for comment-related operations
 #[derive(Clone)]
@@ -15,6 +18,97 @@ impl Comments {
             request: Request::new(self.http_client.clone(), "/comments"),
         }
     }
+
+    /// Get a comment by ID
+    pub fn get(&self, id: impl Into<String>) -> GetComment {
+        let id = id.into();
+        GetComment {
+            request: Request::new(
+                self.http_client.clone(),
+                format!("/comments/{}", urlencoding::encode(&id)),
+            ),
+            id,
+        }
+    }
+
+    /// List comments written by a user, across all markets, events and series
+    pub fn by_user(&self, user_address: impl Into<String>) -> ListUserComments {
+        ListUserComments {
+            request: Request::new(
+                self.http_client.clone(),
+                format!(
+                    "/comments/user_address/{}",
+                    urlencoding::encode(&user_address.into())
+                ),
+            ),
+        }
+    }
+}
+
+/// Request builder for getting a single comment
+pub struct GetComment {
+    request: Request<Vec<Comment>, GammaError>,
+    id: String,
+}
+
+impl GetComment {
+    /// Include the author's positions
+    pub fn get_positions(mut self, include: bool) -> Self {
+        self.request = self.request.query("get_positions", include);
+        self
+    }
+
+    /// Execute the request, returning `None` if the comment does not exist
+    pub async fn send(self) -> Result<Option<Comment>, GammaError> {
+        // The endpoint answers with a list (the comment, possibly with its replies)
+        let comments = self.request.send().await?;
+        Ok(comments.into_iter().find(|c| c.id == self.id))
+    }
+}
+
+/// Request builder for listing a user's comments
+pub struct ListUserComments {
+    request: Request<Vec<Comment>, GammaError>,
+}
+
+impl ListUserComments {
+    /// Set maximum number of results (minimum: 0)
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.request = self.request.query("limit", limit);
+        self
+    }
+
+    /// Set pagination offset (minimum: 0)
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.request = self.request.query("offset", offset);
+        self
+    }
+
+    /// Set order fields (comma-separated list)
+    pub fn order(mut self, order: impl Into<String>) -> Self {
+        self.request = self.request.query("order", order.into());
+        self
+    }
+
+    /// Set sort direction
+    pub fn ascending(mut self, ascending: bool) -> Self {
+        self.request = self.request.query("ascending", ascending);
+        self
+    }
+
+    /// Execute the request
+    pub async fn send(self) -> Result<Vec<Comment>, GammaError> {
+        self.request.send().await
+    }
+}
+
+impl Paginate for ListUserComments {
+    type Item = Comment;
+    type Error = GammaError;
+
+    fn into_request(self) -> Request<Vec<Comment>, GammaError> {
+        self.request
+    }
 }
 
 /// Request builder for listing comments
@@ -75,4 +169,48 @@ impl ListComments {
     pub async fn send(self) -> Result<Vec<Comment>, GammaError> {
         self.request.send().await
     }
+
+    /// Fetch every matching comment and rebuild reply threads
+    pub async fn threads(self) -> Result<Vec<CommentThread>, GammaError> {
+        Ok(CommentThread::build(self.all().await?))
+    }
+
+    /// Fetch every matching comment with author positions and flatten them
+    /// into [`CommentRecord`]s, ready for sentiment analysis
+    pub async fn export(self) -> Result<Vec<CommentRecord>, GammaError> {
+        let threads = self.get_positions(true).threads().await?;
+        Ok(CommentRecord::from_threads(&threads))
+    }
+}
+
+impl Paginate for ListComments {
+    type Item = Comment;
+    type Error = GammaError;
+
+    fn into_request(self) -> Request<Vec<Comment>, GammaError> {
+        self.request
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Gamma;
+
+    #[test]
+    fn test_comment_builders_chain() {
+        let comments = Gamma::new().unwrap().comments();
+        let _list = comments
+            .list()
+            .parent_entity_type("Event")
+            .parent_entity_id(1)
+            .get_positions(true)
+            .holders_only(false);
+        let _get = comments.get("123").get_positions(true);
+        let _by_user = comments
+            .by_user("0xabc")
+            .limit(10)
+            .offset(0)
+            .order("createdAt")
+            .ascending(false);
+    }
 }
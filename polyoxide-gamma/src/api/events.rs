@@ -1,4 +1,4 @@
-use polyoxide_core::{HttpClient, QueryBuilder, Request};
+use polyoxide_core::{HttpClient, Paginate, QueryBuilder, Request};
 
 use crate::{error::GammaError, types::Event};
 
@@ -95,6 +95,12 @@ impl ListEvents {
         self
     }
 
+    /// Filter by series ID
+    pub fn series_id(mut self, series_id: impl Into<String>) -> Self {
+        self.request = self.request.query("series_id", series_id.into());
+        self
+    }
+
     /// Filter by tag slug
     pub fn tag_slug(mut self, slug: impl Into<String>) -> Self {
         self.request = self.request.query("tag_slug", slug.into());
@@ -209,6 +215,15 @@ impl ListEvents {
     }
 }
 
+impl Paginate for ListEvents {
+    type Item = Event;
+    type Error = GammaError;
+
+    fn into_request(self) -> Request<Vec<Event>, GammaError> {
+        self.request
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::Gamma;
@@ -232,6 +247,7 @@ mod tests {
             .exclude_tag_id(vec![99i64])
             .slug(vec!["slug-a"])
             .tag_slug("politics")
+            .series_id("10187")
             .related_tags(true)
             .active(true)
             .archived(false)
@@ -1,4 +1,4 @@
-use polyoxide_core::{HttpClient, QueryBuilder, Request};
+use polyoxide_core::{HttpClient, Paginate, QueryBuilder, Request};
 
 use crate::{error::GammaError, types::Market};
 
@@ -31,8 +31,32 @@ impl Markets {
             request: Request::new(self.http_client.clone(), "/markets"),
         }
     }
+
+    /// Fetch every market whose condition ID is in `condition_ids`.
+    ///
+    /// The IDs are looked up [`CONDITION_ID_CHUNK`] at a time and each lookup
+    /// is paged to the end; `filter` configures every lookup, e.g.
+    /// `|list| list.closed(true)`.
+    pub async fn by_condition_ids(
+        &self,
+        condition_ids: &[impl AsRef<str>],
+        filter: impl Fn(ListMarkets) -> ListMarkets,
+    ) -> Result<Vec<Market>, GammaError> {
+        let mut markets = Vec::new();
+        for chunk in condition_ids.chunks(CONDITION_ID_CHUNK) {
+            let list = self
+                .list()
+                .condition_ids(chunk.iter().map(|id| id.as_ref()));
+            markets.extend(filter(list).all().await?);
+        }
+        Ok(markets)
+    }
 }
 
+/// Condition IDs per lookup in [`Markets::by_condition_ids`], keeping the
+/// query string a sane length
+pub const CONDITION_ID_CHUNK: usize = 50;
+
 /// Request builder for listing markets
 pub struct ListMarkets {
     request: Request<Vec<Market>, GammaError>,
@@ -222,6 +246,15 @@ impl ListMarkets {
     }
 }
 
+impl Paginate for ListMarkets {
+    type Item = Market;
+    type Error = GammaError;
+
+    fn into_request(self) -> Request<Vec<Market>, GammaError> {
+        self.request
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::Gamma;
@@ -286,4 +319,31 @@ mod tests {
         let _req1 = gamma().markets().get_by_slug("my-slug");
         let _req2 = gamma().markets().get_by_slug(String::from("my-slug"));
     }
+
+    #[test]
+    fn test_list_markets_paginates() {
+        use polyoxide_core::Paginate;
+
+        let _stream = gamma().markets().list().closed(false).into_stream();
+        let _capped = gamma()
+            .markets()
+            .list()
+            .limit(500)
+            .max_items(1_000)
+            .page_size(250);
+    }
+
+    #[tokio::test]
+    async fn test_by_condition_ids_without_ids_sends_nothing() {
+        let gamma = Gamma::builder()
+            .base_url("http://127.0.0.1:1")
+            .build()
+            .unwrap();
+        let markets = gamma
+            .markets()
+            .by_condition_ids(&[] as &[String], |list| list.closed(true))
+            .await
+            .unwrap();
+        assert!(markets.is_empty());
+    }
 }
@@ -2,6 +2,7 @@ pub mod comments;
 pub mod events;
 pub mod health;
 pub mod markets;
+pub mod search;
 pub mod series;
 pub mod sports;
 pub mod tags;
@@ -0,0 +1,138 @@
+use polyoxide_core::{HttpClient, QueryBuilder, Request};
+
+use crate::{error::GammaError, types::SearchResults};
+
+/// Search namespace for full-text search across events, markets, tags and profiles
+#[derive(Clone)]
+pub struct Search {
+    pub(crate) http_client: HttpClient,
+}
+
+impl Search {
+    /// Search for events (with their markets), tags and profiles matching `q`
+    pub fn query(&self, q: impl Into<String>) -> PublicSearch {
+        PublicSearch {
+            request: Request::new(self.http_client.clone(), "/public-search").query("q", q.into()),
+        }
+    }
+}
+
+/// Event status filter for search results
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchStatus {
+    /// Only events still open for trading
+    Active,
+    /// Only closed or resolved events
+    Closed,
+}
+
+impl std::fmt::Display for SearchStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Active => write!(f, "active"),
+            Self::Closed => write!(f, "closed"),
+        }
+    }
+}
+
+/// Request builder for public search
+pub struct PublicSearch {
+    request: Request<SearchResults, GammaError>,
+}
+
+impl PublicSearch {
+    /// Set maximum number of results per result type
+    pub fn limit_per_type(mut self, limit: u32) -> Self {
+        self.request = self.request.query("limit_per_type", limit);
+        self
+    }
+
+    /// Set results page (1-based)
+    pub fn page(mut self, page: u32) -> Self {
+        self.request = self.request.query("page", page);
+        self
+    }
+
+    /// Filter events by status
+    pub fn events_status(mut self, status: SearchStatus) -> Self {
+        self.request = self.request.query("events_status", status);
+        self
+    }
+
+    /// Keep closed markets inside matching events
+    pub fn keep_closed_markets(mut self, keep: bool) -> Self {
+        self.request = self.request.query("keep_closed_markets", u8::from(keep));
+        self
+    }
+
+    /// Restrict events to the given tag slugs
+    pub fn events_tag(mut self, tags: impl IntoIterator<Item = impl ToString>) -> Self {
+        self.request = self.request.query_many("events_tag", tags);
+        self
+    }
+
+    /// Exclude events with the given tag IDs
+    pub fn exclude_tag_id(mut self, tag_ids: impl IntoIterator<Item = i64>) -> Self {
+        self.request = self.request.query_many("exclude_tag_id", tag_ids);
+        self
+    }
+
+    /// Include matching tags in the results
+    pub fn search_tags(mut self, include: bool) -> Self {
+        self.request = self.request.query("search_tags", include);
+        self
+    }
+
+    /// Include matching user profiles in the results
+    pub fn search_profiles(mut self, include: bool) -> Self {
+        self.request = self.request.query("search_profiles", include);
+        self
+    }
+
+    /// Set sort field (e.g. `volume`, `liquidity`, `startDate`, `endDate`)
+    pub fn sort(mut self, sort: impl Into<String>) -> Self {
+        self.request = self.request.query("sort", sort.into());
+        self
+    }
+
+    /// Set sort direction
+    pub fn ascending(mut self, ascending: bool) -> Self {
+        self.request = self.request.query("ascending", ascending);
+        self
+    }
+
+    /// Execute the request
+    pub async fn send(self) -> Result<SearchResults, GammaError> {
+        self.request.send().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Gamma;
+
+    #[test]
+    fn test_search_full_chain() {
+        let _search = Gamma::new()
+            .unwrap()
+            .search()
+            .query("bitcoin")
+            .limit_per_type(10)
+            .page(2)
+            .events_status(SearchStatus::Active)
+            .keep_closed_markets(false)
+            .events_tag(vec!["crypto"])
+            .exclude_tag_id(vec![1i64])
+            .search_tags(true)
+            .search_profiles(true)
+            .sort("volume")
+            .ascending(false);
+    }
+
+    #[test]
+    fn test_search_status_display() {
+        assert_eq!(SearchStatus::Active.to_string(), "active");
+        assert_eq!(SearchStatus::Closed.to_string(), "closed");
+    }
+}
@@ -1,4 +1,4 @@
-use polyoxide_core::{HttpClient, QueryBuilder, Request};
+use polyoxide_core::{HttpClient, Paginate, QueryBuilder, Request};
 
 use crate::{error::GammaError, types::SeriesData};
 
@@ -60,3 +60,12 @@ impl ListSeries {
         self.request.send().await
     }
 }
+
+impl Paginate for ListSeries {
+    type Item = SeriesData;
+    type Error = GammaError;
+
+    fn into_request(self) -> Request<Vec<SeriesData>, GammaError> {
+        self.request
+    }
+}
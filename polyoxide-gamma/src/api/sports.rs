@@ -1,8 +1,9 @@
-use polyoxide_core::{HttpClient, QueryBuilder, Request};
+use polyoxide_core::{HttpClient, Paginate, QueryBuilder, Request};
 
 use crate::{
+    api::events::Events,
     error::GammaError,
-    types::{SportMetadata, Team},
+    types::{Event, GameState, SportMetadata, SportsEvent, Team},
 };
 
 /// Sport namespace for sports-related operations
@@ -23,6 +24,83 @@ impl Sports {
             request: Request::new(self.http_client.clone(), "/teams"),
         }
     }
+
+    /// Open events for a sport (e.g. `"nba"`), joined with their typed game
+    /// state and markets.
+    ///
+    /// Resolves the sport's series from [`Sports::list`], lists each series'
+    /// open events with their markets, and resolves team IDs against the
+    /// sport's league in [`Sports::list_teams`].
+    pub fn events(&self, sport: impl Into<String>) -> SportsEvents {
+        SportsEvents {
+            http_client: self.http_client.clone(),
+            sport: sport.into(),
+            live_only: false,
+            include_closed: false,
+        }
+    }
+}
+
+/// Request builder for sports events joined with game state
+pub struct SportsEvents {
+    http_client: HttpClient,
+    sport: String,
+    live_only: bool,
+    include_closed: bool,
+}
+
+impl SportsEvents {
+    /// Only return games currently in play
+    pub fn live(mut self, live_only: bool) -> Self {
+        self.live_only = live_only;
+        self
+    }
+
+    /// Include closed events
+    pub fn include_closed(mut self, include: bool) -> Self {
+        self.include_closed = include;
+        self
+    }
+
+    /// Execute the requests; an unknown sport yields no events
+    pub async fn send(self) -> Result<Vec<SportsEvent>, GammaError> {
+        let api = Sports {
+            http_client: self.http_client.clone(),
+        };
+        let sports = api.list().send().await?;
+        let Some(sport) = sports
+            .into_iter()
+            .find(|s| s.sport.eq_ignore_ascii_case(&self.sport))
+        else {
+            return Ok(Vec::new());
+        };
+
+        let events_api = Events {
+            http_client: self.http_client,
+        };
+        let mut events: Vec<Event> = Vec::new();
+        for series_id in sport.series_ids() {
+            let mut list = events_api.list().series_id(series_id);
+            if !self.include_closed {
+                list = list.closed(false);
+            }
+            events.extend(list.all().await?);
+        }
+        events.sort_by(|a, b| a.id.cmp(&b.id));
+        events.dedup_by(|a, b| a.id == b.id);
+
+        let teams = api.list_teams().league([&self.sport]).all().await?;
+
+        Ok(events
+            .into_iter()
+            .map(|event| SportsEvent {
+                sport: sport.clone(),
+                game: GameState::from_event(&event, &teams),
+                event,
+            })
+            .filter(|e| !self.live_only || e.game.is_live())
+            .collect())
+    }
 }
 
 /// Request builder for listing teams
@@ -78,3 +156,12 @@ impl ListTeams {
         self.request.send().await
     }
 }
+
+impl Paginate for ListTeams {
+    type Item = Team;
+    type Error = GammaError;
+
+    fn into_request(self) -> Request<Vec<Team>, GammaError> {
+        self.request
+    }
+}
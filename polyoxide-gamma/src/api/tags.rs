@@ -1,4 +1,4 @@
-use polyoxide_core::{HttpClient, QueryBuilder, Request};
+use polyoxide_core::{HttpClient, Paginate, QueryBuilder, Request};
 
 use crate::{error::GammaError, types::Tag};
 
@@ -99,3 +99,12 @@ impl ListTags {
         self.request.send().await
     }
 }
+
+impl Paginate for ListTags {
+    type Item = Tag;
+    type Error = GammaError;
+
+    fn into_request(self) -> Request<Vec<Tag>, GammaError> {
+        self.request
+    }
+}
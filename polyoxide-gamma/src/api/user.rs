@@ -1,7 +1,7 @@
 use polyoxide_core::{HttpClient, QueryBuilder, Request};
 use serde::{Deserialize, Serialize};
 
-use crate::error::GammaError;
+use crate::{error::GammaError, types::PublicProfile};
 
 /// User API namespace
 #[derive(Clone)]
@@ -15,6 +15,11 @@ impl User {
         Request::new(self.http_client.clone(), "/public-profile")
             .query("address", signer_address.into())
     }
+
+    /// Get the full public profile for a wallet address
+    pub fn profile(&self, address: impl Into<String>) -> Request<PublicProfile, GammaError> {
+        Request::new(self.http_client.clone(), "/public-profile").query("address", address.into())
+    }
 }
 
 /// User details response
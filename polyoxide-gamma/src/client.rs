@@ -4,8 +4,8 @@ use polyoxide_core::{
 
 use crate::{
     api::{
-        comments::Comments, events::Events, health::Health, markets::Markets, series::Series,
-        sports::Sports, tags::Tags, user::User,
+        comments::Comments, events::Events, health::Health, markets::Markets, search::Search,
+        series::Series, sports::Sports, tags::Tags, user::User,
     },
     error::GammaError,
 };
@@ -71,6 +71,13 @@ impl Gamma {
         }
     }
 
+    /// Get search namespace
+    pub fn search(&self) -> Search {
+        Search {
+            http_client: self.http_client.clone(),
+        }
+    }
+
     /// Get user namespace
     pub fn user(&self) -> User {
         User {
@@ -9,6 +9,13 @@ pub enum GammaError {
     Api(#[from] ApiError),
 }
 
+impl GammaError {
+    /// Whether the API answered 404 Not Found
+    pub fn is_not_found(&self) -> bool {
+        matches!(self, Self::Api(ApiError::Api { status: 404, .. }))
+    }
+}
+
 impl RequestError for GammaError {
     async fn from_response(response: reqwest::Response) -> Self {
         Self::Api(ApiError::from_response(response).await)
@@ -6,8 +6,11 @@
 //!
 //! - Market data retrieval with filtering and pagination
 //! - Event and series (tournament/season) information
-//! - Tags and sports metadata
+//! - Tags, sports metadata and typed live game state
 //! - Comments on markets, events, and series
+//! - Full-text search across events, markets, tags and profiles
+//! - Automatic pagination of list endpoints via [`Paginate`]
+//! - Polling change feed for new, closed and resolved markets via [`GammaWatcher`]
 //! - Type-safe API with idiomatic Rust patterns
 //! - Request builder pattern for flexible, composable queries
 //!
@@ -47,6 +50,9 @@ pub mod api;
 pub mod client;
 pub mod error;
 pub mod types;
+pub mod watcher;
 
 pub use client::{Gamma, GammaBuilder};
 pub use error::GammaError;
+pub use polyoxide_core::{Paginate, Paginator};
+pub use watcher::{FieldChange, GammaChange, GammaWatcher};
@@ -1,7 +1,8 @@
 use std::collections::HashMap;
 
-use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 /// Market data from Gamma API
 #[cfg_attr(feature = "specta", derive(specta::Type))]
@@ -37,7 +38,11 @@ pub struct Market {
     pub liquidity: Option<String>,
     #[serde(default)]
     pub tags: Vec<Tag>,
+    /// Parent event(s), as embedded by Gamma (without their nested markets)
+    #[serde(default)]
+    pub events: Vec<Event>,
     pub neg_risk: Option<bool>,
+    #[serde(alias = "negRiskMarketID")]
     pub neg_risk_market_id: Option<String>,
     pub neg_risk_request_id: Option<String>,
     // Use i64 instead of u64 to prevent sentinel value
@@ -105,7 +110,9 @@ pub struct Market {
     pub clob_token_ids: Option<String>,
     pub disqus_thread: Option<String>,
     pub short_outcomes: Option<String>,
+    #[serde(alias = "teamAID")] // Gamma capitalizes the ID suffix
     pub team_aid: Option<String>,
+    #[serde(alias = "teamBID")]
     pub team_bid: Option<String>,
     pub uma_bond: Option<String>,
     pub uma_reward: Option<String>,
@@ -180,6 +187,283 @@ pub struct Market {
     pub event_start_time: Option<String>,
 }
 
+impl Market {
+    /// Outcomes zipped with their prices and CLOB token IDs.
+    ///
+    /// Built from the JSON-encoded `outcomes`, `outcome_prices` and
+    /// `clob_token_ids` fields. Prices and token IDs are `None` when the
+    /// market does not report them (e.g. before the order book is deployed).
+    pub fn outcomes(&self) -> Vec<Outcome> {
+        let prices = self.outcome_prices();
+        let token_ids = self.clob_token_ids();
+        self.outcome_names()
+            .into_iter()
+            .enumerate()
+            .map(|(i, name)| Outcome {
+                name,
+                price: prices.get(i).copied(),
+                token_id: token_ids.get(i).cloned(),
+            })
+            .collect()
+    }
+
+    /// Outcome names parsed from `outcomes`
+    pub fn outcome_names(&self) -> Vec<String> {
+        parse_json_list(self.outcomes.as_deref())
+    }
+
+    /// Outcome prices parsed from `outcome_prices`
+    pub fn outcome_prices(&self) -> Vec<Decimal> {
+        parse_json_list(self.outcome_prices.as_deref())
+    }
+
+    /// CLOB token IDs parsed from `clob_token_ids`
+    pub fn clob_token_ids(&self) -> Vec<String> {
+        parse_json_list(self.clob_token_ids.as_deref())
+    }
+
+    /// Short outcome names parsed from `short_outcomes`
+    pub fn short_outcomes(&self) -> Vec<String> {
+        parse_json_list(self.short_outcomes.as_deref())
+    }
+
+    /// Previous slugs parsed from `past_slugs`
+    pub fn past_slugs(&self) -> Vec<String> {
+        parse_json_list(self.past_slugs.as_deref())
+    }
+
+    /// UMA resolution statuses parsed from `uma_resolution_statuses`
+    pub fn uma_resolution_statuses(&self) -> Vec<String> {
+        parse_json_list(self.uma_resolution_statuses.as_deref())
+    }
+
+    /// Market end date parsed from `end_date_iso`
+    pub fn end_date(&self) -> Option<DateTime<Utc>> {
+        parse_datetime(self.end_date_iso.as_deref())
+    }
+
+    /// Market start date parsed from `start_date_iso`
+    pub fn start_date(&self) -> Option<DateTime<Utc>> {
+        parse_datetime(self.start_date_iso.as_deref())
+    }
+
+    /// Creation time parsed from `created_at`
+    pub fn created_at(&self) -> Option<DateTime<Utc>> {
+        parse_datetime(self.created_at.as_deref())
+    }
+
+    /// Last update time parsed from `updated_at`
+    pub fn updated_at(&self) -> Option<DateTime<Utc>> {
+        parse_datetime(self.updated_at.as_deref())
+    }
+
+    /// Close time parsed from `closed_time`
+    pub fn closed_time(&self) -> Option<DateTime<Utc>> {
+        parse_datetime(self.closed_time.as_deref())
+    }
+
+    /// Game start time parsed from `game_start_time` (sports markets)
+    pub fn game_start_time(&self) -> Option<DateTime<Utc>> {
+        parse_datetime(self.game_start_time.as_deref())
+    }
+
+    /// Event start time parsed from `event_start_time`
+    pub fn event_start_time(&self) -> Option<DateTime<Utc>> {
+        parse_datetime(self.event_start_time.as_deref())
+    }
+
+    /// UMA resolution deadline parsed from `uma_end_date`
+    pub fn uma_end_date(&self) -> Option<DateTime<Utc>> {
+        parse_datetime(self.uma_end_date.as_deref())
+    }
+
+    /// Liquidity parsed from `liquidity`
+    pub fn liquidity(&self) -> Option<Decimal> {
+        parse_decimal(self.liquidity.as_deref())
+    }
+
+    /// Total volume parsed from `volume`
+    pub fn volume(&self) -> Option<Decimal> {
+        parse_decimal(self.volume.as_deref())
+    }
+
+    /// Minimum price increment parsed from `minimum_tick_size`
+    pub fn minimum_tick_size(&self) -> Option<Decimal> {
+        parse_decimal(self.minimum_tick_size.as_deref())
+    }
+
+    /// Minimum order size parsed from `minimum_order_size`
+    pub fn minimum_order_size(&self) -> Option<Decimal> {
+        parse_decimal(self.minimum_order_size.as_deref())
+    }
+
+    /// UMA bond parsed from `uma_bond`
+    pub fn uma_bond(&self) -> Option<Decimal> {
+        parse_decimal(self.uma_bond.as_deref())
+    }
+
+    /// Sports market type parsed from `sports_market_type`
+    pub fn sports_type(&self) -> Option<SportsMarketType> {
+        self.sports_market_type
+            .clone()
+            .filter(|t| !t.is_empty())
+            .map(SportsMarketType::from)
+    }
+
+    /// Resolution lifecycle state derived from token winner flags, final
+    /// outcome prices and the UMA resolution status
+    pub fn resolution_state(&self) -> ResolutionState {
+        let names = self.outcome_names();
+        let token_ids = self.clob_token_ids();
+
+        if let Some(token) = self.tokens.iter().find(|t| t.winner == Some(true)) {
+            return ResolutionState::Resolved(Outcome {
+                name: token.outcome.clone(),
+                price: Some(Decimal::ONE),
+                token_id: Some(token.token_id.clone()),
+            });
+        }
+
+        let status = self
+            .uma_resolution_status
+            .as_deref()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        if self.closed == Some(true) {
+            let prices = self.outcome_prices();
+            let winners = prices.iter().filter(|p| **p == Decimal::ONE).count();
+            let decisive = winners == 1 && prices.iter().all(|p| p.is_zero() || *p == Decimal::ONE);
+            if decisive {
+                let index = prices.iter().position(|p| *p == Decimal::ONE).unwrap_or(0);
+                if let Some(name) = names.get(index) {
+                    return ResolutionState::Resolved(Outcome {
+                        name: name.clone(),
+                        price: Some(Decimal::ONE),
+                        token_id: token_ids.get(index).cloned(),
+                    });
+                }
+            }
+            // Settled without a winner that can be named: a split payout, or
+            // prices or outcome names missing from the response
+            let settled = status == "resolved" || self.automatically_resolved == Some(true);
+            if settled {
+                return ResolutionState::Voided;
+            }
+        }
+
+        match status.as_str() {
+            "proposed" => ResolutionState::Proposed,
+            "disputed" | "challenged" => ResolutionState::Disputed,
+            _ => ResolutionState::Open,
+        }
+    }
+
+    /// Whether the market is waiting on resolution: a proposal or dispute is
+    /// in flight, or trading has ended without a proposal yet
+    pub fn is_pending_resolution(&self) -> bool {
+        match self.resolution_state() {
+            ResolutionState::Proposed | ResolutionState::Disputed => true,
+            ResolutionState::Open => {
+                self.closed == Some(true) || self.end_date().is_some_and(|end| end <= Utc::now())
+            }
+            ResolutionState::Resolved(_) | ResolutionState::Voided => false,
+        }
+    }
+
+    /// Final payout per share of the outcome at `outcome_index`, once the
+    /// market has resolved or been voided
+    pub fn payout(&self, outcome_index: usize) -> Option<Decimal> {
+        match self.resolution_state() {
+            ResolutionState::Resolved(winner) => {
+                let index = self.outcome_names().iter().position(|n| *n == winner.name);
+                let index =
+                    index.or_else(|| self.tokens.iter().position(|t| t.winner == Some(true)))?;
+                Some(if index == outcome_index {
+                    Decimal::ONE
+                } else {
+                    Decimal::ZERO
+                })
+            }
+            ResolutionState::Voided => self.outcome_prices().get(outcome_index).copied(),
+            _ => None,
+        }
+    }
+}
+
+/// Parse a JSON-encoded array string, returning an empty list if absent or malformed
+fn parse_json_list<T: DeserializeOwned>(raw: Option<&str>) -> Vec<T> {
+    raw.and_then(|s| serde_json::from_str(s).ok())
+        .unwrap_or_default()
+}
+
+/// Parse a stringly decimal, returning `None` if absent or malformed
+fn parse_decimal(raw: Option<&str>) -> Option<Decimal> {
+    raw?.trim().parse().ok()
+}
+
+/// Parse the date formats Gamma mixes across fields.
+///
+/// Accepts RFC 3339 (`2024-11-05T12:00:00Z`), Postgres-style timestamps
+/// (`2024-11-05 12:00:00+00`) and bare dates (`2024-11-05`, taken as midnight UTC).
+fn parse_datetime(raw: Option<&str>) -> Option<DateTime<Utc>> {
+    let s = raw?.trim();
+    if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
+        return Some(dt.with_timezone(&Utc));
+    }
+    if let Ok(dt) = DateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S%.f%#z") {
+        return Some(dt.with_timezone(&Utc));
+    }
+    NaiveDate::parse_from_str(s, "%Y-%m-%d")
+        .ok()
+        .map(|d| d.and_time(chrono::NaiveTime::MIN).and_utc())
+}
+
+/// A market outcome with its price and CLOB token ID, as returned by [`Market::outcomes`]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Outcome {
+    pub name: String,
+    #[cfg_attr(feature = "specta", specta(type = Option<String>))]
+    pub price: Option<Decimal>,
+    pub token_id: Option<String>,
+}
+
+/// Where a market is in its UMA resolution lifecycle, as returned by
+/// [`Market::resolution_state`]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "state", content = "outcome", rename_all = "camelCase")]
+pub enum ResolutionState {
+    /// No resolution has been proposed yet
+    Open,
+    /// An outcome has been proposed and is in the challenge window
+    Proposed,
+    /// The proposal was disputed and awaits a new proposal or a DVM vote
+    Disputed,
+    /// The market resolved to a single winning outcome
+    Resolved(Outcome),
+    /// The market settled without a single named winner (e.g. a 50-50 split,
+    /// or outcome data missing from the response)
+    Voided,
+}
+
+impl ResolutionState {
+    /// Whether the market has settled and positions can be redeemed
+    pub fn is_final(&self) -> bool {
+        matches!(self, Self::Resolved(_) | Self::Voided)
+    }
+
+    /// Winning outcome, if the market resolved to one
+    pub fn winner(&self) -> Option<&Outcome> {
+        match self {
+            Self::Resolved(outcome) => Some(outcome),
+            _ => None,
+        }
+    }
+}
+
 /// Market token (outcome)
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -192,7 +476,7 @@ pub struct MarketToken {
 }
 
 #[cfg_attr(feature = "specta", derive(specta::Type))]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Event {
     pub id: String,
@@ -244,6 +528,7 @@ pub struct Event {
     pub liquidity_amm: Option<f64>,
     pub liquidity_clob: Option<f64>,
     pub neg_risk: Option<bool>,
+    #[serde(alias = "negRiskMarketID")]
     pub neg_risk_market_id: Option<String>,
     #[cfg_attr(feature = "specta", specta(type = Option<f64>))]
     pub neg_risk_fee_bips: Option<i64>,
@@ -293,7 +578,7 @@ pub struct Event {
 
 /// Series information within an event
 #[cfg_attr(feature = "specta", derive(specta::Type))]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct SeriesInfo {
     pub id: String,
@@ -342,11 +627,15 @@ pub struct Tag {
     pub updated_at: Option<String>,
     pub force_hide: Option<bool>,
     pub is_carousel: Option<bool>,
+    /// Number of events carrying the tag (search results only)
+    #[serde(alias = "event_count")]
+    #[cfg_attr(feature = "specta", specta(type = Option<f64>))]
+    pub event_count: Option<u64>,
 }
 
 /// Sports metadata
 #[cfg_attr(feature = "specta", derive(specta::Type))]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct SportMetadata {
     #[cfg_attr(feature = "specta", specta(type = f64))]
@@ -362,7 +651,7 @@ pub struct SportMetadata {
 
 /// Sports team
 #[cfg_attr(feature = "specta", derive(specta::Type))]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Team {
     #[cfg_attr(feature = "specta", specta(type = f64))]
@@ -377,6 +666,241 @@ pub struct Team {
     pub updated_at: Option<DateTime<Utc>>,
 }
 
+impl SportMetadata {
+    /// Series IDs parsed from the comma-separated `series` field
+    pub fn series_ids(&self) -> Vec<String> {
+        split_ids(self.series.as_deref())
+    }
+
+    /// Tag IDs parsed from the comma-separated `tags` field
+    pub fn tag_ids(&self) -> Vec<String> {
+        split_ids(self.tags.as_deref())
+    }
+}
+
+fn split_ids(raw: Option<&str>) -> Vec<String> {
+    raw.unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Kind of sports market, parsed from `Market::sports_market_type`
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum SportsMarketType {
+    /// Straight win/lose market
+    Moneyline,
+    /// Point spread (handicap) market; the spread is in `Market::line`
+    Spreads,
+    /// Over/under market; the total is in `Market::line`
+    Totals,
+    /// Any other type reported by Gamma (props, halves, ...)
+    Other(String),
+}
+
+impl From<String> for SportsMarketType {
+    fn from(value: String) -> Self {
+        match value.to_ascii_lowercase().as_str() {
+            "moneyline" => Self::Moneyline,
+            "spreads" => Self::Spreads,
+            "totals" => Self::Totals,
+            _ => Self::Other(value),
+        }
+    }
+}
+
+impl From<SportsMarketType> for String {
+    fn from(value: SportsMarketType) -> Self {
+        value.to_string()
+    }
+}
+
+impl std::fmt::Display for SportsMarketType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Moneyline => write!(f, "moneyline"),
+            Self::Spreads => write!(f, "spreads"),
+            Self::Totals => write!(f, "totals"),
+            Self::Other(other) => write!(f, "{other}"),
+        }
+    }
+}
+
+/// Status of a game, derived from an event's `game_status`, `live` and `ended` fields
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum GameStatus {
+    /// Not started yet
+    Scheduled,
+    /// In play
+    InProgress,
+    /// Half-time or another scheduled break
+    Break,
+    /// Finished
+    Final,
+    /// Postponed or delayed
+    Postponed,
+    /// Cancelled
+    Cancelled,
+}
+
+/// Score of a two-sided game, parsed from strings like `"3-1"`
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Score {
+    /// Score of the first-listed team (`Market::team_aid`)
+    pub team_a: u32,
+    /// Score of the second-listed team (`Market::team_bid`)
+    pub team_b: u32,
+}
+
+impl Score {
+    /// Parse `"<a>-<b>"`; for multi-part scores (`"6-4, 3-2"`) the last part is used
+    pub fn parse(raw: &str) -> Option<Self> {
+        let last = raw.rsplit([',', '|']).next()?.trim();
+        let (a, b) = last.split_once('-')?;
+        Some(Self {
+            team_a: a.trim().parse().ok()?,
+            team_b: b.trim().parse().ok()?,
+        })
+    }
+}
+
+/// Typed live state of a sports event
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GameState {
+    /// First-listed team, resolved from the event's markets (`team_aid`)
+    pub team_a: Option<Team>,
+    /// Second-listed team, resolved from the event's markets (`team_bid`)
+    pub team_b: Option<Team>,
+    /// Parsed score, if reported
+    pub score: Option<Score>,
+    /// Current period as reported (e.g. `"Q3"`, `"2H"`, `"HT"`)
+    pub period: Option<String>,
+    /// Game clock or elapsed minutes as reported (e.g. `"07:42"`, `"67"`)
+    pub elapsed: Option<String>,
+    pub status: GameStatus,
+    /// Scheduled start time
+    pub start_time: Option<DateTime<Utc>>,
+}
+
+impl GameState {
+    /// Build the game state of `event`, resolving team IDs against `teams`
+    pub fn from_event(event: &Event, teams: &[Team]) -> Self {
+        let team = |id: Option<&String>| {
+            let id: i64 = id?.trim().parse().ok()?;
+            teams.iter().find(|t| t.id == id).cloned()
+        };
+        let team_a = team(event.markets.iter().find_map(|m| m.team_aid.as_ref()));
+        let team_b = team(event.markets.iter().find_map(|m| m.team_bid.as_ref()));
+        let non_empty = |s: &Option<String>| s.clone().filter(|s| !s.trim().is_empty());
+
+        Self {
+            team_a,
+            team_b,
+            score: event.score.as_deref().and_then(Score::parse),
+            period: non_empty(&event.period),
+            elapsed: non_empty(&event.elapsed),
+            status: event.game_status(),
+            start_time: parse_datetime(event.start_time.as_deref())
+                .or_else(|| parse_datetime(event.start_date.as_deref())),
+        }
+    }
+
+    /// Whether the game is currently being played (including breaks)
+    pub fn is_live(&self) -> bool {
+        matches!(self.status, GameStatus::InProgress | GameStatus::Break)
+    }
+}
+
+impl Event {
+    /// Game status derived from `game_status`, `live`, `ended` and `period`
+    pub fn game_status(&self) -> GameStatus {
+        let raw = self
+            .game_status
+            .as_deref()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        if raw.contains("cancel") {
+            return GameStatus::Cancelled;
+        }
+        if raw.contains("postpon") || raw.contains("delay") || raw.contains("suspend") {
+            return GameStatus::Postponed;
+        }
+        if self.ended == Some(true) || raw.contains("final") || raw.contains("finished") {
+            return GameStatus::Final;
+        }
+        if self.live == Some(true) || raw.contains("progress") {
+            let period = self
+                .period
+                .as_deref()
+                .unwrap_or_default()
+                .to_ascii_uppercase();
+            return if matches!(period.as_str(), "HT" | "BREAK" | "END") || raw.contains("half") {
+                GameStatus::Break
+            } else {
+                GameStatus::InProgress
+            };
+        }
+        GameStatus::Scheduled
+    }
+}
+
+/// A sports event joined with its sport, typed game state and markets,
+/// as returned by [`crate::api::sports::Sports::events`]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SportsEvent {
+    pub sport: SportMetadata,
+    /// The event, with its markets embedded
+    pub event: Event,
+    pub game: GameState,
+}
+
+impl SportsEvent {
+    /// Markets of the given sports type
+    pub fn markets_of_type(&self, kind: SportsMarketType) -> impl Iterator<Item = &Market> + '_ {
+        self.event
+            .markets
+            .iter()
+            .filter(move |m| m.sports_type().as_ref() == Some(&kind))
+    }
+
+    /// The moneyline market, if any
+    pub fn moneyline(&self) -> Option<&Market> {
+        self.markets_of_type(SportsMarketType::Moneyline).next()
+    }
+
+    /// Spread markets, ordered by line
+    pub fn spreads(&self) -> Vec<&Market> {
+        self.lines(SportsMarketType::Spreads)
+    }
+
+    /// Over/under markets, ordered by line
+    pub fn totals(&self) -> Vec<&Market> {
+        self.lines(SportsMarketType::Totals)
+    }
+
+    fn lines(&self, kind: SportsMarketType) -> Vec<&Market> {
+        let mut markets: Vec<&Market> = self.markets_of_type(kind).collect();
+        markets.sort_by(|a, b| {
+            a.line
+                .unwrap_or_default()
+                .total_cmp(&b.line.unwrap_or_default())
+        });
+        markets
+    }
+}
+
 /// Comment on a market/event/series
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -391,6 +915,7 @@ pub struct Comment {
     pub market_id: Option<String>,
     pub event_id: Option<String>,
     pub series_id: Option<String>,
+    #[serde(alias = "parentCommentID")]
     pub parent_id: Option<String>,
     #[serde(default)]
     pub reactions: Vec<CommentReaction>,
@@ -433,6 +958,333 @@ pub struct CommentPosition {
     pub shares: String,
 }
 
+impl CommentPosition {
+    /// Share count parsed from `shares`
+    pub fn shares(&self) -> Option<Decimal> {
+        parse_decimal(Some(&self.shares))
+    }
+}
+
+impl Comment {
+    /// Reaction counts by reaction type
+    pub fn reaction_summary(&self) -> ReactionSummary {
+        let mut summary = ReactionSummary::default();
+        summary.extend(&self.reactions);
+        summary
+    }
+
+    /// Whether the author holds any position in the commented market
+    pub fn is_holder(&self) -> bool {
+        self.positions
+            .iter()
+            .any(|p| p.shares().is_some_and(|s| !s.is_zero()))
+    }
+}
+
+/// Reaction counts aggregated over one or more comments
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReactionSummary {
+    /// Number of reactions per reaction type (lowercased)
+    #[cfg_attr(feature = "specta", specta(type = std::collections::BTreeMap<String, f64>))]
+    pub by_type: std::collections::BTreeMap<String, u32>,
+    /// Total number of reactions
+    #[cfg_attr(feature = "specta", specta(type = f64))]
+    pub total: u32,
+}
+
+impl ReactionSummary {
+    /// Number of reactions of `reaction_type` (case-insensitive)
+    pub fn count(&self, reaction_type: &str) -> u32 {
+        self.by_type
+            .get(&reaction_type.to_ascii_lowercase())
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn extend<'a>(&mut self, reactions: impl IntoIterator<Item = &'a CommentReaction>) {
+        for reaction in reactions {
+            *self
+                .by_type
+                .entry(reaction.reaction_type.to_ascii_lowercase())
+                .or_default() += 1;
+            self.total += 1;
+        }
+    }
+}
+
+/// A comment with its replies, rebuilt from a flat comment list by
+/// [`CommentThread::build`]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentThread {
+    pub comment: Comment,
+    /// Direct replies, oldest first
+    pub replies: Vec<CommentThread>,
+}
+
+impl CommentThread {
+    /// Rebuild threads from `parent_id` links.
+    ///
+    /// Comments whose parent is not in `comments` become roots, so a partial
+    /// page still yields every comment exactly once. Reply cycles are broken
+    /// at their oldest comment, which becomes a root. Roots and replies are
+    /// ordered oldest first.
+    pub fn build(comments: impl IntoIterator<Item = Comment>) -> Vec<CommentThread> {
+        fn chronological(a: &Comment, b: &Comment) -> std::cmp::Ordering {
+            a.created_at.cmp(&b.created_at).then(a.id.cmp(&b.id))
+        }
+
+        let mut comments: Vec<Comment> = comments.into_iter().collect();
+        comments.sort_by(chronological);
+
+        let ids: std::collections::HashSet<String> =
+            comments.iter().map(|c| c.id.clone()).collect();
+        let mut children: HashMap<String, Vec<Comment>> = HashMap::new();
+        let mut roots = Vec::new();
+        for comment in comments {
+            match comment
+                .parent_id
+                .clone()
+                .filter(|p| ids.contains(p) && *p != comment.id)
+            {
+                Some(parent) => children.entry(parent).or_default().push(comment),
+                None => roots.push(comment),
+            }
+        }
+
+        fn attach(comment: Comment, children: &mut HashMap<String, Vec<Comment>>) -> CommentThread {
+            let replies = children
+                .remove(&comment.id)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|reply| attach(reply, children))
+                .collect();
+            CommentThread { comment, replies }
+        }
+
+        let mut threads: Vec<CommentThread> = roots
+            .into_iter()
+            .map(|root| attach(root, &mut children))
+            .collect();
+
+        // Anything left is unreachable from a root, i.e. on or below a cycle
+        while let Some(parent) = children
+            .iter()
+            .min_by(|(_, a), (_, b)| chronological(&a[0], &b[0]))
+            .map(|(parent, _)| parent.clone())
+        {
+            let Some(mut siblings) = children.remove(&parent) else {
+                break;
+            };
+            let oldest = siblings.remove(0);
+            if !siblings.is_empty() {
+                children.insert(parent, siblings);
+            }
+            threads.push(attach(oldest, &mut children));
+        }
+        threads.sort_by(|a, b| chronological(&a.comment, &b.comment));
+        threads
+    }
+
+    /// Number of comments in the thread, including the root
+    pub fn len(&self) -> usize {
+        1 + self.replies.iter().map(CommentThread::len).sum::<usize>()
+    }
+
+    /// Always `false`: a thread contains at least its root comment
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Depth of the deepest reply chain (0 for a comment without replies)
+    pub fn depth(&self) -> usize {
+        self.replies
+            .iter()
+            .map(|r| r.depth() + 1)
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Depth-first walk over the thread, yielding each comment with its depth
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &Comment)> {
+        let mut stack = vec![(0, self)];
+        std::iter::from_fn(move || {
+            let (depth, thread) = stack.pop()?;
+            stack.extend(thread.replies.iter().rev().map(|r| (depth + 1, r)));
+            Some((depth, &thread.comment))
+        })
+    }
+
+    /// Reaction counts across every comment in the thread
+    pub fn reaction_summary(&self) -> ReactionSummary {
+        let mut summary = ReactionSummary::default();
+        for (_, comment) in self.iter() {
+            summary.extend(&comment.reactions);
+        }
+        summary
+    }
+}
+
+/// Flat, sentiment-ready view of a comment joined with the author's
+/// positions, as returned by [`crate::api::comments::ListComments::export`]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentRecord {
+    pub comment_id: String,
+    pub parent_id: Option<String>,
+    /// Root comment of the thread this comment belongs to
+    pub thread_id: String,
+    /// Reply depth (0 for top-level comments)
+    #[cfg_attr(feature = "specta", specta(type = f64))]
+    pub depth: u32,
+    pub author_id: String,
+    pub author_name: String,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+    #[cfg_attr(feature = "specta", specta(type = f64))]
+    pub like_count: u32,
+    #[cfg_attr(feature = "specta", specta(type = f64))]
+    pub dislike_count: u32,
+    pub reactions: ReactionSummary,
+    /// Whether the author held a non-zero position when commenting
+    pub is_holder: bool,
+    /// Shares held by the author per outcome name
+    #[cfg_attr(feature = "specta", specta(type = std::collections::BTreeMap<String, String>))]
+    pub holdings: std::collections::BTreeMap<String, Decimal>,
+}
+
+impl CommentRecord {
+    /// Flatten threads into records, depth-first; deleted comments are skipped
+    pub fn from_threads(threads: &[CommentThread]) -> Vec<CommentRecord> {
+        let mut records = Vec::new();
+        for thread in threads {
+            for (depth, comment) in thread.iter() {
+                if comment.deleted_at.is_some() {
+                    continue;
+                }
+                let mut holdings = std::collections::BTreeMap::new();
+                for position in &comment.positions {
+                    if let Some(shares) = position.shares() {
+                        *holdings
+                            .entry(position.outcome.clone())
+                            .or_insert(Decimal::ZERO) += shares;
+                    }
+                }
+                records.push(CommentRecord {
+                    comment_id: comment.id.clone(),
+                    parent_id: comment.parent_id.clone(),
+                    thread_id: thread.comment.id.clone(),
+                    depth: depth as u32,
+                    author_id: comment.user.id.clone(),
+                    author_name: comment.user.name.clone(),
+                    body: comment.body.clone(),
+                    created_at: comment.created_at,
+                    like_count: comment.like_count,
+                    dislike_count: comment.dislike_count,
+                    reactions: comment.reaction_summary(),
+                    is_holder: comment.is_holder(),
+                    holdings,
+                });
+            }
+        }
+        records
+    }
+}
+
+/// Public user profile
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PublicProfile {
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub pseudonym: Option<String>,
+    pub display_username_public: Option<bool>,
+    pub bio: Option<String>,
+    pub profile_image: Option<String>,
+    pub proxy_wallet: Option<String>,
+    pub x_username: Option<String>,
+    pub verified_badge: Option<bool>,
+    pub wallet_activated: Option<bool>,
+    pub created_at: Option<DateTime<Utc>>,
+    /// Accounts linked to the profile
+    #[serde(default)]
+    pub users: Vec<ProfileUser>,
+}
+
+impl PublicProfile {
+    /// Name to show for this profile, honouring the user's display preference
+    pub fn display_name(&self) -> Option<&str> {
+        if self.display_username_public == Some(true) {
+            self.name.as_deref().or(self.pseudonym.as_deref())
+        } else {
+            self.pseudonym.as_deref().or(self.name.as_deref())
+        }
+    }
+}
+
+/// Account linked to a public profile
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileUser {
+    pub id: Option<String>,
+    pub creator: Option<bool>,
+    #[serde(rename = "mod")]
+    pub moderator: Option<bool>,
+}
+
+/// Results of a public search
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchResults {
+    /// Matching events, each with its markets
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub events: Vec<Event>,
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub tags: Vec<Tag>,
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub profiles: Vec<PublicProfile>,
+    pub pagination: Option<SearchPagination>,
+}
+
+impl SearchResults {
+    /// Markets of every matching event
+    pub fn markets(&self) -> impl Iterator<Item = &Market> {
+        self.events.iter().flat_map(|e| e.markets.iter())
+    }
+
+    /// Whether another page of results is available
+    pub fn has_more(&self) -> bool {
+        self.pagination.as_ref().is_some_and(|p| p.has_more)
+    }
+}
+
+/// Pagination info for search results
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchPagination {
+    #[serde(default)]
+    pub has_more: bool,
+    #[cfg_attr(feature = "specta", specta(type = Option<f64>))]
+    pub total_results: Option<u64>,
+}
+
+/// Deserialize `null` as the type's default (search omits empty sections as `null`)
+fn null_as_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: Default + Deserialize<'de>,
+{
+    Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
+}
+
 /// Pagination cursor for list operations
 #[cfg_attr(feature = "specta", derive(specta::Type))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -530,6 +1382,123 @@ mod tests {
         assert!(market.volume_24hr.is_none());
     }
 
+    fn market_with(fields: &str) -> Market {
+        let json = format!(
+            r#"{{
+                "id": "1",
+                "conditionId": "0xcond",
+                "description": "Test",
+                "question": "Test?",
+                "marketMakerAddress": "0xaddr"{fields}
+            }}"#
+        );
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn test_market_outcomes_zipped() {
+        let market = market_with(
+            r#",
+            "outcomes": "[\"Yes\", \"No\"]",
+            "outcomePrices": "[\"0.55\", \"0.45\"]",
+            "clobTokenIds": "[\"111\", \"222\"]""#,
+        );
+        let outcomes = market.outcomes();
+        assert_eq!(outcomes.len(), 2);
+        assert_eq!(outcomes[0].name, "Yes");
+        assert_eq!(outcomes[0].price, Some(Decimal::new(55, 2)));
+        assert_eq!(outcomes[0].token_id.as_deref(), Some("111"));
+        assert_eq!(outcomes[1].name, "No");
+        assert_eq!(outcomes[1].price, Some(Decimal::new(45, 2)));
+        assert_eq!(outcomes[1].token_id.as_deref(), Some("222"));
+    }
+
+    #[test]
+    fn test_market_outcomes_without_prices_or_tokens() {
+        let market = market_with(r#", "outcomes": "[\"Up\", \"Down\"]""#);
+        let outcomes = market.outcomes();
+        assert_eq!(outcomes.len(), 2);
+        assert!(outcomes
+            .iter()
+            .all(|o| o.price.is_none() && o.token_id.is_none()));
+    }
+
+    #[test]
+    fn test_market_json_lists_missing_or_malformed() {
+        let market = market_with(r#", "pastSlugs": "not json""#);
+        assert!(market.outcomes().is_empty());
+        assert!(market.clob_token_ids().is_empty());
+        assert!(market.past_slugs().is_empty());
+    }
+
+    #[test]
+    fn test_market_string_lists() {
+        let market = market_with(
+            r#",
+            "shortOutcomes": "[\"Y\", \"N\"]",
+            "pastSlugs": "[\"old-slug\"]",
+            "umaResolutionStatuses": "[\"proposed\", \"resolved\"]""#,
+        );
+        assert_eq!(market.short_outcomes(), vec!["Y", "N"]);
+        assert_eq!(market.past_slugs(), vec!["old-slug"]);
+        assert_eq!(
+            market.uma_resolution_statuses(),
+            vec!["proposed", "resolved"]
+        );
+    }
+
+    #[test]
+    fn test_market_dates() {
+        let market = market_with(
+            r#",
+            "endDateIso": "2025-12-31",
+            "startDateIso": "2024-01-01T12:30:00Z",
+            "createdAt": "2024-01-01T12:30:00.123456Z",
+            "gameStartTime": "2025-01-12 18:00:00+00",
+            "closedTime": "2025-01-13 02:15:00.5+02",
+            "umaEndDate": "garbage""#,
+        );
+        assert_eq!(
+            market.end_date().unwrap().to_rfc3339(),
+            "2025-12-31T00:00:00+00:00"
+        );
+        assert_eq!(
+            market.start_date().unwrap().to_rfc3339(),
+            "2024-01-01T12:30:00+00:00"
+        );
+        assert_eq!(
+            market.created_at().unwrap().timestamp_micros(),
+            1_704_112_200_123_456
+        );
+        assert_eq!(
+            market.game_start_time().unwrap().to_rfc3339(),
+            "2025-01-12T18:00:00+00:00"
+        );
+        assert_eq!(
+            market.closed_time().unwrap().timestamp_millis(),
+            1_736_727_300_500
+        );
+        assert!(market.uma_end_date().is_none());
+        assert!(market.updated_at().is_none());
+    }
+
+    #[test]
+    fn test_market_decimals() {
+        let market = market_with(
+            r#",
+            "liquidity": "12345.678",
+            "volume": "1000000",
+            "minimumTickSize": "0.01",
+            "minimumOrderSize": "5",
+            "umaBond": "not a number""#,
+        );
+        assert_eq!(market.liquidity(), Some(Decimal::new(12_345_678, 3)));
+        assert_eq!(market.volume(), Some(Decimal::from(1_000_000)));
+        assert_eq!(market.minimum_tick_size(), Some(Decimal::new(1, 2)));
+        assert_eq!(market.minimum_order_size(), Some(Decimal::from(5)));
+        assert!(market.uma_bond().is_none());
+    }
+
     #[test]
     fn test_market_with_tokens() {
         let json = r#"{
@@ -571,6 +1540,18 @@ mod tests {
         assert_eq!(market.volume_1wk_clob, Some(9900.0));
     }
 
+    #[test]
+    fn test_market_embedded_events() {
+        let market = market_with(
+            r#",
+            "events": [{"id": "903", "slug": "us-election", "title": "US Election"}]"#,
+        );
+        assert_eq!(market.events.len(), 1);
+        assert_eq!(market.events[0].slug.as_deref(), Some("us-election"));
+        assert!(market.events[0].markets.is_empty());
+        assert!(market_with("").events.is_empty());
+    }
+
     #[test]
     fn test_market_denomination_token_rename() {
         // API field is "denomationToken" (typo in Polymarket API)
@@ -616,6 +1597,123 @@ mod tests {
         assert!(market.rewards.is_none());
     }
 
+    // ── ResolutionState ─────────────────────────────────────────
+
+    const BINARY: &str = r#",
+        "outcomes": "[\"Yes\", \"No\"]",
+        "clobTokenIds": "[\"111\", \"222\"]""#;
+
+    #[test]
+    fn test_resolution_open() {
+        let market = market_with(&format!(
+            r#"{BINARY}, "outcomePrices": "[\"0.6\", \"0.4\"]""#
+        ));
+        assert_eq!(market.resolution_state(), ResolutionState::Open);
+        assert!(!market.is_pending_resolution());
+        assert!(market.payout(0).is_none());
+    }
+
+    #[test]
+    fn test_resolution_proposed_and_disputed() {
+        let proposed = market_with(&format!(r#"{BINARY}, "umaResolutionStatus": "proposed""#));
+        assert_eq!(proposed.resolution_state(), ResolutionState::Proposed);
+        assert!(proposed.is_pending_resolution());
+
+        let disputed = market_with(&format!(r#"{BINARY}, "umaResolutionStatus": "disputed""#));
+        assert_eq!(disputed.resolution_state(), ResolutionState::Disputed);
+        assert!(disputed.is_pending_resolution());
+    }
+
+    #[test]
+    fn test_resolution_closed_awaiting_proposal_is_pending() {
+        let market = market_with(&format!(
+            r#"{BINARY}, "closed": true, "outcomePrices": "[\"0.6\", \"0.4\"]""#
+        ));
+        assert_eq!(market.resolution_state(), ResolutionState::Open);
+        assert!(market.is_pending_resolution());
+    }
+
+    #[test]
+    fn test_resolution_past_end_date_is_pending() {
+        let market = market_with(&format!(r#"{BINARY}, "endDateIso": "2020-01-01""#));
+        assert!(market.is_pending_resolution());
+    }
+
+    #[test]
+    fn test_resolution_resolved_from_prices() {
+        let market = market_with(&format!(
+            r#"{BINARY}, "closed": true, "outcomePrices": "[\"0\", \"1\"]", "umaResolutionStatus": "resolved""#
+        ));
+        let state = market.resolution_state();
+        let winner = state.winner().unwrap();
+        assert_eq!(winner.name, "No");
+        assert_eq!(winner.token_id.as_deref(), Some("222"));
+        assert!(state.is_final());
+        assert!(!market.is_pending_resolution());
+        assert_eq!(market.payout(0), Some(Decimal::ZERO));
+        assert_eq!(market.payout(1), Some(Decimal::ONE));
+    }
+
+    #[test]
+    fn test_resolution_resolved_from_token_winner() {
+        let market = market_with(
+            r#",
+            "outcomes": "[\"Yes\", \"No\"]",
+            "tokens": [
+                {"tokenId": "111", "outcome": "Yes", "winner": true},
+                {"tokenId": "222", "outcome": "No", "winner": false}
+            ]"#,
+        );
+        let state = market.resolution_state();
+        assert_eq!(state.winner().unwrap().token_id.as_deref(), Some("111"));
+        assert_eq!(market.payout(0), Some(Decimal::ONE));
+        assert_eq!(market.payout(1), Some(Decimal::ZERO));
+    }
+
+    #[test]
+    fn test_resolution_voided() {
+        let market = market_with(&format!(
+            r#"{BINARY}, "closed": true, "outcomePrices": "[\"0.5\", \"0.5\"]", "umaResolutionStatus": "resolved""#
+        ));
+        assert_eq!(market.resolution_state(), ResolutionState::Voided);
+        assert_eq!(market.payout(0), Some(Decimal::new(5, 1)));
+    }
+
+    #[test]
+    fn test_resolution_resolved_without_prices_is_voided() {
+        let market = market_with(&format!(
+            r#"{BINARY}, "closed": true, "umaResolutionStatus": "resolved""#
+        ));
+        assert_eq!(market.resolution_state(), ResolutionState::Voided);
+        assert!(!market.is_pending_resolution());
+        assert!(market.payout(0).is_none());
+    }
+
+    #[test]
+    fn test_resolution_resolved_without_winner_name_is_voided() {
+        let market = market_with(
+            r#", "outcomes": "[\"Yes\"]", "closed": true,
+            "outcomePrices": "[\"0\", \"1\"]", "umaResolutionStatus": "resolved""#,
+        );
+        assert_eq!(market.resolution_state(), ResolutionState::Voided);
+        assert!(!market.is_pending_resolution());
+        assert_eq!(market.payout(1), Some(Decimal::ONE));
+    }
+
+    #[test]
+    fn test_resolution_state_serialization() {
+        let json = serde_json::to_value(ResolutionState::Proposed).unwrap();
+        assert_eq!(json, serde_json::json!({"state": "proposed"}));
+        let state = ResolutionState::Resolved(Outcome {
+            name: "Yes".into(),
+            price: Some(Decimal::ONE),
+            token_id: Some("111".into()),
+        });
+        let back: ResolutionState =
+            serde_json::from_value(serde_json::to_value(&state).unwrap()).unwrap();
+        assert_eq!(back, state);
+    }
+
     // ── Event ───────────────────────────────────────────────────
 
     #[test]
@@ -715,6 +1813,142 @@ mod tests {
         assert_eq!(sm.sport, "Basketball");
     }
 
+    #[test]
+    fn test_sport_metadata_ids() {
+        let json = r#"{"id": 1, "sport": "nba", "tags": "1, 745,100639", "series": "10345"}"#;
+        let sm: SportMetadata = serde_json::from_str(json).unwrap();
+        assert_eq!(sm.series_ids(), vec!["10345"]);
+        assert_eq!(sm.tag_ids(), vec!["1", "745", "100639"]);
+    }
+
+    // ── Game state ──────────────────────────────────────────────
+
+    fn team(id: i64, name: &str) -> Team {
+        serde_json::from_value(serde_json::json!({ "id": id, "name": name, "league": "nba" }))
+            .unwrap()
+    }
+
+    fn sports_event(fields: serde_json::Value) -> Event {
+        let mut json = serde_json::json!({
+            "id": "100",
+            "markets": [
+                {
+                    "id": "1", "conditionId": "0x1", "description": "", "question": "Winner?",
+                    "marketMakerAddress": "", "teamAID": "7", "teamBID": "9",
+                    "sportsMarketType": "moneyline"
+                },
+                {
+                    "id": "2", "conditionId": "0x2", "description": "", "question": "Spread +5.5",
+                    "marketMakerAddress": "", "sportsMarketType": "spreads", "line": 5.5
+                },
+                {
+                    "id": "3", "conditionId": "0x3", "description": "", "question": "Spread -1.5",
+                    "marketMakerAddress": "", "sportsMarketType": "spreads", "line": -1.5
+                },
+                {
+                    "id": "4", "conditionId": "0x4", "description": "", "question": "O/U 220.5",
+                    "marketMakerAddress": "", "sportsMarketType": "totals", "line": 220.5
+                }
+            ]
+        });
+        json.as_object_mut()
+            .unwrap()
+            .extend(fields.as_object().unwrap().clone());
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn test_score_parse() {
+        assert_eq!(
+            Score::parse("110-105"),
+            Some(Score {
+                team_a: 110,
+                team_b: 105
+            })
+        );
+        assert_eq!(Score::parse("6-4, 3-2").unwrap().team_a, 3);
+        assert!(Score::parse("").is_none());
+        assert!(Score::parse("abc").is_none());
+    }
+
+    #[test]
+    fn test_game_status() {
+        let scheduled = sports_event(serde_json::json!({}));
+        assert_eq!(scheduled.game_status(), GameStatus::Scheduled);
+
+        let live = sports_event(serde_json::json!({ "live": true, "period": "Q3" }));
+        assert_eq!(live.game_status(), GameStatus::InProgress);
+
+        let half = sports_event(serde_json::json!({ "live": true, "period": "HT" }));
+        assert_eq!(half.game_status(), GameStatus::Break);
+
+        let ended = sports_event(serde_json::json!({ "live": false, "ended": true }));
+        assert_eq!(ended.game_status(), GameStatus::Final);
+
+        let postponed = sports_event(serde_json::json!({ "gameStatus": "Postponed" }));
+        assert_eq!(postponed.game_status(), GameStatus::Postponed);
+    }
+
+    #[test]
+    fn test_game_state_from_event() {
+        let event = sports_event(serde_json::json!({
+            "live": true,
+            "score": "54-48",
+            "period": "Q3",
+            "elapsed": "07:42",
+            "startTime": "2025-01-15T00:30:00Z",
+        }));
+        let teams = vec![team(7, "Lakers"), team(9, "Celtics"), team(11, "Bulls")];
+        let game = GameState::from_event(&event, &teams);
+
+        assert_eq!(
+            game.team_a.as_ref().unwrap().name.as_deref(),
+            Some("Lakers")
+        );
+        assert_eq!(
+            game.team_b.as_ref().unwrap().name.as_deref(),
+            Some("Celtics")
+        );
+        assert_eq!(
+            game.score,
+            Some(Score {
+                team_a: 54,
+                team_b: 48
+            })
+        );
+        assert_eq!(game.period.as_deref(), Some("Q3"));
+        assert_eq!(game.elapsed.as_deref(), Some("07:42"));
+        assert!(game.is_live());
+        assert!(game.start_time.is_some());
+    }
+
+    #[test]
+    fn test_sports_market_type() {
+        assert_eq!(
+            SportsMarketType::from("Totals".to_string()),
+            SportsMarketType::Totals
+        );
+        let other = SportsMarketType::from("first_half_moneyline".to_string());
+        assert_eq!(other.to_string(), "first_half_moneyline");
+        let json = serde_json::to_string(&SportsMarketType::Spreads).unwrap();
+        assert_eq!(json, r#""spreads""#);
+    }
+
+    #[test]
+    fn test_sports_event_market_lines() {
+        let event = sports_event(serde_json::json!({}));
+        let sports_event = SportsEvent {
+            sport: serde_json::from_str(r#"{"id": 1, "sport": "nba"}"#).unwrap(),
+            game: GameState::from_event(&event, &[]),
+            event,
+        };
+        assert_eq!(sports_event.moneyline().unwrap().id, "1");
+        let spreads: Vec<_> = sports_event.spreads().iter().map(|m| m.line).collect();
+        assert_eq!(spreads, vec![Some(-1.5), Some(5.5)]);
+        assert_eq!(sports_event.totals().len(), 1);
+        assert!(sports_event.game.team_a.is_none());
+    }
+
     // ── Team ────────────────────────────────────────────────────
 
     #[test]
@@ -765,6 +1999,123 @@ mod tests {
         assert!(comment.deleted_at.is_none());
     }
 
+    fn comment(id: &str, parent: Option<&str>, minute: u32, extra: serde_json::Value) -> Comment {
+        let mut json = serde_json::json!({
+            "id": id,
+            "body": format!("comment {id}"),
+            "createdAt": format!("2024-06-01T10:{minute:02}:00Z"),
+            "updatedAt": "2024-06-01T10:00:00Z",
+            "user": {"id": format!("u-{id}"), "name": format!("user {id}")},
+            "parentCommentID": parent,
+            "likeCount": 0,
+            "dislikeCount": 0,
+            "replyCount": 0
+        });
+        json.as_object_mut()
+            .unwrap()
+            .extend(extra.as_object().unwrap().clone());
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn test_comment_threads() {
+        let comments = vec![
+            comment("c3", Some("c1"), 3, serde_json::json!({})),
+            comment("c1", None, 1, serde_json::json!({})),
+            comment("c4", Some("c3"), 4, serde_json::json!({})),
+            comment("c2", None, 2, serde_json::json!({})),
+            comment("c5", Some("c1"), 5, serde_json::json!({})),
+            comment("c6", Some("missing"), 6, serde_json::json!({})),
+        ];
+        let threads = CommentThread::build(comments);
+        let roots: Vec<&str> = threads.iter().map(|t| t.comment.id.as_str()).collect();
+        assert_eq!(roots, vec!["c1", "c2", "c6"]);
+
+        let first = &threads[0];
+        assert_eq!(first.len(), 4);
+        assert_eq!(first.depth(), 2);
+        let walk: Vec<(usize, &str)> = first.iter().map(|(d, c)| (d, c.id.as_str())).collect();
+        assert_eq!(walk, vec![(0, "c1"), (1, "c3"), (2, "c4"), (1, "c5")]);
+        assert_eq!(threads[1].len(), 1);
+        assert_eq!(threads[1].depth(), 0);
+    }
+
+    #[test]
+    fn test_comment_thread_cycles_are_kept() {
+        let comments = vec![
+            comment("c1", Some("c1"), 1, serde_json::json!({})),
+            comment("c2", Some("c4"), 2, serde_json::json!({})),
+            comment("c3", Some("c2"), 3, serde_json::json!({})),
+            comment("c4", Some("c3"), 4, serde_json::json!({})),
+            comment("c5", Some("c3"), 5, serde_json::json!({})),
+        ];
+        let threads = CommentThread::build(comments);
+        let roots: Vec<&str> = threads.iter().map(|t| t.comment.id.as_str()).collect();
+        assert_eq!(roots, vec!["c1", "c2"]);
+
+        let walk: Vec<(usize, &str)> = threads[1].iter().map(|(d, c)| (d, c.id.as_str())).collect();
+        assert_eq!(walk, vec![(0, "c2"), (1, "c3"), (2, "c4"), (2, "c5")]);
+    }
+
+    #[test]
+    fn test_comment_reaction_summary() {
+        let reactions = serde_json::json!({
+            "reactions": [
+                {"userId": "a", "reactionType": "HEART"},
+                {"userId": "b", "reactionType": "heart"},
+                {"userId": "c", "reactionType": "laugh"}
+            ]
+        });
+        let root = comment("c1", None, 1, reactions.clone());
+        let reply = comment("c2", Some("c1"), 2, reactions);
+
+        let summary = root.reaction_summary();
+        assert_eq!(summary.total, 3);
+        assert_eq!(summary.count("Heart"), 2);
+        assert_eq!(summary.count("laugh"), 1);
+        assert_eq!(summary.count("dislike"), 0);
+
+        let threads = CommentThread::build(vec![root, reply]);
+        assert_eq!(threads[0].reaction_summary().count("heart"), 4);
+    }
+
+    #[test]
+    fn test_comment_records_join_positions() {
+        let comments = vec![
+            comment(
+                "c1",
+                None,
+                1,
+                serde_json::json!({
+                    "positions": [
+                        {"tokenId": "t1", "outcome": "Yes", "shares": "100.5"},
+                        {"tokenId": "t2", "outcome": "No", "shares": "0"}
+                    ],
+                    "likeCount": 2
+                }),
+            ),
+            comment("c2", Some("c1"), 2, serde_json::json!({})),
+            comment(
+                "c3",
+                Some("c1"),
+                3,
+                serde_json::json!({"deletedAt": "2024-06-02T00:00:00Z"}),
+            ),
+        ];
+        let records = CommentRecord::from_threads(&CommentThread::build(comments));
+        assert_eq!(records.len(), 2);
+
+        assert!(records[0].is_holder);
+        assert_eq!(records[0].like_count, 2);
+        assert_eq!(records[0].holdings["Yes"], Decimal::new(1005, 1));
+        assert_eq!(records[0].holdings["No"], Decimal::ZERO);
+
+        assert_eq!(records[1].thread_id, "c1");
+        assert_eq!(records[1].depth, 1);
+        assert!(!records[1].is_holder);
+        assert!(records[1].holdings.is_empty());
+    }
+
     // ── UserResponse ────────────────────────────────────────────
 
     #[test]
@@ -790,6 +2141,91 @@ mod tests {
         assert!(user.name.is_none());
     }
 
+    // ── Search ──────────────────────────────────────────────────
+
+    #[test]
+    fn test_search_results_deserialization() {
+        let json = r#"{
+            "events": [{
+                "id": "1",
+                "slug": "btc-100k",
+                "markets": [
+                    {"id": "10", "conditionId": "0xa", "description": "", "question": "A?", "marketMakerAddress": ""},
+                    {"id": "11", "conditionId": "0xb", "description": "", "question": "B?", "marketMakerAddress": ""}
+                ]
+            }],
+            "tags": [{"id": "21", "slug": "crypto", "label": "Crypto", "event_count": 42}],
+            "profiles": [{
+                "id": "7",
+                "name": "whale",
+                "pseudonym": "Giant-Whale",
+                "proxyWallet": "0x0000000000000000000000000000000000000001",
+                "displayUsernamePublic": true,
+                "createdAt": "2024-03-01T12:00:00.000Z"
+            }],
+            "pagination": {"hasMore": true, "totalResults": 57}
+        }"#;
+        let results: SearchResults = serde_json::from_str(json).unwrap();
+        assert_eq!(results.events.len(), 1);
+        let market_ids: Vec<_> = results.markets().map(|m| m.id.as_str()).collect();
+        assert_eq!(market_ids, vec!["10", "11"]);
+        assert_eq!(results.tags[0].slug, "crypto");
+        assert_eq!(results.tags[0].event_count, Some(42));
+        assert_eq!(
+            results.profiles[0].pseudonym.as_deref(),
+            Some("Giant-Whale")
+        );
+        assert_eq!(results.profiles[0].display_username_public, Some(true));
+        assert!(results.profiles[0].created_at.is_some());
+        assert!(results.has_more());
+        assert_eq!(results.pagination.unwrap().total_results, Some(57));
+    }
+
+    #[test]
+    fn test_search_results_null_sections() {
+        let json = r#"{"events": null, "tags": null, "profiles": null}"#;
+        let results: SearchResults = serde_json::from_str(json).unwrap();
+        assert!(results.events.is_empty());
+        assert!(results.tags.is_empty());
+        assert!(results.profiles.is_empty());
+        assert!(!results.has_more());
+    }
+
+    #[test]
+    fn test_public_profile_deserialization() {
+        let json = r#"{
+            "createdAt": "2023-05-14T09:30:00.000Z",
+            "proxyWallet": "0x56687bf447db6ffa42ffe2204a05edaa20f55839",
+            "profileImage": "https://example.com/avatar.png",
+            "displayUsernamePublic": true,
+            "bio": "Macro trader",
+            "pseudonym": "Quiet-Heron",
+            "name": "theo",
+            "users": [{"id": "1234", "creator": false, "mod": true}],
+            "xUsername": "theo_x",
+            "verifiedBadge": true
+        }"#;
+        let profile: PublicProfile = serde_json::from_str(json).unwrap();
+        assert_eq!(profile.bio.as_deref(), Some("Macro trader"));
+        assert_eq!(profile.x_username.as_deref(), Some("theo_x"));
+        assert_eq!(profile.verified_badge, Some(true));
+        assert_eq!(
+            profile.created_at.unwrap().to_rfc3339(),
+            "2023-05-14T09:30:00+00:00"
+        );
+        assert_eq!(profile.users[0].moderator, Some(true));
+        assert_eq!(profile.display_name(), Some("theo"));
+    }
+
+    #[test]
+    fn test_public_profile_display_name_prefers_pseudonym() {
+        let json =
+            r#"{"name": "theo", "pseudonym": "Quiet-Heron", "displayUsernamePublic": false}"#;
+        let profile: PublicProfile = serde_json::from_str(json).unwrap();
+        assert_eq!(profile.display_name(), Some("Quiet-Heron"));
+        assert!(profile.users.is_empty());
+    }
+
     // ── Cursor / PaginatedResponse ──────────────────────────────
 
     #[test]
@@ -854,6 +2290,7 @@ mod tests {
             updated_at: None,
             force_hide: None,
             is_carousel: None,
+            event_count: None,
         };
         let json = serde_json::to_string(&tag).unwrap();
         let back: Tag = serde_json::from_str(&json).unwrap();
@@ -0,0 +1,753 @@
+//! Polling change feed for Gamma events and markets
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use futures_util::stream::{self, BoxStream, StreamExt};
+use polyoxide_core::Paginate;
+
+use crate::{
+    api::events::ListEvents,
+    error::GammaError,
+    types::{Event, Market},
+    Gamma,
+};
+
+/// Default delay between polls
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Default time a market that left the watched set is still followed
+pub const DEFAULT_RETIRED_TTL: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+type EventFilter = Arc<dyn Fn(ListEvents) -> ListEvents + Send + Sync>;
+
+/// A change detected between two Gamma snapshots
+#[derive(Debug, Clone, PartialEq)]
+pub enum GammaChange {
+    /// A new event appeared
+    EventCreated(Box<Event>),
+    /// A new market appeared
+    MarketCreated {
+        /// ID of the event the market belongs to
+        event_id: String,
+        market: Box<Market>,
+    },
+    /// Tracked fields of a market changed
+    MarketUpdated {
+        market: Box<Market>,
+        changes: Vec<FieldChange>,
+    },
+    /// A market closed
+    MarketClosed(Box<Market>),
+    /// A market resolved
+    MarketResolved {
+        market: Box<Market>,
+        /// Winning outcome name; `None` when the market was voided
+        winner: Option<String>,
+    },
+    /// A market started or stopped accepting orders
+    AcceptingOrdersChanged {
+        market: Box<Market>,
+        accepting_orders: bool,
+    },
+    /// A market left the watched set and Gamma no longer knows it (404)
+    MarketRemoved {
+        /// Gamma market ID
+        market_id: String,
+    },
+}
+
+/// Old and new value of a market field, rendered as JSON
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldChange {
+    /// Field name, as in [`Market`]
+    pub field: &'static str,
+    pub old: String,
+    pub new: String,
+}
+
+/// Polls Gamma events and emits typed changes as a [`Stream`](futures_util::Stream).
+///
+/// Each poll lists events (with their markets) using the configured filter and
+/// diffs the markets against the previous poll by ID. The first poll only
+/// records a baseline unless [`GammaWatcher::emit_initial`] is set. Markets
+/// that drop out of the filtered list (typically because they closed) are
+/// fetched individually so their final state is still reported, then looked
+/// up by condition ID with `closed(true)` on every poll until they resolve, so
+/// a resolution that lands after close is still reported. They are followed
+/// for [`GammaWatcher::retired_ttl`]; if one comes back within that time it is
+/// diffed against its last state rather than reported as new.
+///
+/// Prices and volumes are not diffed; use the CLOB websocket for those.
+///
+/// # Example
+///
+/// ```no_run
+/// use futures_util::StreamExt;
+/// use polyoxide_gamma::{Gamma, GammaChange, GammaWatcher};
+/// use std::time::Duration;
+///
+/// # async fn example() -> Result<(), polyoxide_gamma::GammaError> {
+/// let watcher = GammaWatcher::new(Gamma::new()?)
+///     .interval(Duration::from_secs(15))
+///     .filter(|list| list.closed(false).tag_slug("crypto"));
+///
+/// let mut changes = watcher.into_stream();
+/// while let Some(change) = changes.next().await {
+///     if let GammaChange::MarketResolved { market, winner } = change? {
+///         println!("{} resolved to {:?}", market.question, winner);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct GammaWatcher {
+    gamma: Gamma,
+    interval: Duration,
+    filter: EventFilter,
+    max_events: Option<usize>,
+    emit_initial: bool,
+    retired_ttl: Duration,
+}
+
+impl GammaWatcher {
+    /// Watch open events, polling every [`DEFAULT_POLL_INTERVAL`]
+    pub fn new(gamma: Gamma) -> Self {
+        Self {
+            gamma,
+            interval: DEFAULT_POLL_INTERVAL,
+            filter: Arc::new(|list| list.closed(false)),
+            max_events: None,
+            emit_initial: false,
+            retired_ttl: DEFAULT_RETIRED_TTL,
+        }
+    }
+
+    /// Set the delay between polls
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Configure the event listing used on every poll, replacing the default
+    /// `closed(false)` filter
+    pub fn filter(
+        mut self,
+        filter: impl Fn(ListEvents) -> ListEvents + Send + Sync + 'static,
+    ) -> Self {
+        self.filter = Arc::new(filter);
+        self
+    }
+
+    /// Cap the number of events fetched per poll
+    pub fn max_events(mut self, max_events: usize) -> Self {
+        self.max_events = Some(max_events);
+        self
+    }
+
+    /// Emit `EventCreated`/`MarketCreated` for everything seen on the first poll
+    pub fn emit_initial(mut self, emit: bool) -> Self {
+        self.emit_initial = emit;
+        self
+    }
+
+    /// Set how long markets and events that left the watched set are
+    /// remembered (default [`DEFAULT_RETIRED_TTL`])
+    pub fn retired_ttl(mut self, ttl: Duration) -> Self {
+        self.retired_ttl = ttl;
+        self
+    }
+
+    /// Start polling and stream the detected changes.
+    ///
+    /// Poll errors are yielded as `Err` items; polling continues on the next tick.
+    pub fn into_stream(self) -> BoxStream<'static, Result<GammaChange, GammaError>> {
+        let state = WatchState {
+            watcher: self,
+            snapshot: None,
+            retired: Retired::default(),
+            pending: VecDeque::new(),
+            polled: false,
+        };
+
+        stream::unfold(state, |mut state| async move {
+            loop {
+                if let Some(item) = state.pending.pop_front() {
+                    return Some((item, state));
+                }
+                if state.polled {
+                    tokio::time::sleep(state.watcher.interval).await;
+                }
+                state.poll().await;
+            }
+        })
+        .boxed()
+    }
+
+    async fn fetch(&self) -> Result<Snapshot, GammaError> {
+        let list = (self.filter)(self.gamma.events().list());
+        let events = match self.max_events {
+            Some(max) => list.max_items(max).all().await?,
+            None => list.all().await?,
+        };
+        Ok(Snapshot::from_events(events))
+    }
+}
+
+impl std::fmt::Debug for GammaWatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GammaWatcher")
+            .field("interval", &self.interval)
+            .field("max_events", &self.max_events)
+            .field("emit_initial", &self.emit_initial)
+            .field("retired_ttl", &self.retired_ttl)
+            .finish_non_exhaustive()
+    }
+}
+
+struct WatchState {
+    watcher: GammaWatcher,
+    snapshot: Option<Snapshot>,
+    retired: Retired,
+    pending: VecDeque<Result<GammaChange, GammaError>>,
+    polled: bool,
+}
+
+/// Last known state of markets and events that dropped out of the watched
+/// set, with the time each one left
+#[derive(Debug, Default)]
+struct Retired {
+    events: HashMap<String, (Instant, Event)>,
+    markets: HashMap<String, (Instant, Market)>,
+}
+
+impl WatchState {
+    async fn poll(&mut self) {
+        self.polled = true;
+        let mut next = match self.watcher.fetch().await {
+            Ok(next) => next,
+            Err(e) => {
+                // Keep the baseline (if any) so the next poll diffs against it
+                self.pending.push_back(Err(e));
+                return;
+            }
+        };
+
+        let Some(mut prev) = self.snapshot.take() else {
+            if self.watcher.emit_initial {
+                self.pending
+                    .extend(diff(&Snapshot::default(), &next).into_iter().map(Ok));
+            }
+            self.snapshot = Some(next);
+            return;
+        };
+
+        self.retired.restore(&mut prev, &next);
+        self.pending.extend(diff(&prev, &next).into_iter().map(Ok));
+
+        let now = Instant::now();
+        self.retired.prune(now, self.watcher.retired_ttl);
+        self.follow_retired().await;
+
+        // Markets that dropped out of the filtered list: report their final state
+        let mut dropped: Vec<&String> = prev
+            .markets
+            .keys()
+            .filter(|id| !next.markets.contains_key(*id))
+            .collect();
+        dropped.sort();
+        for id in dropped {
+            let old = &prev.markets[id];
+            match self.watcher.gamma.markets().get(id).send().await {
+                Ok(market) => {
+                    self.pending
+                        .extend(diff_market(old, &market).into_iter().map(Ok));
+                    self.retired.markets.insert(id.clone(), (now, market));
+                }
+                Err(e) if e.is_not_found() => {
+                    self.pending.push_back(Ok(GammaChange::MarketRemoved {
+                        market_id: id.clone(),
+                    }));
+                }
+                Err(e) => {
+                    // Keep the market so the next poll fetches it again
+                    self.pending.push_back(Err(e));
+                    next.keep(&prev, id);
+                }
+            }
+        }
+
+        for (id, event) in prev.events {
+            if !next.events.contains_key(&id) {
+                self.retired.events.insert(id, (now, event));
+            }
+        }
+        self.snapshot = Some(next);
+    }
+
+    /// Look up retired markets that have not resolved yet and report changes
+    async fn follow_retired(&mut self) {
+        let condition_ids = self.retired.unresolved();
+        if condition_ids.is_empty() {
+            return;
+        }
+        let markets = self.watcher.gamma.markets();
+        match markets
+            .by_condition_ids(&condition_ids, |list| list.closed(true))
+            .await
+        {
+            Ok(markets) => {
+                let changes = self.retired.update(markets);
+                self.pending.extend(changes.into_iter().map(Ok));
+            }
+            Err(e) => self.pending.push_back(Err(e)),
+        }
+    }
+}
+
+/// Markets and event IDs seen in one poll
+#[derive(Debug, Default)]
+struct Snapshot {
+    events: HashMap<String, Event>,
+    markets: HashMap<String, Market>,
+    market_events: HashMap<String, String>,
+}
+
+impl Snapshot {
+    fn from_events(events: Vec<Event>) -> Self {
+        let mut snapshot = Self::default();
+        for mut event in events {
+            for market in std::mem::take(&mut event.markets) {
+                snapshot
+                    .market_events
+                    .insert(market.id.clone(), event.id.clone());
+                snapshot.markets.insert(market.id.clone(), market);
+            }
+            snapshot.events.insert(event.id.clone(), event);
+        }
+        snapshot
+    }
+
+    /// Carry a market (and its event ID) over from `prev`
+    fn keep(&mut self, prev: &Snapshot, id: &str) {
+        self.markets
+            .insert(id.to_string(), prev.markets[id].clone());
+        if let Some(event_id) = prev.market_events.get(id) {
+            self.market_events.insert(id.to_string(), event_id.clone());
+        }
+    }
+}
+
+impl Retired {
+    /// Move entries that reappear in `next` back into `prev` so they are
+    /// diffed against their last known state
+    fn restore(&mut self, prev: &mut Snapshot, next: &Snapshot) {
+        for id in next.markets.keys() {
+            if !prev.markets.contains_key(id) {
+                if let Some((_, market)) = self.markets.remove(id) {
+                    prev.markets.insert(id.clone(), market);
+                }
+            }
+        }
+        for id in next.events.keys() {
+            if !prev.events.contains_key(id) {
+                if let Some((_, event)) = self.events.remove(id) {
+                    prev.events.insert(id.clone(), event);
+                }
+            }
+        }
+    }
+
+    /// Forget entries that left the watched set more than `ttl` before `now`
+    fn prune(&mut self, now: Instant, ttl: Duration) {
+        let live = |retired_at: &Instant| now.duration_since(*retired_at) <= ttl;
+        self.markets.retain(|_, (retired_at, _)| live(retired_at));
+        self.events.retain(|_, (retired_at, _)| live(retired_at));
+    }
+
+    /// Condition IDs of retired markets that have not resolved yet, sorted
+    fn unresolved(&self) -> Vec<String> {
+        let mut ids: Vec<String> = self
+            .markets
+            .values()
+            .filter(|(_, market)| resolution(market).is_none())
+            .map(|(_, market)| market.condition_id.clone())
+            .collect();
+        ids.sort_unstable();
+        ids.dedup();
+        ids
+    }
+
+    /// Diff freshly fetched versions of retired markets against their last
+    /// state and keep the new versions
+    fn update(&mut self, mut markets: Vec<Market>) -> Vec<GammaChange> {
+        markets.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let mut changes = Vec::new();
+        for market in markets {
+            if let Some((_, old)) = self.markets.get_mut(&market.id) {
+                changes.extend(diff_market(old, &market));
+                *old = market;
+            }
+        }
+        changes
+    }
+}
+
+/// Changes between two snapshots, excluding markets missing from `next`
+fn diff(prev: &Snapshot, next: &Snapshot) -> Vec<GammaChange> {
+    let mut changes = Vec::new();
+
+    let known_events: HashSet<&String> = prev.events.keys().collect();
+    let mut new_events: Vec<&Event> = next
+        .events
+        .values()
+        .filter(|e| !known_events.contains(&e.id))
+        .collect();
+    new_events.sort_by(|a, b| a.id.cmp(&b.id));
+    changes.extend(
+        new_events
+            .into_iter()
+            .map(|e| GammaChange::EventCreated(Box::new(e.clone()))),
+    );
+
+    let mut ids: Vec<&String> = next.markets.keys().collect();
+    ids.sort();
+    for id in ids {
+        let market = &next.markets[id];
+        match prev.markets.get(id) {
+            Some(old) => changes.extend(diff_market(old, market)),
+            None => changes.push(GammaChange::MarketCreated {
+                event_id: next.market_events.get(id).cloned().unwrap_or_default(),
+                market: Box::new(market.clone()),
+            }),
+        }
+    }
+    changes
+}
+
+/// Changes between two versions of the same market
+fn diff_market(old: &Market, new: &Market) -> Vec<GammaChange> {
+    let mut changes = Vec::new();
+
+    if old.closed != Some(true) && new.closed == Some(true) {
+        changes.push(GammaChange::MarketClosed(Box::new(new.clone())));
+    }
+
+    if let (None, Some(winner)) = (resolution(old), resolution(new)) {
+        changes.push(GammaChange::MarketResolved {
+            market: Box::new(new.clone()),
+            winner,
+        });
+    }
+
+    let accepting = new.accepting_orders.unwrap_or(false);
+    if old.accepting_orders.unwrap_or(false) != accepting {
+        changes.push(GammaChange::AcceptingOrdersChanged {
+            market: Box::new(new.clone()),
+            accepting_orders: accepting,
+        });
+    }
+
+    let fields = field_changes(old, new);
+    if !fields.is_empty() {
+        changes.push(GammaChange::MarketUpdated {
+            market: Box::new(new.clone()),
+            changes: fields,
+        });
+    }
+    changes
+}
+
+/// `Some(winner)` once a market has resolved or been voided, `None` while unresolved
+fn resolution(market: &Market) -> Option<Option<String>> {
+    let state = market.resolution_state();
+    state
+        .is_final()
+        .then(|| state.winner().map(|outcome| outcome.name.clone()))
+}
+
+/// Diff the market fields worth reporting; status fields have dedicated events
+fn field_changes(old: &Market, new: &Market) -> Vec<FieldChange> {
+    let mut changes = Vec::new();
+    macro_rules! track {
+        ($($field:ident),* $(,)?) => {
+            $(
+                if old.$field != new.$field {
+                    changes.push(FieldChange {
+                        field: stringify!($field),
+                        old: render(&old.$field),
+                        new: render(&new.$field),
+                    });
+                }
+            )*
+        };
+    }
+    track!(
+        question,
+        description,
+        end_date_iso,
+        active,
+        archived,
+        restricted,
+        enable_order_book,
+        neg_risk,
+        uma_resolution_status,
+        minimum_tick_size,
+        minimum_order_size,
+        clob_token_ids,
+    );
+    changes
+}
+
+fn render<T: serde::Serialize>(value: &T) -> String {
+    serde_json::to_string(value).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn market(id: &str, extra: serde_json::Value) -> Market {
+        let mut json = serde_json::json!({
+            "id": id,
+            "conditionId": format!("0x{id}"),
+            "description": "",
+            "question": format!("Q{id}?"),
+            "marketMakerAddress": "",
+            "outcomes": "[\"Yes\", \"No\"]",
+            "outcomePrices": "[\"0.5\", \"0.5\"]",
+            "closed": false,
+            "acceptingOrders": true,
+        });
+        json.as_object_mut()
+            .unwrap()
+            .extend(extra.as_object().unwrap().clone());
+        serde_json::from_value(json).unwrap()
+    }
+
+    fn event(id: &str, markets: Vec<Market>) -> Event {
+        let mut event: Event = serde_json::from_value(serde_json::json!({ "id": id })).unwrap();
+        event.markets = markets;
+        event
+    }
+
+    fn snapshot(events: Vec<Event>) -> Snapshot {
+        Snapshot::from_events(events)
+    }
+
+    #[test]
+    fn test_identical_snapshots_have_no_changes() {
+        let a = snapshot(vec![event("1", vec![market("10", serde_json::json!({}))])]);
+        let b = snapshot(vec![event("1", vec![market("10", serde_json::json!({}))])]);
+        assert!(diff(&a, &b).is_empty());
+    }
+
+    #[test]
+    fn test_created_event_and_market() {
+        let prev = snapshot(vec![event("1", vec![market("10", serde_json::json!({}))])]);
+        let next = snapshot(vec![
+            event("1", vec![market("10", serde_json::json!({}))]),
+            event("2", vec![market("20", serde_json::json!({}))]),
+        ]);
+        let changes = diff(&prev, &next);
+        assert_eq!(changes.len(), 2);
+        assert!(matches!(&changes[0], GammaChange::EventCreated(e) if e.id == "2"));
+        assert!(matches!(
+            &changes[1],
+            GammaChange::MarketCreated { event_id, market } if event_id == "2" && market.id == "20"
+        ));
+    }
+
+    #[test]
+    fn test_snapshot_strips_nested_markets() {
+        let snap = snapshot(vec![event("1", vec![market("10", serde_json::json!({}))])]);
+        assert!(snap.events["1"].markets.is_empty());
+        assert_eq!(snap.market_events["10"], "1");
+    }
+
+    #[test]
+    fn test_closed_and_resolved_with_winner() {
+        let old = market("10", serde_json::json!({}));
+        let new = market(
+            "10",
+            serde_json::json!({
+                "closed": true,
+                "acceptingOrders": false,
+                "outcomePrices": "[\"0\", \"1\"]",
+                "umaResolutionStatus": "resolved",
+            }),
+        );
+        let changes = diff_market(&old, &new);
+        assert!(matches!(&changes[0], GammaChange::MarketClosed(_)));
+        assert!(matches!(
+            &changes[1],
+            GammaChange::MarketResolved { winner: Some(w), .. } if w == "No"
+        ));
+        assert!(matches!(
+            &changes[2],
+            GammaChange::AcceptingOrdersChanged {
+                accepting_orders: false,
+                ..
+            }
+        ));
+        let GammaChange::MarketUpdated {
+            changes: fields, ..
+        } = &changes[3]
+        else {
+            panic!("expected field update, got {:?}", changes[3]);
+        };
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].field, "uma_resolution_status");
+        assert_eq!(fields[0].old, "null");
+        assert_eq!(fields[0].new, "\"resolved\"");
+    }
+
+    #[test]
+    fn test_closed_without_resolution() {
+        let old = market("10", serde_json::json!({}));
+        let new = market("10", serde_json::json!({ "closed": true }));
+        let changes = diff_market(&old, &new);
+        assert_eq!(changes.len(), 1);
+        assert!(matches!(&changes[0], GammaChange::MarketClosed(_)));
+    }
+
+    #[test]
+    fn test_resolution_reported_once() {
+        let resolved = market(
+            "10",
+            serde_json::json!({ "closed": true, "outcomePrices": "[\"1\", \"0\"]" }),
+        );
+        assert!(diff_market(&resolved, &resolved).is_empty());
+    }
+
+    #[test]
+    fn test_uma_resolution_without_clear_winner() {
+        let old = market("10", serde_json::json!({ "closed": true }));
+        let new = market(
+            "10",
+            serde_json::json!({ "closed": true, "umaResolutionStatus": "resolved" }),
+        );
+        let changes = diff_market(&old, &new);
+        assert!(matches!(
+            &changes[0],
+            GammaChange::MarketResolved { winner: None, .. }
+        ));
+    }
+
+    #[test]
+    fn test_price_moves_are_ignored() {
+        let old = market("10", serde_json::json!({}));
+        let new = market(
+            "10",
+            serde_json::json!({ "outcomePrices": "[\"0.7\", \"0.3\"]", "volume": "123" }),
+        );
+        assert!(diff_market(&old, &new).is_empty());
+    }
+
+    #[test]
+    fn test_missing_markets_left_to_caller() {
+        let prev = snapshot(vec![event("1", vec![market("10", serde_json::json!({}))])]);
+        let next = snapshot(vec![event("1", vec![])]);
+        assert!(diff(&prev, &next).is_empty());
+    }
+
+    #[test]
+    fn test_reappearing_market_is_not_created_again() {
+        let now = Instant::now();
+        let mut retired = Retired::default();
+        retired
+            .markets
+            .insert("10".into(), (now, market("10", serde_json::json!({}))));
+        retired.events.insert("1".into(), (now, event("1", vec![])));
+
+        let mut prev = snapshot(vec![]);
+        let next = snapshot(vec![event(
+            "1",
+            vec![market("10", serde_json::json!({ "question": "Reworded?" }))],
+        )]);
+        retired.restore(&mut prev, &next);
+        assert!(retired.markets.is_empty() && retired.events.is_empty());
+
+        let changes = diff(&prev, &next);
+        assert_eq!(changes.len(), 1);
+        assert!(matches!(
+            &changes[0],
+            GammaChange::MarketUpdated { changes, .. } if changes[0].field == "question"
+        ));
+    }
+
+    #[test]
+    fn test_retired_entries_expire_after_ttl() {
+        let start = Instant::now();
+        let mut retired = Retired::default();
+        retired
+            .markets
+            .insert("10".into(), (start, market("10", serde_json::json!({}))));
+        retired
+            .events
+            .insert("1".into(), (start, event("1", vec![])));
+        let later = start + Duration::from_secs(60);
+        retired
+            .markets
+            .insert("20".into(), (later, market("20", serde_json::json!({}))));
+
+        retired.prune(later, Duration::from_secs(30));
+        assert_eq!(retired.markets.keys().collect::<Vec<_>>(), ["20"]);
+        assert!(retired.events.is_empty());
+    }
+
+    #[test]
+    fn test_retired_market_resolution_after_close_is_reported() {
+        let now = Instant::now();
+        let closed = market("10", serde_json::json!({ "closed": true }));
+        let resolved = market(
+            "20",
+            serde_json::json!({ "closed": true, "outcomePrices": "[\"1\", \"0\"]" }),
+        );
+        let mut retired = Retired::default();
+        retired.markets.insert("10".into(), (now, closed));
+        retired.markets.insert("20".into(), (now, resolved));
+        // Already resolved markets are not looked up again
+        assert_eq!(retired.unresolved(), ["0x10"]);
+
+        let changes = retired.update(vec![market(
+            "10",
+            serde_json::json!({
+                "closed": true,
+                "outcomePrices": "[\"0\", \"1\"]",
+                "umaResolutionStatus": "resolved",
+            }),
+        )]);
+        assert!(matches!(
+            &changes[0],
+            GammaChange::MarketResolved { winner: Some(w), .. } if w == "No"
+        ));
+        assert!(retired.unresolved().is_empty());
+    }
+
+    #[test]
+    fn test_keep_carries_market_into_next_snapshot() {
+        let prev = snapshot(vec![event("1", vec![market("10", serde_json::json!({}))])]);
+        let mut next = snapshot(vec![]);
+        next.keep(&prev, "10");
+        assert_eq!(next.markets["10"].id, "10");
+        assert_eq!(next.market_events["10"], "1");
+        assert!(diff(&prev, &next).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_first_poll_error_is_yielded() {
+        let gamma = Gamma::builder()
+            .base_url("http://127.0.0.1:1")
+            .build()
+            .unwrap();
+        let mut stream = GammaWatcher::new(gamma)
+            .interval(Duration::from_millis(10))
+            .into_stream();
+        assert!(stream.next().await.unwrap().is_err());
+        assert!(stream.next().await.unwrap().is_err());
+    }
+}
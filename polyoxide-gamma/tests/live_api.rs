@@ -8,7 +8,7 @@
 //! cargo test -p polyoxide-gamma --test live_api -- --ignored
 //! ```
 
-use polyoxide_gamma::Gamma;
+use polyoxide_gamma::{Gamma, Paginate};
 use std::time::Duration;
 
 fn client() -> Gamma {
@@ -135,6 +135,27 @@ async fn live_list_markets_closed_false() {
     }
 }
 
+#[tokio::test]
+#[ignore]
+async fn live_list_markets_paginates() {
+    let gamma = client();
+    let markets = gamma
+        .markets()
+        .list()
+        .closed(false)
+        .limit(5)
+        .max_items(12)
+        .all()
+        .await
+        .expect("paginate markets");
+    assert_eq!(markets.len(), 12, "should span three pages");
+
+    let mut ids: Vec<_> = markets.iter().map(|m| m.id.as_str()).collect();
+    ids.sort_unstable();
+    ids.dedup();
+    assert_eq!(ids.len(), 12, "pages should not overlap");
+}
+
 // ── Events ──────────────────────────────────────────────────────
 
 #[tokio::test]
@@ -346,6 +367,22 @@ async fn live_list_teams() {
     assert!(!teams.is_empty(), "should return at least one team");
 }
 
+#[tokio::test]
+#[ignore]
+async fn live_sports_events() {
+    let gamma = client();
+    let events = gamma
+        .sports()
+        .events("nba")
+        .send()
+        .await
+        .expect("sports events");
+    for e in &events {
+        assert!(e.sport.sport.eq_ignore_ascii_case("nba"));
+        assert!(e.game.team_a.is_none() || e.game.team_a.as_ref().unwrap().id > 0);
+    }
+}
+
 // ── Comments ────────────────────────────────────────────────────
 
 #[tokio::test]
@@ -378,6 +415,35 @@ async fn live_list_comments() {
     let _ = comments;
 }
 
+#[tokio::test]
+#[ignore]
+async fn live_comment_threads() {
+    let gamma = client();
+    let events = gamma
+        .events()
+        .list()
+        .order("commentCount")
+        .ascending(false)
+        .limit(1)
+        .send()
+        .await
+        .expect("list events to discover id for comments");
+    let event_id: i64 = events[0].id.parse().expect("event id should be numeric");
+
+    let threads = gamma
+        .comments()
+        .list()
+        .parent_entity_type("Event")
+        .parent_entity_id(event_id)
+        .max_items(50)
+        .all()
+        .await
+        .map(polyoxide_gamma::types::CommentThread::build)
+        .expect("comment threads");
+    let total: usize = threads.iter().map(|t| t.len()).sum();
+    assert!(total <= 50);
+}
+
 // ── User ────────────────────────────────────────────────────────
 
 #[tokio::test]
@@ -421,3 +487,56 @@ async fn live_get_user() {
     // If no comments found, skip silently -- the endpoint itself is
     // exercised in the request path even when no suitable address exists.
 }
+
+// ── Search ──────────────────────────────────────────────────────
+
+#[tokio::test]
+#[ignore]
+async fn live_search() {
+    let gamma = client();
+    let results = gamma
+        .search()
+        .query("bitcoin")
+        .limit_per_type(5)
+        .search_tags(true)
+        .send()
+        .await
+        .expect("search");
+    assert!(
+        !results.events.is_empty(),
+        "should match at least one event"
+    );
+    assert!(
+        results.markets().next().is_some(),
+        "events should carry markets"
+    );
+}
+
+// ── User ────────────────────────────────────────────────────────
+
+#[tokio::test]
+#[ignore]
+async fn live_public_profile() {
+    let gamma = client();
+    // Find a profile through search so the address is known to exist
+    let results = gamma
+        .search()
+        .query("trader")
+        .search_profiles(true)
+        .send()
+        .await
+        .expect("search profiles");
+    let Some(address) = results.profiles.iter().find_map(|p| p.proxy_wallet.clone()) else {
+        return;
+    };
+    let profile = gamma
+        .user()
+        .profile(&address)
+        .send()
+        .await
+        .expect("public profile");
+    assert_eq!(
+        profile.proxy_wallet.as_deref().map(str::to_lowercase),
+        Some(address.to_lowercase())
+    );
+}
@@ -0,0 +1,348 @@
+use crate::types::SafeTransaction;
+use alloy::primitives::{Address, Bytes, B256, U256};
+use alloy::sol;
+use alloy::sol_types::SolCall;
+
+sol! {
+    interface IERC20 {
+        function transfer(address to, uint256 amount) external returns (bool);
+        function approve(address spender, uint256 amount) external returns (bool);
+        function balanceOf(address account) external view returns (uint256);
+        function allowance(address owner, address spender) external view returns (uint256);
+    }
+
+    interface IERC1155 {
+        function safeTransferFrom(address from, address to, uint256 id, uint256 amount, bytes data) external;
+        function setApprovalForAll(address operator, bool approved) external;
+        function balanceOf(address account, uint256 id) external view returns (uint256);
+        function balanceOfBatch(address[] accounts, uint256[] ids) external view returns (uint256[]);
+        function isApprovedForAll(address account, address operator) external view returns (bool);
+    }
+
+    interface IConditionalTokens {
+        function splitPosition(address collateralToken, bytes32 parentCollectionId, bytes32 conditionId, uint256[] partition, uint256 amount) external;
+        function mergePositions(address collateralToken, bytes32 parentCollectionId, bytes32 conditionId, uint256[] partition, uint256 amount) external;
+        function redeemPositions(address collateralToken, bytes32 parentCollectionId, bytes32 conditionId, uint256[] indexSets) external;
+    }
+
+    interface INegRiskAdapter {
+        function mergePositions(bytes32 conditionId, uint256 amount) external;
+        function redeemPositions(bytes32 conditionId, uint256[] amounts) external;
+    }
+}
+
+/// A single contract call to be relayed through a Safe or proxy wallet.
+///
+/// Calls are always executed as a plain `CALL` from the wallet. Use the typed
+/// constructors for the common Polymarket contracts, or [`RelayCall::from_sol_call`]
+/// for any other `alloy` [`SolCall`].
+///
+/// # Example
+///
+/// ```
+/// use polyoxide_relay::RelayCall;
+/// use alloy::primitives::{address, U256};
+///
+/// let usdc = address!("2791Bca1f2de4661ED88A30C99A7a9449Aa84174");
+/// let recipient = address!("0000000000000000000000000000000000000001");
+/// let call = RelayCall::erc20_transfer(usdc, recipient, U256::from(1_000_000u64));
+/// assert_eq!(call.to, usdc);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelayCall {
+    /// Contract to call
+    pub to: Address,
+    /// Native value (POL) forwarded with the call
+    pub value: U256,
+    /// ABI-encoded calldata
+    pub data: Bytes,
+}
+
+impl RelayCall {
+    /// Create a call from raw calldata
+    pub fn new(to: Address, data: impl Into<Bytes>) -> Self {
+        Self {
+            to,
+            value: U256::ZERO,
+            data: data.into(),
+        }
+    }
+
+    /// Create a call from any `alloy` [`SolCall`]
+    pub fn from_sol_call<C: SolCall>(to: Address, call: &C) -> Self {
+        Self::new(to, call.abi_encode())
+    }
+
+    /// Set the native value forwarded with the call
+    pub fn with_value(mut self, value: U256) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// ERC-20 `transfer(to, amount)`
+    pub fn erc20_transfer(token: Address, to: Address, amount: U256) -> Self {
+        Self::from_sol_call(token, &IERC20::transferCall { to, amount })
+    }
+
+    /// ERC-20 `approve(spender, amount)`
+    pub fn erc20_approve(token: Address, spender: Address, amount: U256) -> Self {
+        Self::from_sol_call(token, &IERC20::approveCall { spender, amount })
+    }
+
+    /// ERC-1155 `safeTransferFrom(from, to, id, amount, "")`
+    pub fn erc1155_safe_transfer_from(
+        token: Address,
+        from: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+    ) -> Self {
+        Self::from_sol_call(
+            token,
+            &IERC1155::safeTransferFromCall {
+                from,
+                to,
+                id,
+                amount,
+                data: Bytes::new(),
+            },
+        )
+    }
+
+    /// ERC-1155 `setApprovalForAll(operator, approved)`
+    pub fn erc1155_set_approval_for_all(token: Address, operator: Address, approved: bool) -> Self {
+        Self::from_sol_call(
+            token,
+            &IERC1155::setApprovalForAllCall { operator, approved },
+        )
+    }
+
+    /// Conditional Tokens `splitPosition`
+    ///
+    /// Splits `amount` of collateral (or of the parent position) into one
+    /// position per entry of `partition`.
+    pub fn split_position(
+        conditional_tokens: Address,
+        collateral: Address,
+        parent_collection_id: B256,
+        condition_id: B256,
+        partition: Vec<U256>,
+        amount: U256,
+    ) -> Self {
+        Self::from_sol_call(
+            conditional_tokens,
+            &IConditionalTokens::splitPositionCall {
+                collateralToken: collateral,
+                parentCollectionId: parent_collection_id,
+                conditionId: condition_id,
+                partition,
+                amount,
+            },
+        )
+    }
+
+    /// Conditional Tokens `mergePositions`
+    ///
+    /// Merges `amount` of each position in `partition` back into collateral
+    /// (or into the parent position).
+    pub fn merge_positions(
+        conditional_tokens: Address,
+        collateral: Address,
+        parent_collection_id: B256,
+        condition_id: B256,
+        partition: Vec<U256>,
+        amount: U256,
+    ) -> Self {
+        Self::from_sol_call(
+            conditional_tokens,
+            &IConditionalTokens::mergePositionsCall {
+                collateralToken: collateral,
+                parentCollectionId: parent_collection_id,
+                conditionId: condition_id,
+                partition,
+                amount,
+            },
+        )
+    }
+
+    /// Conditional Tokens `redeemPositions` for a resolved condition
+    pub fn redeem_positions(
+        conditional_tokens: Address,
+        collateral: Address,
+        parent_collection_id: B256,
+        condition_id: B256,
+        index_sets: Vec<U256>,
+    ) -> Self {
+        Self::from_sol_call(
+            conditional_tokens,
+            &IConditionalTokens::redeemPositionsCall {
+                collateralToken: collateral,
+                parentCollectionId: parent_collection_id,
+                conditionId: condition_id,
+                indexSets: index_sets,
+            },
+        )
+    }
+
+    /// Neg-risk adapter `mergePositions`
+    ///
+    /// Merges `amount` of every outcome of a negative-risk condition back into
+    /// collateral.
+    pub fn neg_risk_merge_positions(adapter: Address, condition_id: B256, amount: U256) -> Self {
+        Self::from_sol_call(
+            adapter,
+            &INegRiskAdapter::mergePositionsCall {
+                conditionId: condition_id,
+                amount,
+            },
+        )
+    }
+
+    /// Neg-risk adapter `redeemPositions` for a resolved condition
+    ///
+    /// `amounts` holds the balance to redeem of each outcome, in outcome order.
+    pub fn neg_risk_redeem_positions(
+        adapter: Address,
+        condition_id: B256,
+        amounts: Vec<U256>,
+    ) -> Self {
+        Self::from_sol_call(
+            adapter,
+            &INegRiskAdapter::redeemPositionsCall {
+                conditionId: condition_id,
+                amounts,
+            },
+        )
+    }
+}
+
+impl From<RelayCall> for SafeTransaction {
+    fn from(call: RelayCall) -> Self {
+        SafeTransaction {
+            to: call.to,
+            operation: 0, // 0 = Call
+            data: call.data,
+            value: call.value,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::hex;
+
+    const TOKEN: Address = Address::repeat_byte(0x11);
+    const ALICE: Address = Address::repeat_byte(0xaa);
+
+    fn selector(call: &RelayCall) -> String {
+        hex::encode(&call.data[..4])
+    }
+
+    #[test]
+    fn test_new_defaults_to_zero_value() {
+        let call = RelayCall::new(TOKEN, vec![0xde, 0xad]);
+        assert_eq!(call.to, TOKEN);
+        assert_eq!(call.value, U256::ZERO);
+        assert_eq!(call.data.as_ref(), &[0xde, 0xad]);
+    }
+
+    #[test]
+    fn test_with_value() {
+        let call = RelayCall::new(TOKEN, Bytes::new()).with_value(U256::from(7));
+        assert_eq!(call.value, U256::from(7));
+    }
+
+    #[test]
+    fn test_erc20_transfer_encoding() {
+        let call = RelayCall::erc20_transfer(TOKEN, ALICE, U256::from(1_000_000u64));
+        assert_eq!(selector(&call), "a9059cbb");
+        let decoded = IERC20::transferCall::abi_decode(&call.data).unwrap();
+        assert_eq!(decoded.to, ALICE);
+        assert_eq!(decoded.amount, U256::from(1_000_000u64));
+    }
+
+    #[test]
+    fn test_erc20_approve_encoding() {
+        let call = RelayCall::erc20_approve(TOKEN, ALICE, U256::MAX);
+        assert_eq!(selector(&call), "095ea7b3");
+    }
+
+    #[test]
+    fn test_erc1155_safe_transfer_from_encoding() {
+        let call = RelayCall::erc1155_safe_transfer_from(
+            TOKEN,
+            ALICE,
+            Address::repeat_byte(0xbb),
+            U256::from(42),
+            U256::from(5),
+        );
+        assert_eq!(selector(&call), "f242432a");
+        let decoded = IERC1155::safeTransferFromCall::abi_decode(&call.data).unwrap();
+        assert_eq!(decoded.id, U256::from(42));
+        assert!(decoded.data.is_empty());
+    }
+
+    #[test]
+    fn test_erc1155_set_approval_for_all_encoding() {
+        let call = RelayCall::erc1155_set_approval_for_all(TOKEN, ALICE, true);
+        assert_eq!(selector(&call), "a22cb465");
+    }
+
+    #[test]
+    fn test_ctf_calls_round_trip() {
+        let condition_id = B256::repeat_byte(0x42);
+        let partition = vec![U256::from(1), U256::from(2)];
+
+        let split = RelayCall::split_position(
+            TOKEN,
+            ALICE,
+            B256::ZERO,
+            condition_id,
+            partition.clone(),
+            U256::from(10),
+        );
+        let decoded = IConditionalTokens::splitPositionCall::abi_decode(&split.data).unwrap();
+        assert_eq!(decoded.conditionId, condition_id);
+        assert_eq!(decoded.partition, partition);
+
+        let merge = RelayCall::merge_positions(
+            TOKEN,
+            ALICE,
+            B256::ZERO,
+            condition_id,
+            partition.clone(),
+            U256::from(10),
+        );
+        assert!(IConditionalTokens::mergePositionsCall::abi_decode(&merge.data).is_ok());
+
+        let redeem = RelayCall::redeem_positions(TOKEN, ALICE, B256::ZERO, condition_id, partition);
+        assert_eq!(selector(&redeem), "01b7037c");
+    }
+
+    #[test]
+    fn test_neg_risk_calls_round_trip() {
+        let condition_id = B256::repeat_byte(0x42);
+
+        let merge = RelayCall::neg_risk_merge_positions(TOKEN, condition_id, U256::from(10));
+        assert_eq!(merge.to, TOKEN);
+        let decoded = INegRiskAdapter::mergePositionsCall::abi_decode(&merge.data).unwrap();
+        assert_eq!(decoded.conditionId, condition_id);
+        assert_eq!(decoded.amount, U256::from(10));
+
+        let amounts = vec![U256::from(5), U256::ZERO];
+        let redeem = RelayCall::neg_risk_redeem_positions(TOKEN, condition_id, amounts.clone());
+        let decoded = INegRiskAdapter::redeemPositionsCall::abi_decode(&redeem.data).unwrap();
+        assert_eq!(decoded.amounts, amounts);
+    }
+
+    #[test]
+    fn test_into_safe_transaction_is_plain_call() {
+        let call = RelayCall::erc20_transfer(TOKEN, ALICE, U256::from(1)).with_value(U256::from(3));
+        let tx: SafeTransaction = call.clone().into();
+        assert_eq!(tx.to, TOKEN);
+        assert_eq!(tx.operation, 0);
+        assert_eq!(tx.value, U256::from(3));
+        assert_eq!(tx.data, call.data);
+    }
+}
@@ -1,19 +1,24 @@
 use crate::account::BuilderAccount;
+use crate::calls::RelayCall;
 use crate::config::{get_contract_config, BuilderConfig, ContractConfig};
 use crate::error::RelayError;
+use crate::nonce::NonceManager;
+use crate::onchain::OnChain;
 use crate::types::{
     NonceResponse, RelayerTransactionResponse, SafeTransaction, SafeTx, TransactionStatusResponse,
     WalletType,
 };
 use alloy::hex;
 use alloy::network::TransactionBuilder;
-use alloy::primitives::{keccak256, Address, Bytes, U256};
+use alloy::primitives::{keccak256, Address, Bytes, B256, U256};
 use alloy::providers::{Provider, ProviderBuilder};
+use alloy::rpc::types::state::StateOverridesBuilder;
 use alloy::rpc::types::TransactionRequest;
 use alloy::signers::Signer;
 use alloy::sol_types::{Eip712Domain, SolCall, SolStruct, SolValue};
 use polyoxide_core::{retry_after_header, HttpClient, HttpClientBuilder, RateLimiter, RetryConfig};
 use serde::Serialize;
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 use url::Url;
 
@@ -21,6 +26,9 @@ use url::Url;
 const SAFE_INIT_CODE_HASH: &str =
     "2bce2127ff07fb632d16c8347c4ebf501f4841168bed00d9e6ef715ddb6fcecf";
 
+// Index sets of the two outcomes of a binary condition
+const BINARY_PARTITION: [u64; 2] = [1, 2];
+
 // From Polymarket Relayer Client
 const PROXY_INIT_CODE_HASH: &str =
     "d21df8dc65880a8606f09fe0ce3df9b8869287ab0b058be05aa9e8af6330a00b";
@@ -32,6 +40,9 @@ pub struct RelayClient {
     account: Option<BuilderAccount>,
     contract_config: ContractConfig,
     wallet_type: WalletType,
+    nonces: Arc<NonceManager>,
+    /// Relay address from the last proxy nonce sync, refreshed with the nonce
+    relay_address: Arc<Mutex<Option<Address>>>,
 }
 
 impl RelayClient {
@@ -280,6 +291,29 @@ impl RelayClient {
         self.derive_proxy_wallet(account.address())
     }
 
+    /// Address of the Safe or proxy wallet that relayed transactions execute from,
+    /// depending on the configured [`WalletType`]
+    pub fn wallet_address(&self) -> Result<Address, RelayError> {
+        match self.wallet_type {
+            WalletType::Proxy => self.get_expected_proxy_wallet(),
+            WalletType::Safe => self.get_expected_safe(),
+        }
+    }
+
+    /// Contract addresses and RPC endpoint for the configured chain
+    pub fn contract_config(&self) -> &ContractConfig {
+        &self.contract_config
+    }
+
+    fn rpc_provider(&self) -> Result<impl Provider, RelayError> {
+        let rpc_url = self
+            .contract_config
+            .rpc_url
+            .parse()
+            .map_err(|e| RelayError::Api(format!("Invalid RPC URL: {}", e)))?;
+        Ok(ProviderBuilder::new().connect_http(rpc_url))
+    }
+
     /// Get relay payload for PROXY wallets (returns relay address and nonce)
     pub async fn get_relay_payload(&self, address: Address) -> Result<(Address, u64), RelayError> {
         #[derive(serde::Deserialize)]
@@ -473,6 +507,88 @@ impl RelayClient {
         self.execute_with_gas(transactions, metadata, None).await
     }
 
+    /// Execute typed contract calls through the configured wallet.
+    ///
+    /// Equivalent to [`RelayClient::execute`] with each [`RelayCall`] converted
+    /// into a plain-call [`SafeTransaction`].
+    pub async fn execute_calls(
+        &self,
+        calls: Vec<RelayCall>,
+        metadata: Option<String>,
+    ) -> Result<RelayerTransactionResponse, RelayError> {
+        self.execute(calls.into_iter().map(Into::into).collect(), metadata)
+            .await
+    }
+
+    /// Dry-run contract calls via `eth_call` without submitting them to the relayer.
+    ///
+    /// The calls are simulated exactly as the wallet would execute them: for Safe
+    /// wallets the (multisend-aggregated) transaction runs in the Safe's context,
+    /// and for proxy wallets the encoded `proxy(...)` call is sent to the proxy
+    /// factory from the signer. Returns the raw return data of the outer call;
+    /// a revert is reported as [`RelayError::Api`].
+    pub async fn simulate(&self, calls: &[RelayCall]) -> Result<Bytes, RelayError> {
+        if calls.is_empty() {
+            return Err(RelayError::Api("No transactions to simulate".into()));
+        }
+        let account = self.account.as_ref().ok_or(RelayError::MissingSigner)?;
+        let from_address = account.address();
+        let transactions: Vec<SafeTransaction> = calls.iter().cloned().map(Into::into).collect();
+        let provider = self.rpc_provider()?;
+
+        let result = match self.wallet_type {
+            WalletType::Safe => {
+                let safe_address = self.derive_safe_address(from_address);
+                let aggregated = self.create_safe_multisend_transaction(&transactions);
+
+                if aggregated.operation == 0 {
+                    let tx = TransactionRequest::default()
+                        .with_from(safe_address)
+                        .with_to(aggregated.to)
+                        .with_value(aggregated.value)
+                        .with_input(aggregated.data);
+                    provider.call(tx).await
+                } else {
+                    // The Safe delegatecalls into MultiSend, so run the MultiSend
+                    // bytecode at the Safe's address to reproduce that context.
+                    let multisend_code = provider
+                        .get_code_at(aggregated.to)
+                        .await
+                        .map_err(|e| RelayError::Api(format!("Simulation failed: {}", e)))?;
+                    let overrides =
+                        StateOverridesBuilder::default().with_code(safe_address, multisend_code);
+                    let tx = TransactionRequest::default()
+                        .with_from(from_address)
+                        .with_to(safe_address)
+                        .with_input(aggregated.data);
+                    provider.call(tx).overrides(overrides).await
+                }
+            }
+            WalletType::Proxy => {
+                let proxy_factory = self
+                    .contract_config
+                    .proxy_factory
+                    .ok_or_else(|| RelayError::Api("Proxy factory not configured".to_string()))?;
+                let tx = TransactionRequest::default()
+                    .with_from(from_address)
+                    .with_to(proxy_factory)
+                    .with_input(self.encode_proxy_transaction_data(&transactions));
+                provider.call(tx).await
+            }
+        };
+
+        result.map_err(|e| RelayError::Api(format!("Simulation failed: {}", e)))
+    }
+
+    /// Execute transactions with an optional gas limit (used by proxy wallets).
+    ///
+    /// Nonces are managed locally: submissions from the same wallet are queued,
+    /// each one takes the next sequential nonce, and the nonce is resynced from
+    /// the relayer after any failure. A submission whose cached nonce the
+    /// relayer rejected ([`RelayError::NonceRejected`]) is retried once with the
+    /// resynced nonce; every other error is returned as is. Proxy wallets take
+    /// the relay address from the same resync. Clones of the client share the
+    /// same nonce state.
     pub async fn execute_with_gas(
         &self,
         transactions: Vec<SafeTransaction>,
@@ -482,16 +598,75 @@ impl RelayClient {
         if transactions.is_empty() {
             return Err(RelayError::Api("No transactions to execute".into()));
         }
+        let account = self.account.as_ref().ok_or(RelayError::MissingSigner)?;
+        let from_address = account.address();
+        let mut slot = self.nonces.lock(self.wallet_address()?).await;
+
+        loop {
+            let (nonce, resynced) = match *slot {
+                Some(nonce) => (nonce, false),
+                None => (self.fetch_nonce(from_address).await?, true),
+            };
+
+            let result = match self.wallet_type {
+                WalletType::Safe => {
+                    self.execute_safe(&transactions, metadata.clone(), nonce)
+                        .await
+                }
+                WalletType::Proxy => {
+                    self.execute_proxy(&transactions, metadata.clone(), gas_limit, nonce)
+                        .await
+                }
+            };
+
+            match result {
+                Ok(response) => {
+                    *slot = Some(nonce + 1);
+                    return Ok(response);
+                }
+                Err(e) => {
+                    *slot = None;
+                    // Only nonce rejections are retried: after a transport error or
+                    // a 5xx the submission may have landed, so resubmitting could
+                    // duplicate it, and other errors will not go away on a retry.
+                    if resynced || !matches!(e, RelayError::NonceRejected(_)) {
+                        return Err(e);
+                    }
+                    tracing::warn!(
+                        "Relayer rejected submission with nonce {}, resyncing: {}",
+                        nonce,
+                        e
+                    );
+                }
+            }
+        }
+    }
+
+    /// Forget the locally cached nonce for the configured wallet.
+    ///
+    /// Call this after submitting transactions for the same wallet from outside
+    /// this client; the next submission then resyncs from the relayer.
+    pub async fn reset_nonce(&self) -> Result<(), RelayError> {
+        self.nonces.reset(self.wallet_address()?).await;
+        Ok(())
+    }
+
+    async fn fetch_nonce(&self, from_address: Address) -> Result<u64, RelayError> {
         match self.wallet_type {
-            WalletType::Safe => self.execute_safe(transactions, metadata).await,
-            WalletType::Proxy => self.execute_proxy(transactions, metadata, gas_limit).await,
+            WalletType::Safe => self.get_nonce(from_address).await,
+            WalletType::Proxy => {
+                let (relay_address, nonce) = self.get_relay_payload(from_address).await?;
+                *self.relay_address.lock().unwrap_or_else(|e| e.into_inner()) = Some(relay_address);
+                Ok(nonce)
+            }
         }
     }
 
     async fn execute_safe(
         &self,
-        transactions: Vec<SafeTransaction>,
+        transactions: &[SafeTransaction],
         metadata: Option<String>,
+        nonce: u64,
     ) -> Result<RelayerTransactionResponse, RelayError> {
         let account = self.account.as_ref().ok_or(RelayError::MissingSigner)?;
         let from_address = account.address();
@@ -505,9 +680,7 @@ impl RelayClient {
             )));
         }
 
-        let nonce = self.get_nonce(from_address).await?;
-
-        let aggregated = self.create_safe_multisend_transaction(&transactions);
+        let aggregated = self.create_safe_multisend_transaction(transactions);
 
         let safe_tx = SafeTx {
             to: aggregated.to,
@@ -597,9 +770,10 @@ impl RelayClient {
 
     async fn execute_proxy(
         &self,
-        transactions: Vec<SafeTransaction>,
+        transactions: &[SafeTransaction],
         metadata: Option<String>,
         gas_limit: Option<u64>,
+        nonce: u64,
     ) -> Result<RelayerTransactionResponse, RelayError> {
         let account = self.account.as_ref().ok_or(RelayError::MissingSigner)?;
         let from_address = account.address();
@@ -614,11 +788,16 @@ impl RelayClient {
             .proxy_factory
             .ok_or_else(|| RelayError::Api("Proxy factory not configured".to_string()))?;
 
-        // Get relay payload (relay address + nonce)
-        let (relay_address, nonce) = self.get_relay_payload(from_address).await?;
+        // The relay address is cached by the last nonce sync, which runs again
+        // after any failed submission
+        let cached = *self.relay_address.lock().unwrap_or_else(|e| e.into_inner());
+        let relay_address = match cached {
+            Some(relay_address) => relay_address,
+            None => self.get_relay_payload(from_address).await?.0,
+        };
 
         // Encode all transactions into proxy calldata
-        let encoded_data = self.encode_proxy_transaction_data(&transactions);
+        let encoded_data = self.encode_proxy_transaction_data(transactions);
 
         // Constants for proxy transactions
         let tx_fee = U256::ZERO;
@@ -701,6 +880,141 @@ impl RelayClient {
         self._post_request("submit", &body).await
     }
 
+    /// On-chain balance reader for the configured chain and RPC endpoint
+    pub fn onchain(&self) -> Result<OnChain, RelayError> {
+        OnChain::from_config(self.contract_config.rpc_url, self.contract_config.clone())
+    }
+
+    /// USDC (collateral) balance of the configured wallet, in base units (6 decimals)
+    pub async fn usdc_balance(&self) -> Result<U256, RelayError> {
+        let wallet = self.wallet_address()?;
+        self.onchain()?.usdc_balance(wallet).await
+    }
+
+    /// Outcome token balance of the configured wallet for an ERC-1155 position ID
+    pub async fn position_balance(&self, token_id: U256) -> Result<U256, RelayError> {
+        let wallet = self.wallet_address()?;
+        self.onchain()?.token_balance(wallet, token_id).await
+    }
+
+    /// Withdraw USDC from the configured Safe or proxy wallet.
+    ///
+    /// `amount` is in USDC base units (6 decimals). The wallet balance is checked
+    /// over RPC first, and [`RelayError::InsufficientBalance`] is returned without
+    /// submitting anything if it is too low.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use polyoxide_relay::{RelayClient, BuilderAccount, BuilderConfig, WalletType};
+    /// use alloy::primitives::{address, U256};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = BuilderConfig::new("key".to_string(), "secret".to_string(), None);
+    /// let account = BuilderAccount::new("0x...", Some(config))?;
+    /// let client = RelayClient::builder()?
+    ///     .with_account(account)
+    ///     .wallet_type(WalletType::Proxy)
+    ///     .build()?;
+    ///
+    /// let to = address!("0000000000000000000000000000000000000001");
+    /// let response = client.withdraw_usdc(to, U256::from(5_000_000u64)).await?;
+    /// println!("Transaction ID: {}", response.transaction_id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn withdraw_usdc(
+        &self,
+        to: Address,
+        amount: U256,
+    ) -> Result<RelayerTransactionResponse, RelayError> {
+        let available = self.usdc_balance().await?;
+        ensure_balance(amount, available)?;
+
+        let call = RelayCall::erc20_transfer(self.contract_config.collateral, to, amount);
+        self.execute_calls(vec![call], None).await
+    }
+
+    /// Transfer outcome tokens out of the configured Safe or proxy wallet.
+    ///
+    /// `token_id` is the ERC-1155 position ID (the CLOB token ID) and `amount` is
+    /// in base units (6 decimals). The wallet balance is checked over RPC first,
+    /// and [`RelayError::InsufficientBalance`] is returned without submitting
+    /// anything if it is too low.
+    pub async fn transfer_position(
+        &self,
+        token_id: U256,
+        to: Address,
+        amount: U256,
+    ) -> Result<RelayerTransactionResponse, RelayError> {
+        let wallet = self.wallet_address()?;
+        let available = self.position_balance(token_id).await?;
+        ensure_balance(amount, available)?;
+
+        let call = RelayCall::erc1155_safe_transfer_from(
+            self.contract_config.conditional_tokens,
+            wallet,
+            to,
+            token_id,
+            amount,
+        );
+        self.execute_calls(vec![call], None).await
+    }
+
+    /// Redeem the wallet's outcome tokens of a resolved binary condition.
+    ///
+    /// Standard markets redeem both outcomes through the Conditional Tokens
+    /// contract. Negative-risk markets redeem through the neg-risk adapter,
+    /// which takes the balance of each outcome to redeem: pass those `amounts`
+    /// (base units, 6 decimals) for neg-risk markets and `None` otherwise.
+    pub async fn redeem_condition(
+        &self,
+        condition_id: B256,
+        neg_risk_amounts: Option<Vec<U256>>,
+    ) -> Result<RelayerTransactionResponse, RelayError> {
+        let config = &self.contract_config;
+        let call = match neg_risk_amounts {
+            Some(amounts) => {
+                RelayCall::neg_risk_redeem_positions(config.neg_risk_adapter, condition_id, amounts)
+            }
+            None => RelayCall::redeem_positions(
+                config.conditional_tokens,
+                config.collateral,
+                B256::ZERO,
+                condition_id,
+                BINARY_PARTITION.map(U256::from).to_vec(),
+            ),
+        };
+        self.execute_calls(vec![call], None).await
+    }
+
+    /// Merge `amount` full sets of a binary condition back into USDC.
+    ///
+    /// `amount` is in base units (6 decimals) and must not exceed the wallet's
+    /// balance of either outcome. Negative-risk markets merge through the
+    /// neg-risk adapter.
+    pub async fn merge_condition(
+        &self,
+        condition_id: B256,
+        amount: U256,
+        neg_risk: bool,
+    ) -> Result<RelayerTransactionResponse, RelayError> {
+        let config = &self.contract_config;
+        let call = if neg_risk {
+            RelayCall::neg_risk_merge_positions(config.neg_risk_adapter, condition_id, amount)
+        } else {
+            RelayCall::merge_positions(
+                config.conditional_tokens,
+                config.collateral,
+                B256::ZERO,
+                condition_id,
+                BINARY_PARTITION.map(U256::from).to_vec(),
+                amount,
+            )
+        };
+        self.execute_calls(vec![call], None).await
+    }
+
     /// Estimate gas required for a redemption transaction.
     ///
     /// Returns the estimated gas limit with relayer overhead and safety buffer included.
@@ -767,18 +1081,10 @@ impl RelayClient {
         let redemption_calldata = Bytes::from(call.abi_encode());
 
         // 4. Get the proxy wallet address
-        let proxy_wallet = match self.wallet_type {
-            WalletType::Proxy => self.get_expected_proxy_wallet()?,
-            WalletType::Safe => self.get_expected_safe()?,
-        };
+        let proxy_wallet = self.wallet_address()?;
 
         // 5. Create provider using the configured RPC URL
-        let provider = ProviderBuilder::new().connect_http(
-            self.contract_config
-                .rpc_url
-                .parse()
-                .map_err(|e| RelayError::Api(format!("Invalid RPC URL: {}", e)))?,
-        );
+        let provider = self.rpc_provider()?;
 
         // 6. Construct a mock transaction exactly as the proxy will execute it
         let tx = TransactionRequest::default()
@@ -935,7 +1241,7 @@ impl RelayClient {
                     status,
                     polyoxide_core::truncate_for_log(&text)
                 );
-                return Err(RelayError::Api(format!("Request failed: {}", text)));
+                return Err(submission_error(status, &text));
             }
 
             let response_text = resp.text().await?;
@@ -954,6 +1260,16 @@ impl RelayClient {
     }
 }
 
+fn ensure_balance(required: U256, available: U256) -> Result<(), RelayError> {
+    if available < required {
+        return Err(RelayError::InsufficientBalance {
+            required,
+            available,
+        });
+    }
+    Ok(())
+}
+
 pub struct RelayClientBuilder {
     base_url: String,
     chain_id: u64,
@@ -1047,10 +1363,36 @@ impl RelayClientBuilder {
             account: self.account,
             contract_config,
             wallet_type: self.wallet_type,
+            nonces: Arc::new(NonceManager::default()),
+            relay_address: Arc::new(Mutex::new(None)),
         })
     }
 }
 
+/// Error message the relayer returns for a submission with a stale nonce
+const NONCE_REJECTED_ERROR: &str = "invalid nonce";
+
+/// Error for a failed relayer submission.
+///
+/// A 400 whose JSON body is `{"error": "invalid nonce"}` is a
+/// [`RelayError::NonceRejected`]; anything else, including 5xx responses after
+/// which the submission may have been accepted, is a plain [`RelayError::Api`].
+fn submission_error(status: reqwest::StatusCode, body: &str) -> RelayError {
+    #[derive(serde::Deserialize)]
+    struct ErrorBody {
+        error: String,
+    }
+
+    let nonce_rejected = status == reqwest::StatusCode::BAD_REQUEST
+        && serde_json::from_str::<ErrorBody>(body)
+            .is_ok_and(|body| body.error.eq_ignore_ascii_case(NONCE_REJECTED_ERROR));
+    if nonce_rejected {
+        RelayError::NonceRejected(body.to_string())
+    } else {
+        RelayError::Api(format!("Request failed: {}", body))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1211,6 +1553,136 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_wallet_address_follows_wallet_type() {
+        let account = crate::BuilderAccount::new(TEST_KEY, None).unwrap();
+        let proxy_client = RelayClient::builder()
+            .unwrap()
+            .with_account(account)
+            .wallet_type(WalletType::Proxy)
+            .build()
+            .unwrap();
+        assert_eq!(
+            proxy_client.wallet_address().unwrap(),
+            proxy_client.get_expected_proxy_wallet().unwrap()
+        );
+
+        let safe_client = test_client_with_account();
+        assert_eq!(
+            safe_client.wallet_address().unwrap(),
+            safe_client.get_expected_safe().unwrap()
+        );
+    }
+
+    // ── nonce management ────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_clones_share_nonce_state() {
+        let client = test_client_with_account();
+        let clone = client.clone();
+        let wallet = client.wallet_address().unwrap();
+
+        *client.nonces.lock(wallet).await = Some(5);
+        assert_eq!(*clone.nonces.lock(wallet).await, Some(5));
+
+        *client.relay_address.lock().unwrap() = Some(Address::repeat_byte(0x11));
+        assert_eq!(
+            *clone.relay_address.lock().unwrap(),
+            Some(Address::repeat_byte(0x11))
+        );
+
+        clone.reset_nonce().await.unwrap();
+        assert_eq!(*client.nonces.lock(wallet).await, None);
+    }
+
+    #[tokio::test]
+    async fn test_reset_nonce_requires_signer() {
+        let client = RelayClient::builder().unwrap().build().unwrap();
+        let err = client.reset_nonce().await.unwrap_err();
+        assert!(matches!(err, RelayError::MissingSigner), "got: {err:?}");
+    }
+
+    #[test]
+    fn test_submission_error_only_flags_nonce_rejections() {
+        use reqwest::StatusCode;
+
+        let err = submission_error(StatusCode::BAD_REQUEST, r#"{"error":"invalid nonce"}"#);
+        assert!(matches!(err, RelayError::NonceRejected(_)), "got: {err:?}");
+
+        // The submission may have landed on a 5xx, so it must not look retryable
+        let err = submission_error(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            r#"{"error":"invalid nonce"}"#,
+        );
+        assert!(matches!(err, RelayError::Api(_)), "got: {err:?}");
+
+        // Other errors that merely mention the nonce are not rejections
+        let err = submission_error(
+            StatusCode::BAD_REQUEST,
+            r#"{"error":"nonce field is required"}"#,
+        );
+        assert!(matches!(err, RelayError::Api(_)), "got: {err:?}");
+
+        let err = submission_error(StatusCode::BAD_REQUEST, "invalid nonce");
+        assert!(matches!(err, RelayError::Api(_)), "got: {err:?}");
+
+        let err = submission_error(StatusCode::BAD_REQUEST, r#"{"error":"invalid signature"}"#);
+        assert!(matches!(err, RelayError::Api(_)), "got: {err:?}");
+    }
+
+    // ── simulate ────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_simulate_rejects_empty_calls() {
+        let client = test_client_with_account();
+        let err = client.simulate(&[]).await.unwrap_err();
+        assert!(
+            format!("{err}").contains("No transactions to simulate"),
+            "unexpected: {err}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_simulate_requires_signer() {
+        let client = RelayClient::builder().unwrap().build().unwrap();
+        let call = RelayCall::new(Address::ZERO, Bytes::new());
+        let err = client.simulate(&[call]).await.unwrap_err();
+        assert!(matches!(err, RelayError::MissingSigner), "got: {err:?}");
+    }
+
+    // ── balance pre-checks ──────────────────────────────────────
+
+    #[test]
+    fn test_ensure_balance_sufficient() {
+        assert!(ensure_balance(U256::from(10), U256::from(10)).is_ok());
+        assert!(ensure_balance(U256::ZERO, U256::ZERO).is_ok());
+    }
+
+    #[test]
+    fn test_ensure_balance_insufficient() {
+        let err = ensure_balance(U256::from(11), U256::from(10)).unwrap_err();
+        match err {
+            RelayError::InsufficientBalance {
+                required,
+                available,
+            } => {
+                assert_eq!(required, U256::from(11));
+                assert_eq!(available, U256::from(10));
+            }
+            other => panic!("Expected InsufficientBalance, got: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_withdraw_usdc_requires_signer() {
+        let client = RelayClient::builder().unwrap().build().unwrap();
+        let err = client
+            .withdraw_usdc(Address::ZERO, U256::from(1))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, RelayError::MissingSigner), "got: {err:?}");
+    }
+
     // ── signature packing ───────────────────────────────────────
 
     #[test]
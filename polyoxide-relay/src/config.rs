@@ -8,9 +8,22 @@ pub struct ContractConfig {
     pub safe_multisend: Address,
     pub proxy_factory: Option<Address>,
     pub relay_hub: Option<Address>,
+    pub collateral: Address,
+    pub conditional_tokens: Address,
+    pub exchange: Address,
+    pub neg_risk_exchange: Address,
+    pub neg_risk_adapter: Address,
     pub rpc_url: &'static str,
 }
 
+impl ContractConfig {
+    /// Contracts that trade on a wallet's behalf and need USDC allowances
+    /// and outcome token approvals
+    pub fn spenders(&self) -> [Address; 3] {
+        [self.exchange, self.neg_risk_exchange, self.neg_risk_adapter]
+    }
+}
+
 pub fn get_contract_config(chain_id: u64) -> Option<ContractConfig> {
     match chain_id {
         137 => Some(ContractConfig {
@@ -18,6 +31,11 @@ pub fn get_contract_config(chain_id: u64) -> Option<ContractConfig> {
             safe_multisend: address!("A238CBeb142c10Ef7Ad8442C6D1f9E89e07e7761"),
             proxy_factory: Some(address!("aB45c5A4B0c941a2F231C04C3f49182e1A254052")),
             relay_hub: Some(address!("D216153c06E857cD7f72665E0aF1d7D82172F494")),
+            collateral: address!("2791Bca1f2de4661ED88A30C99A7a9449Aa84174"),
+            conditional_tokens: address!("4D97DCd97eC945f40cF65F87097ACe5EA0476045"),
+            exchange: address!("4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E"),
+            neg_risk_exchange: address!("C5d563A36AE78145C45a50134d48A1215220f80a"),
+            neg_risk_adapter: address!("d91E80cF2E7be2e162c6513ceD06f1dD0dA35296"),
             rpc_url: "https://polygon.drpc.org",
         }),
         80002 => Some(ContractConfig {
@@ -25,6 +43,11 @@ pub fn get_contract_config(chain_id: u64) -> Option<ContractConfig> {
             safe_multisend: address!("A238CBeb142c10Ef7Ad8442C6D1f9E89e07e7761"),
             proxy_factory: None, // Proxy not supported on Amoy testnet
             relay_hub: None,
+            collateral: address!("9c4e1703476e875070ee25b56a58b008cfb8fa78"),
+            conditional_tokens: address!("69308FB512518e39F9b16112fA8d994F4e2Bf8bB"),
+            exchange: address!("dFE02Eb6733538f8Ea35D585af8DE5958AD99E40"),
+            neg_risk_exchange: address!("d91E80cF2E7be2e162c6513ceD06f1dD0dA35296"),
+            neg_risk_adapter: address!("d0D0E471E88e0A8E7C304F2df3A0Cc7400fe4635"),
             rpc_url: "https://rpc-amoy.polygon.technology",
         }),
         _ => None,
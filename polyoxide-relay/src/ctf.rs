@@ -0,0 +1,350 @@
+//! Local derivation of Conditional Tokens Framework (CTF) identifiers.
+//!
+//! Mirrors `CTHelpers` from the Gnosis conditional-tokens contracts, so
+//! question, condition, collection and ERC-1155 position IDs can be computed
+//! without an RPC round trip. For a standard binary Polymarket market, the
+//! CLOB token IDs are:
+//!
+//! ```
+//! use polyoxide_relay::ctf;
+//! use alloy::primitives::{address, B256};
+//!
+//! let usdc = address!("2791Bca1f2de4661ED88A30C99A7a9449Aa84174");
+//! let condition_id = B256::repeat_byte(0x42);
+//!
+//! // index set 1 (outcome 0, "Yes") and index set 2 (outcome 1, "No")
+//! let token_ids = ctf::outcome_position_ids(usdc, condition_id, 2);
+//! assert_eq!(token_ids.len(), 2);
+//! ```
+
+use crate::error::RelayError;
+use alloy::primitives::{keccak256, uint, Address, B256, U256};
+
+/// Field modulus of the alt_bn128 curve `y^2 = x^3 + 3`
+const P: U256 =
+    uint!(21888242871839275222246405745257275088696311157297823662689037894645226208583_U256);
+const B: U256 = uint!(3_U256);
+
+/// Question ID assigned by the UMA CTF adapter.
+///
+/// The adapter appends `,initializer:<address>` (lowercase hex, no `0x`) to the
+/// ancillary data and hashes the result.
+pub fn question_id(ancillary_data: &[u8], initializer: Address) -> B256 {
+    let mut data = ancillary_data.to_vec();
+    data.extend_from_slice(b",initializer:");
+    data.extend_from_slice(alloy::hex::encode(initializer).as_bytes());
+    keccak256(data)
+}
+
+/// Question ID of the `index`-th question in a neg-risk market.
+pub fn neg_risk_question_id(market_id: B256, index: u8) -> B256 {
+    B256::from(U256::from_be_bytes(market_id.0) + U256::from(index))
+}
+
+/// `getConditionId(oracle, questionId, outcomeSlotCount)`
+pub fn condition_id(oracle: Address, question_id: B256, outcome_slot_count: u32) -> B256 {
+    let mut data = Vec::with_capacity(20 + 32 + 32);
+    data.extend_from_slice(oracle.as_slice());
+    data.extend_from_slice(question_id.as_slice());
+    data.extend_from_slice(&U256::from(outcome_slot_count).to_be_bytes::<32>());
+    keccak256(data)
+}
+
+/// `getCollectionId(parentCollectionId, conditionId, indexSet)`
+///
+/// Collection IDs are compressed alt_bn128 points, so nesting a collection
+/// under a non-zero parent adds the two points on the curve.
+///
+/// Fails if `parent_collection_id` is not a valid compressed point.
+pub fn collection_id(
+    parent_collection_id: B256,
+    condition_id: B256,
+    index_set: U256,
+) -> Result<B256, RelayError> {
+    let mut data = Vec::with_capacity(64);
+    data.extend_from_slice(condition_id.as_slice());
+    data.extend_from_slice(&index_set.to_be_bytes::<32>());
+    let hash = U256::from_be_bytes(keccak256(data).0);
+
+    // Hash onto the curve: increment x until x^3 + 3 is a quadratic residue
+    let odd = hash.bit(255);
+    let mut x1 = hash;
+    let mut y1;
+    loop {
+        x1 = x1.add_mod(U256::from(1), P);
+        let yy = curve_rhs(x1);
+        y1 = sqrt(yy);
+        if y1.mul_mod(y1, P) == yy {
+            break;
+        }
+    }
+    if odd != y1.bit(0) {
+        y1 = P - y1;
+    }
+
+    let mut point = Some((x1, y1));
+    let parent = U256::from_be_bytes(parent_collection_id.0);
+    if !parent.is_zero() {
+        let parent_point = decompress(parent).ok_or_else(|| {
+            RelayError::Api(format!(
+                "Invalid parent collection ID: {}",
+                parent_collection_id
+            ))
+        })?;
+        point = ec_add(point, Some(parent_point));
+    }
+
+    Ok(match point {
+        Some((x, y)) => B256::from(compress(x, y)),
+        // The precompile returns (0, 0) for the point at infinity
+        None => B256::ZERO,
+    })
+}
+
+/// `getPositionId(collateralToken, collectionId)`, the ERC-1155 token ID
+pub fn position_id(collateral: Address, collection_id: B256) -> U256 {
+    let mut data = Vec::with_capacity(20 + 32);
+    data.extend_from_slice(collateral.as_slice());
+    data.extend_from_slice(collection_id.as_slice());
+    U256::from_be_bytes(keccak256(data).0)
+}
+
+/// Position IDs for each single outcome of a top-level condition.
+///
+/// Entry `i` uses index set `1 << i`, matching the order of a market's
+/// `outcomes` and `clob_token_ids`.
+pub fn outcome_position_ids(
+    collateral: Address,
+    condition_id: B256,
+    outcome_slot_count: u32,
+) -> Vec<U256> {
+    (0..outcome_slot_count.min(256))
+        .map(|i| {
+            let collection = collection_id(B256::ZERO, condition_id, U256::from(1) << i)
+                .expect("zero parent collection is always valid");
+            position_id(collateral, collection)
+        })
+        .collect()
+}
+
+fn curve_rhs(x: U256) -> U256 {
+    x.mul_mod(x.mul_mod(x, P), P).add_mod(B, P)
+}
+
+/// Square root modulo `P` (valid because `P % 4 == 3`); callers must check the result
+fn sqrt(a: U256) -> U256 {
+    a.pow_mod((P + U256::from(1)) >> 2, P)
+}
+
+fn compress(x: U256, y: U256) -> U256 {
+    if y.bit(0) {
+        x ^ (U256::from(1) << 254)
+    } else {
+        x
+    }
+}
+
+fn decompress(compressed: U256) -> Option<(U256, U256)> {
+    let odd = compressed.bit(254);
+    let x = (compressed << 2) >> 2;
+    let yy = curve_rhs(x);
+    let mut y = sqrt(yy);
+    if odd != y.bit(0) {
+        y = P - y;
+    }
+    (y.mul_mod(y, P) == yy).then_some((x, y))
+}
+
+/// Affine point addition on alt_bn128, with `None` as the point at infinity
+fn ec_add(a: Option<(U256, U256)>, b: Option<(U256, U256)>) -> Option<(U256, U256)> {
+    let ((x1, y1), (x2, y2)) = match (a, b) {
+        (None, other) | (other, None) => return other,
+        (Some(a), Some(b)) => (a, b),
+    };
+
+    let lambda = if x1 == x2 {
+        if y1.add_mod(y2, P).is_zero() {
+            return None;
+        }
+        // Tangent: 3x^2 / 2y
+        let num = U256::from(3).mul_mod(x1.mul_mod(x1, P), P);
+        let den = U256::from(2).mul_mod(y1, P);
+        num.mul_mod(den.inv_mod(P)?, P)
+    } else {
+        let num = y2.add_mod(P - y1, P);
+        let den = x2.add_mod(P - x1, P);
+        num.mul_mod(den.inv_mod(P)?, P)
+    };
+
+    let x3 = lambda
+        .mul_mod(lambda, P)
+        .add_mod(P - x1, P)
+        .add_mod(P - x2, P);
+    let y3 = lambda.mul_mod(x1.add_mod(P - x3, P), P).add_mod(P - y1, P);
+    Some((x3, y3))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::{address, b256};
+
+    const USDC: Address = address!("2791Bca1f2de4661ED88A30C99A7a9449Aa84174");
+
+    // EIP-196 test vectors: multiples of the generator G = (1, 2)
+    const G: (U256, U256) = (uint!(1_U256), uint!(2_U256));
+    const G2: (U256, U256) = (
+        uint!(0x030644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd3_U256),
+        uint!(0x15ed738c0e0a7c92e7845f96b2ae9c0a68a6a449e3538fc7ff3ebf7a5a18a2c4_U256),
+    );
+    const G3: (U256, U256) = (
+        uint!(0x0769bf9ac56bea3ff40232bcb1b6bd159315d84715b8e679f2d355961915abf0_U256),
+        uint!(0x2ab799bee0489429554fdb7c8d086475319e63b40b9c5b57cdf1ff3dd9fe2261_U256),
+    );
+
+    fn on_curve((x, y): (U256, U256)) -> bool {
+        y.mul_mod(y, P) == curve_rhs(x)
+    }
+
+    // ── curve arithmetic ────────────────────────────────────────
+
+    #[test]
+    fn test_ec_add_doubling() {
+        assert_eq!(ec_add(Some(G), Some(G)), Some(G2));
+    }
+
+    #[test]
+    fn test_ec_add_distinct_points() {
+        assert_eq!(ec_add(Some(G), Some(G2)), Some(G3));
+        assert_eq!(ec_add(Some(G2), Some(G)), Some(G3));
+    }
+
+    #[test]
+    fn test_ec_add_inverse_is_infinity() {
+        let neg_g = (G.0, P - G.1);
+        assert_eq!(ec_add(Some(G), Some(neg_g)), None);
+        assert_eq!(ec_add(None, Some(G)), Some(G));
+    }
+
+    #[test]
+    fn test_compress_round_trip() {
+        for point in [G, G2, G3] {
+            assert_eq!(decompress(compress(point.0, point.1)), Some(point));
+        }
+    }
+
+    #[test]
+    fn test_decompress_rejects_off_curve_x() {
+        // x = 0 gives y^2 = 3, which is not a quadratic residue mod P
+        assert_eq!(decompress(U256::ZERO), None);
+    }
+
+    // ── CTHelpers ───────────────────────────────────────────────
+
+    #[test]
+    fn test_condition_id_packing() {
+        let oracle = address!("6A9D222616C90FcA5754cd1333cFD9b7fb6a4F74");
+        let question = B256::repeat_byte(0x01);
+        let mut packed = oracle.to_vec();
+        packed.extend_from_slice(question.as_slice());
+        packed.extend_from_slice(&U256::from(2).to_be_bytes::<32>());
+        assert_eq!(condition_id(oracle, question, 2), keccak256(packed));
+        assert_ne!(
+            condition_id(oracle, question, 2),
+            condition_id(oracle, question, 3)
+        );
+    }
+
+    #[test]
+    fn test_question_id_appends_initializer() {
+        let initializer = address!("91430CaD2d3975766499717fA0D66A78D814E5c5");
+        let expected = keccak256(
+            b"q: title: Test,initializer:91430cad2d3975766499717fa0d66a78d814e5c5".as_slice(),
+        );
+        assert_eq!(question_id(b"q: title: Test", initializer), expected);
+    }
+
+    #[test]
+    fn test_neg_risk_question_id_sets_index() {
+        let market_id = b256!("ee7f1a8bc0fb1ac8e3c6e0e9f6c07a55e3b2a1d4c5b6a7980123456789abcd00");
+        let question = neg_risk_question_id(market_id, 5);
+        assert_eq!(question[..31], market_id[..31]);
+        assert_eq!(question[31], 0x05);
+    }
+
+    #[test]
+    fn test_top_level_collection_id_is_valid_point() {
+        let condition = B256::repeat_byte(0x42);
+        for index_set in [1u64, 2, 3] {
+            let collection = collection_id(B256::ZERO, condition, U256::from(index_set)).unwrap();
+            let point = decompress(U256::from_be_bytes(collection.0)).unwrap();
+            assert!(on_curve(point));
+        }
+    }
+
+    #[test]
+    fn test_nested_collection_ids_commute() {
+        // Positions conditioned on A then B equal positions conditioned on B then A
+        let condition_a = B256::repeat_byte(0xaa);
+        let condition_b = B256::repeat_byte(0xbb);
+        let a = collection_id(B256::ZERO, condition_a, U256::from(1)).unwrap();
+        let b = collection_id(B256::ZERO, condition_b, U256::from(2)).unwrap();
+
+        let a_then_b = collection_id(a, condition_b, U256::from(2)).unwrap();
+        let b_then_a = collection_id(b, condition_a, U256::from(1)).unwrap();
+        assert_eq!(a_then_b, b_then_a);
+        assert_ne!(a_then_b, a);
+    }
+
+    #[test]
+    fn test_invalid_parent_collection_id() {
+        // Parity bit set with x = 0, which is not on the curve
+        let parent = B256::from(U256::from(1) << 254);
+        let err = collection_id(parent, B256::ZERO, U256::from(1)).unwrap_err();
+        assert!(format!("{err}").contains("Invalid parent collection ID"));
+    }
+
+    #[test]
+    fn test_position_id_packing() {
+        let collection = B256::repeat_byte(0x07);
+        let mut packed = USDC.to_vec();
+        packed.extend_from_slice(collection.as_slice());
+        assert_eq!(
+            position_id(USDC, collection),
+            U256::from_be_bytes(keccak256(packed).0)
+        );
+    }
+
+    #[test]
+    fn test_outcome_position_ids_match_live_market() {
+        // "Presidential Election Winner 2024: Donald Trump", a neg-risk market
+        // whose outcome tokens are collateralised by the neg-risk adapter's
+        // wrapped USDC; token IDs as listed in Gamma's `clobTokenIds`
+        let wrapped_collateral = address!("3A3BD7bb9528E159577F7C2e685CC81A765002E2");
+        let condition = b256!("dd22472e552920b8438158ea7238bfadfa4f736aa4cee91a6b86c39ead110917");
+
+        let ids = outcome_position_ids(wrapped_collateral, condition, 2);
+        assert_eq!(
+            ids,
+            vec![
+                uint!(
+                    21742633143463906290569050155826241533067272736897614950488156847949938836455_U256
+                ),
+                uint!(
+                    48331043336612883890938759509493159234755048973500640148014422747788308965732_U256
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_outcome_position_ids_binary() {
+        let condition = B256::repeat_byte(0x42);
+        let ids = outcome_position_ids(USDC, condition, 2);
+        assert_eq!(ids.len(), 2);
+        assert_ne!(ids[0], ids[1]);
+
+        let yes = collection_id(B256::ZERO, condition, U256::from(1)).unwrap();
+        assert_eq!(ids[0], position_id(USDC, yes));
+    }
+}
@@ -17,12 +17,23 @@ pub enum RelayError {
     #[error("Relayer API error: {0}")]
     Api(String),
 
+    /// The relayer rejected the submission's nonce; resubmitting with a
+    /// resynced nonce is safe because nothing was accepted
+    #[error("Nonce rejected by relayer: {0}")]
+    NonceRejected(String),
+
     #[error("Rate limit exceeded")]
     RateLimit,
 
     #[error("Missing signer")]
     MissingSigner,
 
+    #[error("Insufficient balance: required {required}, available {available}")]
+    InsufficientBalance {
+        required: alloy::primitives::U256,
+        available: alloy::primitives::U256,
+    },
+
     #[error("Core API error: {0}")]
     Core(#[from] polyoxide_core::ApiError),
 }
@@ -43,6 +54,12 @@ mod tests {
         assert_eq!(format!("{err}"), "Relayer API error: server returned 500");
     }
 
+    #[test]
+    fn test_nonce_rejected_display() {
+        let err = RelayError::NonceRejected("invalid nonce".into());
+        assert_eq!(format!("{err}"), "Nonce rejected by relayer: invalid nonce");
+    }
+
     #[test]
     fn test_rate_limit_display() {
         let err = RelayError::RateLimit;
@@ -55,6 +72,18 @@ mod tests {
         assert_eq!(format!("{err}"), "Missing signer");
     }
 
+    #[test]
+    fn test_insufficient_balance_display() {
+        let err = RelayError::InsufficientBalance {
+            required: alloy::primitives::U256::from(100),
+            available: alloy::primitives::U256::from(42),
+        };
+        assert_eq!(
+            format!("{err}"),
+            "Insufficient balance: required 100, available 42"
+        );
+    }
+
     #[test]
     fn test_from_url_parse_error() {
         let url_err: url::ParseError = url::Url::parse("://bad").unwrap_err();
@@ -1,12 +1,20 @@
+mod calls;
 mod client;
 mod config;
+pub mod ctf;
 mod error;
+mod nonce;
+mod onchain;
 mod types;
 
+pub use calls::RelayCall;
 pub use client::RelayClient;
 pub use config::{BuilderConfig, ContractConfig};
 pub use error::RelayError;
-pub use types::{SafeTransaction, SafeTx, TransactionRequest, WalletType};
+pub use onchain::{OnChain, OnChainBalances};
+pub use types::{
+    RelayerTransactionResponse, SafeTransaction, SafeTx, TransactionRequest, WalletType,
+};
 
 mod account;
 
@@ -0,0 +1,121 @@
+use alloy::primitives::Address;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio::sync::{Mutex as AsyncMutex, OwnedMutexGuard};
+
+/// Next nonce to use for a wallet, or `None` when it must be resynced from the relayer.
+pub(crate) type NonceSlot = OwnedMutexGuard<Option<u64>>;
+
+/// Hands out sequential relayer nonces per wallet.
+///
+/// Each wallet gets its own async lock, so submissions from the same wallet are
+/// queued behind each other while different wallets proceed in parallel. The
+/// manager is shared between clones of a `RelayClient`.
+#[derive(Default)]
+pub(crate) struct NonceManager {
+    wallets: Mutex<HashMap<Address, Arc<AsyncMutex<Option<u64>>>>>,
+}
+
+impl std::fmt::Debug for NonceManager {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let wallets = self.wallets.lock().map(|w| w.len()).unwrap_or_default();
+        f.debug_struct("NonceManager")
+            .field("wallets", &wallets)
+            .finish()
+    }
+}
+
+impl NonceManager {
+    /// Wait for exclusive access to a wallet's nonce slot.
+    ///
+    /// The slot stays locked until the returned guard is dropped, which queues
+    /// any other submission for the same wallet.
+    pub(crate) async fn lock(&self, wallet: Address) -> NonceSlot {
+        let slot = {
+            let mut wallets = self.wallets.lock().unwrap_or_else(|e| e.into_inner());
+            wallets.entry(wallet).or_default().clone()
+        };
+        slot.lock_owned().await
+    }
+
+    /// Forget the cached nonce for a wallet so the next submission resyncs.
+    pub(crate) async fn reset(&self, wallet: Address) {
+        *self.lock(wallet).await = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    const WALLET_A: Address = Address::repeat_byte(0xaa);
+    const WALLET_B: Address = Address::repeat_byte(0xbb);
+
+    #[tokio::test]
+    async fn test_new_wallet_starts_unsynced() {
+        let manager = NonceManager::default();
+        assert_eq!(*manager.lock(WALLET_A).await, None);
+    }
+
+    #[tokio::test]
+    async fn test_slot_persists_between_locks() {
+        let manager = NonceManager::default();
+        *manager.lock(WALLET_A).await = Some(7);
+        assert_eq!(*manager.lock(WALLET_A).await, Some(7));
+        assert_eq!(*manager.lock(WALLET_B).await, None);
+    }
+
+    #[tokio::test]
+    async fn test_reset_clears_slot() {
+        let manager = NonceManager::default();
+        *manager.lock(WALLET_A).await = Some(3);
+        manager.reset(WALLET_A).await;
+        assert_eq!(*manager.lock(WALLET_A).await, None);
+    }
+
+    #[tokio::test]
+    async fn test_same_wallet_is_queued() {
+        let manager = Arc::new(NonceManager::default());
+        let guard = manager.lock(WALLET_A).await;
+
+        let waiter = {
+            let manager = manager.clone();
+            tokio::spawn(async move { *manager.lock(WALLET_A).await })
+        };
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(!waiter.is_finished(), "second lock should wait");
+
+        drop(guard);
+        assert_eq!(waiter.await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_different_wallets_do_not_block() {
+        let manager = NonceManager::default();
+        let _a = manager.lock(WALLET_A).await;
+        let b = tokio::time::timeout(Duration::from_millis(100), manager.lock(WALLET_B)).await;
+        assert!(b.is_ok(), "other wallets should not be blocked");
+    }
+
+    #[tokio::test]
+    async fn test_sequential_handout() {
+        let manager = Arc::new(NonceManager::default());
+        let mut handles = Vec::new();
+        for _ in 0..10 {
+            let manager = manager.clone();
+            handles.push(tokio::spawn(async move {
+                let mut slot = manager.lock(WALLET_A).await;
+                let nonce = slot.unwrap_or(0);
+                *slot = Some(nonce + 1);
+                nonce
+            }));
+        }
+        let mut nonces = Vec::new();
+        for handle in handles {
+            nonces.push(handle.await.unwrap());
+        }
+        nonces.sort_unstable();
+        assert_eq!(nonces, (0..10).collect::<Vec<_>>());
+    }
+}
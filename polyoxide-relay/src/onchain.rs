@@ -0,0 +1,282 @@
+use crate::calls::{IERC1155, IERC20};
+use crate::config::{get_contract_config, ContractConfig};
+use crate::error::RelayError;
+use alloy::network::TransactionBuilder;
+use alloy::primitives::{Address, Bytes, U256};
+use alloy::providers::{DynProvider, Provider, ProviderBuilder};
+use alloy::rpc::types::TransactionRequest;
+use alloy::sol_types::SolCall;
+use std::collections::HashMap;
+
+/// Read-only view of Polymarket balances and approvals straight from the chain.
+///
+/// Queries the collateral (USDC) and Conditional Tokens contracts over JSON-RPC,
+/// independently of the CLOB and Data APIs, so their figures can be reconciled
+/// against on-chain state. All amounts are raw base units (6 decimals).
+///
+/// # Example
+///
+/// ```no_run
+/// use polyoxide_relay::OnChain;
+/// use alloy::primitives::{address, U256};
+///
+/// # async fn example() -> Result<(), polyoxide_relay::RelayError> {
+/// let onchain = OnChain::new(137)?;
+/// let wallet = address!("0000000000000000000000000000000000000001");
+///
+/// let usdc = onchain.usdc_balance(wallet).await?;
+/// let tokens = onchain.token_balances(wallet, &[U256::from(1), U256::from(2)]).await?;
+/// println!("USDC: {usdc}, tokens: {tokens:?}");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct OnChain {
+    provider: DynProvider,
+    contract_config: ContractConfig,
+}
+
+/// Balances and approvals of one address, as reported by [`OnChain::snapshot`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OnChainBalances {
+    /// Address the snapshot was taken for
+    pub owner: Address,
+    /// USDC balance
+    pub usdc: U256,
+    /// USDC allowance granted to each spender
+    pub allowances: HashMap<Address, U256>,
+    /// Whether each spender is approved to move the owner's outcome tokens
+    pub approvals: HashMap<Address, bool>,
+    /// Outcome token balance for each requested token ID
+    pub tokens: HashMap<U256, U256>,
+}
+
+impl OnChain {
+    /// Create a reader for a supported chain using its default RPC endpoint
+    pub fn new(chain_id: u64) -> Result<Self, RelayError> {
+        let contract_config = get_contract_config(chain_id)
+            .ok_or_else(|| RelayError::Api(format!("Unsupported chain ID: {}", chain_id)))?;
+        Self::from_config(contract_config.rpc_url, contract_config)
+    }
+
+    /// Create a reader for a supported chain using a custom RPC endpoint
+    pub fn with_rpc_url(chain_id: u64, rpc_url: &str) -> Result<Self, RelayError> {
+        let contract_config = get_contract_config(chain_id)
+            .ok_or_else(|| RelayError::Api(format!("Unsupported chain ID: {}", chain_id)))?;
+        Self::from_config(rpc_url, contract_config)
+    }
+
+    pub(crate) fn from_config(
+        rpc_url: &str,
+        contract_config: ContractConfig,
+    ) -> Result<Self, RelayError> {
+        let rpc_url = rpc_url
+            .parse()
+            .map_err(|e| RelayError::Api(format!("Invalid RPC URL: {}", e)))?;
+        let provider = ProviderBuilder::new().connect_http(rpc_url).erased();
+        Ok(Self {
+            provider,
+            contract_config,
+        })
+    }
+
+    /// Contract addresses queried by this reader
+    pub fn contract_config(&self) -> &ContractConfig {
+        &self.contract_config
+    }
+
+    /// USDC (collateral) balance of `owner`
+    pub async fn usdc_balance(&self, owner: Address) -> Result<U256, RelayError> {
+        let data = self
+            .call(
+                self.contract_config.collateral,
+                IERC20::balanceOfCall { account: owner },
+            )
+            .await?;
+        decode::<IERC20::balanceOfCall>(&data)
+    }
+
+    /// USDC allowance `owner` has granted to `spender`
+    pub async fn usdc_allowance(
+        &self,
+        owner: Address,
+        spender: Address,
+    ) -> Result<U256, RelayError> {
+        let data = self
+            .call(
+                self.contract_config.collateral,
+                IERC20::allowanceCall { owner, spender },
+            )
+            .await?;
+        decode::<IERC20::allowanceCall>(&data)
+    }
+
+    /// USDC allowances `owner` has granted to each exchange spender
+    pub async fn usdc_allowances(
+        &self,
+        owner: Address,
+    ) -> Result<HashMap<Address, U256>, RelayError> {
+        let mut allowances = HashMap::new();
+        for spender in self.contract_config.spenders() {
+            allowances.insert(spender, self.usdc_allowance(owner, spender).await?);
+        }
+        Ok(allowances)
+    }
+
+    /// Whether each exchange spender may move `owner`'s outcome tokens
+    pub async fn token_approvals(
+        &self,
+        owner: Address,
+    ) -> Result<HashMap<Address, bool>, RelayError> {
+        let mut approvals = HashMap::new();
+        for operator in self.contract_config.spenders() {
+            let data = self
+                .call(
+                    self.contract_config.conditional_tokens,
+                    IERC1155::isApprovedForAllCall {
+                        account: owner,
+                        operator,
+                    },
+                )
+                .await?;
+            approvals.insert(operator, decode::<IERC1155::isApprovedForAllCall>(&data)?);
+        }
+        Ok(approvals)
+    }
+
+    /// Outcome token balance of `owner` for a single ERC-1155 position ID
+    pub async fn token_balance(&self, owner: Address, token_id: U256) -> Result<U256, RelayError> {
+        let data = self
+            .call(
+                self.contract_config.conditional_tokens,
+                IERC1155::balanceOfCall {
+                    account: owner,
+                    id: token_id,
+                },
+            )
+            .await?;
+        decode::<IERC1155::balanceOfCall>(&data)
+    }
+
+    /// Outcome token balances of `owner` for many position IDs in one `balanceOfBatch` call.
+    ///
+    /// The returned balances are in the same order as `token_ids`.
+    pub async fn token_balances(
+        &self,
+        owner: Address,
+        token_ids: &[U256],
+    ) -> Result<Vec<U256>, RelayError> {
+        if token_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let data = self
+            .call(
+                self.contract_config.conditional_tokens,
+                IERC1155::balanceOfBatchCall {
+                    accounts: vec![owner; token_ids.len()],
+                    ids: token_ids.to_vec(),
+                },
+            )
+            .await?;
+        let balances = decode::<IERC1155::balanceOfBatchCall>(&data)?;
+        if balances.len() != token_ids.len() {
+            return Err(RelayError::Api(format!(
+                "balanceOfBatch returned {} balances for {} token IDs",
+                balances.len(),
+                token_ids.len()
+            )));
+        }
+        Ok(balances)
+    }
+
+    /// USDC balance, allowances, approvals and outcome token balances of `owner`
+    pub async fn snapshot(
+        &self,
+        owner: Address,
+        token_ids: &[U256],
+    ) -> Result<OnChainBalances, RelayError> {
+        let usdc = self.usdc_balance(owner).await?;
+        let allowances = self.usdc_allowances(owner).await?;
+        let approvals = self.token_approvals(owner).await?;
+        let balances = self.token_balances(owner, token_ids).await?;
+
+        Ok(OnChainBalances {
+            owner,
+            usdc,
+            allowances,
+            approvals,
+            tokens: token_ids.iter().copied().zip(balances).collect(),
+        })
+    }
+
+    async fn call<C: SolCall>(&self, to: Address, call: C) -> Result<Bytes, RelayError> {
+        let tx = TransactionRequest::default()
+            .with_to(to)
+            .with_input(Bytes::from(call.abi_encode()));
+        self.provider
+            .call(tx)
+            .await
+            .map_err(|e| RelayError::Api(format!("RPC call failed: {}", e)))
+    }
+}
+
+fn decode<C: SolCall>(data: &[u8]) -> Result<C::Return, RelayError> {
+    C::abi_decode_returns(data).map_err(|e| {
+        RelayError::Api(format!(
+            "Invalid {} response: {}",
+            C::SIGNATURE.split('(').next().unwrap_or(C::SIGNATURE),
+            e
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::sol_types::SolValue;
+
+    #[test]
+    fn test_new_polygon() {
+        let onchain = OnChain::new(137).unwrap();
+        assert_eq!(
+            onchain.contract_config().collateral,
+            get_contract_config(137).unwrap().collateral
+        );
+    }
+
+    #[test]
+    fn test_new_unsupported_chain() {
+        let err = OnChain::new(999).unwrap_err();
+        assert!(format!("{err}").contains("Unsupported chain ID"));
+    }
+
+    #[test]
+    fn test_with_invalid_rpc_url() {
+        let err = OnChain::with_rpc_url(137, "not a url").unwrap_err();
+        assert!(format!("{err}").contains("Invalid RPC URL"));
+    }
+
+    #[tokio::test]
+    async fn test_token_balances_empty_skips_rpc() {
+        // Unroutable endpoint: any RPC call would fail
+        let onchain = OnChain::with_rpc_url(137, "http://127.0.0.1:1").unwrap();
+        let balances = onchain.token_balances(Address::ZERO, &[]).await.unwrap();
+        assert!(balances.is_empty());
+    }
+
+    #[test]
+    fn test_decode_balance_of_batch() {
+        let encoded = vec![U256::from(1), U256::from(2)].abi_encode();
+        let decoded = decode::<IERC1155::balanceOfBatchCall>(&encoded).unwrap();
+        assert_eq!(decoded, vec![U256::from(1), U256::from(2)]);
+    }
+
+    #[test]
+    fn test_decode_invalid_names_function() {
+        let err = decode::<IERC20::balanceOfCall>(&[0x01]).unwrap_err();
+        assert!(
+            format!("{err}").contains("Invalid balanceOf response"),
+            "unexpected: {err}"
+        );
+    }
+}
@@ -8,8 +8,8 @@
 //! cargo test -p polyoxide-relay --test live_api -- --ignored
 //! ```
 
-use alloy::primitives::Address;
-use polyoxide_relay::RelayClient;
+use alloy::primitives::{address, Address, B256, U256};
+use polyoxide_relay::{ctf, RelayClient};
 use std::time::Duration;
 
 fn client() -> RelayClient {
@@ -77,3 +77,39 @@ async fn live_get_nonce() {
         .expect("get_nonce should succeed for zero address");
     assert_eq!(nonce, 0, "zero address should have nonce 0");
 }
+
+// ── CTF position IDs ────────────────────────────────────────────
+
+#[tokio::test]
+#[ignore]
+async fn live_position_ids_match_gamma_clob_token_ids() {
+    let usdc = address!("2791Bca1f2de4661ED88A30C99A7a9449Aa84174");
+    let gamma = polyoxide_gamma::Gamma::new().expect("gamma client");
+    let markets = gamma
+        .markets()
+        .list()
+        .closed(false)
+        .limit(50)
+        .send()
+        .await
+        .expect("list markets");
+
+    let mut checked = 0;
+    // Neg-risk markets use the adapter's wrapped collateral, so only check standard ones
+    for market in markets.iter().filter(|m| m.neg_risk != Some(true)) {
+        let token_ids = market.clob_token_ids();
+        if token_ids.is_empty() {
+            continue;
+        }
+        let condition_id: B256 = market.condition_id.parse().expect("condition ID");
+
+        let derived = ctf::outcome_position_ids(usdc, condition_id, token_ids.len() as u32);
+        let expected: Vec<U256> = token_ids
+            .iter()
+            .map(|id| id.parse().expect("token ID"))
+            .collect();
+        assert_eq!(derived, expected, "market {}", market.id);
+        checked += 1;
+    }
+    assert!(checked > 0, "no standard markets to check");
+}
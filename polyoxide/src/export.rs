@@ -0,0 +1,800 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::{self, File};
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use arrow::array::{
+    ArrayRef, BooleanArray, Float64Array, RecordBatch, StringArray, TimestampSecondArray,
+    UInt32Array,
+};
+use arrow::datatypes::{DataType, Field, Schema, SchemaRef, TimeUnit};
+use arrow::error::ArrowError;
+use futures_util::{Stream, StreamExt};
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::errors::ParquetError;
+use parquet::file::properties::WriterProperties;
+use polyoxide_clob::api::markets::PriceHistoryPoint;
+use polyoxide_data::types::{Activity, Position, Trade};
+use serde::{Deserialize, Serialize};
+
+use crate::PolymarketError;
+
+/// Name of the cursor file kept next to the part files
+pub const CURSOR_FILE: &str = "_cursor.json";
+
+/// Default maximum number of rows per part file
+pub const DEFAULT_ROWS_PER_PART: usize = 100_000;
+
+/// Error writing an export
+#[derive(Debug, thiserror::Error)]
+pub enum ExportError {
+    /// Building a record batch failed
+    #[error("Arrow error: {0}")]
+    Arrow(#[from] ArrowError),
+
+    /// Writing a Parquet file failed
+    #[error("Parquet error: {0}")]
+    Parquet(#[from] ParquetError),
+
+    /// Filesystem error
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// The cursor file could not be read or written
+    #[error("Cursor error: {0}")]
+    Cursor(#[from] serde_json::Error),
+}
+
+/// File format of exported part files
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExportFormat {
+    /// Snappy-compressed Parquet
+    #[default]
+    Parquet,
+    /// CSV with a header row
+    Csv,
+}
+
+impl ExportFormat {
+    /// File extension of part files
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Parquet => "parquet",
+            Self::Csv => "csv",
+        }
+    }
+}
+
+/// A row type that can be exported as Arrow record batches
+pub trait ExportRecord {
+    /// Arrow schema; columns are never reordered or renamed
+    fn schema() -> SchemaRef;
+
+    /// Convert rows into a record batch matching [`ExportRecord::schema`]
+    fn to_batch(rows: &[Self]) -> Result<RecordBatch, ExportError>
+    where
+        Self: Sized;
+
+    /// Unix timestamp (seconds) used for incremental appends
+    fn timestamp(&self) -> i64;
+
+    /// Identity of the row among rows sharing its timestamp
+    fn key(&self) -> String;
+
+    /// Series the row belongs to. Each partition keeps its own cursor, so
+    /// series with overlapping time ranges can share a directory.
+    fn partition(&self) -> String;
+}
+
+/// A price history point tagged with its token, for [`ExportRecord`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PricePoint {
+    /// CLOB token ID
+    pub token_id: String,
+    /// Unix timestamp (seconds)
+    pub timestamp: i64,
+    /// Price at this point in time
+    pub price: f64,
+}
+
+impl PricePoint {
+    /// Tag a `prices_history` series with its token ID
+    pub fn from_history(token_id: &str, points: &[PriceHistoryPoint]) -> Vec<Self> {
+        points
+            .iter()
+            .map(|p| Self {
+                token_id: token_id.to_string(),
+                timestamp: p.timestamp,
+                price: p.price,
+            })
+            .collect()
+    }
+}
+
+/// A position as seen at a point in time, for [`ExportRecord`]
+///
+/// Positions carry no timestamp of their own; snapshots taken at different
+/// times append as separate rows.
+#[derive(Debug, Clone)]
+pub struct PositionSnapshot {
+    /// Unix timestamp (seconds) the snapshot was taken
+    pub taken_at: i64,
+    /// The position
+    pub position: Position,
+}
+
+impl PositionSnapshot {
+    /// Tag positions with the time they were fetched
+    pub fn from_positions(
+        taken_at: i64,
+        positions: impl IntoIterator<Item = Position>,
+    ) -> Vec<Self> {
+        positions
+            .into_iter()
+            .map(|position| Self { taken_at, position })
+            .collect()
+    }
+}
+
+fn timestamp_type() -> DataType {
+    DataType::Timestamp(TimeUnit::Second, Some("+00:00".into()))
+}
+
+fn timestamps<T>(rows: &[T], f: impl Fn(&T) -> i64) -> ArrayRef {
+    Arc::new(TimestampSecondArray::from_iter_values(rows.iter().map(f)).with_timezone("+00:00"))
+}
+
+fn strings<'a, T: 'a>(rows: &'a [T], f: impl Fn(&'a T) -> Option<&'a str>) -> ArrayRef {
+    Arc::new(rows.iter().map(f).collect::<StringArray>())
+}
+
+fn floats<T>(rows: &[T], f: impl Fn(&T) -> Option<f64>) -> ArrayRef {
+    Arc::new(rows.iter().map(f).collect::<Float64Array>())
+}
+
+fn u32s<T>(rows: &[T], f: impl Fn(&T) -> Option<u32>) -> ArrayRef {
+    Arc::new(rows.iter().map(f).collect::<UInt32Array>())
+}
+
+fn bools<T>(rows: &[T], f: impl Fn(&T) -> Option<bool>) -> ArrayRef {
+    Arc::new(rows.iter().map(f).collect::<BooleanArray>())
+}
+
+impl ExportRecord for Trade {
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new("timestamp", timestamp_type(), false),
+            Field::new("proxy_wallet", DataType::Utf8, false),
+            Field::new("side", DataType::Utf8, false),
+            Field::new("asset", DataType::Utf8, false),
+            Field::new("condition_id", DataType::Utf8, false),
+            Field::new("outcome", DataType::Utf8, false),
+            Field::new("outcome_index", DataType::UInt32, false),
+            Field::new("size", DataType::Float64, false),
+            Field::new("price", DataType::Float64, false),
+            Field::new("transaction_hash", DataType::Utf8, true),
+            Field::new("title", DataType::Utf8, false),
+            Field::new("slug", DataType::Utf8, false),
+            Field::new("event_slug", DataType::Utf8, true),
+            Field::new("name", DataType::Utf8, true),
+            Field::new("pseudonym", DataType::Utf8, true),
+        ]))
+    }
+
+    fn to_batch(rows: &[Self]) -> Result<RecordBatch, ExportError> {
+        let sides: Vec<String> = rows.iter().map(|t| t.side.to_string()).collect();
+        Ok(RecordBatch::try_new(
+            Self::schema(),
+            vec![
+                timestamps(rows, |t| t.timestamp),
+                strings(rows, |t| Some(&t.proxy_wallet)),
+                strings(&sides, |s| Some(s)),
+                strings(rows, |t| Some(&t.asset)),
+                strings(rows, |t| Some(&t.condition_id)),
+                strings(rows, |t| Some(&t.outcome)),
+                u32s(rows, |t| Some(t.outcome_index)),
+                floats(rows, |t| Some(t.size)),
+                floats(rows, |t| Some(t.price)),
+                strings(rows, |t| t.transaction_hash.as_deref()),
+                strings(rows, |t| Some(&t.title)),
+                strings(rows, |t| Some(&t.slug)),
+                strings(rows, |t| t.event_slug.as_deref()),
+                strings(rows, |t| t.name.as_deref()),
+                strings(rows, |t| t.pseudonym.as_deref()),
+            ],
+        )?)
+    }
+
+    fn timestamp(&self) -> i64 {
+        self.timestamp
+    }
+
+    fn key(&self) -> String {
+        format!(
+            "{}:{}:{}:{}:{}:{}",
+            self.transaction_hash.as_deref().unwrap_or_default(),
+            self.proxy_wallet,
+            self.asset,
+            self.side,
+            self.size,
+            self.price
+        )
+    }
+
+    fn partition(&self) -> String {
+        // Trades are listed per market and/or per wallet; either way each
+        // wallet's fills in one market arrive as a complete, ordered series
+        format!(
+            "{}:{}",
+            self.proxy_wallet.to_ascii_lowercase(),
+            self.condition_id.to_ascii_lowercase()
+        )
+    }
+}
+
+impl ExportRecord for Activity {
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new("timestamp", timestamp_type(), false),
+            Field::new("proxy_wallet", DataType::Utf8, false),
+            Field::new("type", DataType::Utf8, false),
+            Field::new("condition_id", DataType::Utf8, false),
+            Field::new("asset", DataType::Utf8, true),
+            Field::new("side", DataType::Utf8, true),
+            Field::new("outcome", DataType::Utf8, true),
+            Field::new("outcome_index", DataType::UInt32, true),
+            Field::new("size", DataType::Float64, false),
+            Field::new("usdc_size", DataType::Float64, false),
+            Field::new("price", DataType::Float64, true),
+            Field::new("transaction_hash", DataType::Utf8, true),
+            Field::new("title", DataType::Utf8, true),
+            Field::new("slug", DataType::Utf8, true),
+        ]))
+    }
+
+    fn to_batch(rows: &[Self]) -> Result<RecordBatch, ExportError> {
+        let types: Vec<String> = rows.iter().map(|a| a.activity_type.to_string()).collect();
+        Ok(RecordBatch::try_new(
+            Self::schema(),
+            vec![
+                timestamps(rows, |a| a.timestamp),
+                strings(rows, |a| Some(&a.proxy_wallet)),
+                strings(&types, |t| Some(t)),
+                strings(rows, |a| Some(&a.condition_id)),
+                strings(rows, |a| a.asset.as_deref()),
+                strings(rows, |a| a.side.as_deref().filter(|s| !s.is_empty())),
+                strings(rows, |a| a.outcome.as_deref()),
+                u32s(rows, |a| a.outcome_index),
+                floats(rows, |a| Some(a.size)),
+                floats(rows, |a| Some(a.usdc_size)),
+                floats(rows, |a| a.price),
+                strings(rows, |a| a.transaction_hash.as_deref()),
+                strings(rows, |a| a.title.as_deref()),
+                strings(rows, |a| a.slug.as_deref()),
+            ],
+        )?)
+    }
+
+    fn timestamp(&self) -> i64 {
+        self.timestamp
+    }
+
+    fn key(&self) -> String {
+        format!(
+            "{}:{}:{}:{}:{}:{}",
+            self.transaction_hash.as_deref().unwrap_or_default(),
+            self.proxy_wallet,
+            self.activity_type,
+            self.asset.as_deref().unwrap_or(&self.condition_id),
+            self.size,
+            self.usdc_size
+        )
+    }
+
+    fn partition(&self) -> String {
+        self.proxy_wallet.to_ascii_lowercase()
+    }
+}
+
+impl ExportRecord for PositionSnapshot {
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new("taken_at", timestamp_type(), false),
+            Field::new("proxy_wallet", DataType::Utf8, false),
+            Field::new("asset", DataType::Utf8, false),
+            Field::new("condition_id", DataType::Utf8, false),
+            Field::new("outcome", DataType::Utf8, false),
+            Field::new("outcome_index", DataType::UInt32, false),
+            Field::new("size", DataType::Float64, false),
+            Field::new("avg_price", DataType::Float64, false),
+            Field::new("initial_value", DataType::Float64, false),
+            Field::new("current_value", DataType::Float64, false),
+            Field::new("cash_pnl", DataType::Float64, false),
+            Field::new("percent_pnl", DataType::Float64, false),
+            Field::new("total_bought", DataType::Float64, false),
+            Field::new("realized_pnl", DataType::Float64, false),
+            Field::new("cur_price", DataType::Float64, false),
+            Field::new("redeemable", DataType::Boolean, false),
+            Field::new("mergeable", DataType::Boolean, false),
+            Field::new("negative_risk", DataType::Boolean, false),
+            Field::new("title", DataType::Utf8, false),
+            Field::new("slug", DataType::Utf8, false),
+            Field::new("event_slug", DataType::Utf8, true),
+            Field::new("end_date", DataType::Utf8, true),
+        ]))
+    }
+
+    fn to_batch(rows: &[Self]) -> Result<RecordBatch, ExportError> {
+        Ok(RecordBatch::try_new(
+            Self::schema(),
+            vec![
+                timestamps(rows, |s| s.taken_at),
+                strings(rows, |s| Some(&s.position.proxy_wallet)),
+                strings(rows, |s| Some(&s.position.asset)),
+                strings(rows, |s| Some(&s.position.condition_id)),
+                strings(rows, |s| Some(&s.position.outcome)),
+                u32s(rows, |s| Some(s.position.outcome_index)),
+                floats(rows, |s| Some(s.position.size)),
+                floats(rows, |s| Some(s.position.avg_price)),
+                floats(rows, |s| Some(s.position.initial_value)),
+                floats(rows, |s| Some(s.position.current_value)),
+                floats(rows, |s| Some(s.position.cash_pnl)),
+                floats(rows, |s| Some(s.position.percent_pnl)),
+                floats(rows, |s| Some(s.position.total_bought)),
+                floats(rows, |s| Some(s.position.realized_pnl)),
+                floats(rows, |s| Some(s.position.cur_price)),
+                bools(rows, |s| Some(s.position.redeemable)),
+                bools(rows, |s| Some(s.position.mergeable)),
+                bools(rows, |s| Some(s.position.negative_risk)),
+                strings(rows, |s| Some(&s.position.title)),
+                strings(rows, |s| Some(&s.position.slug)),
+                strings(rows, |s| s.position.event_slug.as_deref()),
+                strings(rows, |s| s.position.end_date.as_deref()),
+            ],
+        )?)
+    }
+
+    fn timestamp(&self) -> i64 {
+        self.taken_at
+    }
+
+    fn key(&self) -> String {
+        format!("{}:{}", self.position.proxy_wallet, self.position.asset)
+    }
+
+    fn partition(&self) -> String {
+        self.position.proxy_wallet.to_ascii_lowercase()
+    }
+}
+
+impl ExportRecord for PricePoint {
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new("timestamp", timestamp_type(), false),
+            Field::new("token_id", DataType::Utf8, false),
+            Field::new("price", DataType::Float64, false),
+        ]))
+    }
+
+    fn to_batch(rows: &[Self]) -> Result<RecordBatch, ExportError> {
+        Ok(RecordBatch::try_new(
+            Self::schema(),
+            vec![
+                timestamps(rows, |p| p.timestamp),
+                strings(rows, |p| Some(&p.token_id)),
+                floats(rows, |p| Some(p.price)),
+            ],
+        )?)
+    }
+
+    fn timestamp(&self) -> i64 {
+        self.timestamp
+    }
+
+    fn key(&self) -> String {
+        self.token_id.clone()
+    }
+
+    fn partition(&self) -> String {
+        self.token_id.clone()
+    }
+}
+
+/// Progress of an export, persisted as [`CURSOR_FILE`]
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportCursor {
+    /// Progress of each series, keyed by [`ExportRecord::partition`]
+    pub partitions: BTreeMap<String, PartitionCursor>,
+    /// Total rows exported
+    pub rows: u64,
+    /// Number of part files written
+    pub parts: u32,
+}
+
+/// Newest exported row of one partition
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PartitionCursor {
+    /// Timestamp of the newest exported row
+    pub timestamp: i64,
+    /// Keys of the exported rows sharing `timestamp`
+    pub keys: BTreeSet<String>,
+}
+
+impl ExportCursor {
+    /// Whether `row` is newer than everything already exported in its partition
+    pub fn is_new<T: ExportRecord>(&self, row: &T) -> bool {
+        match self.partitions.get(&row.partition()) {
+            None => true,
+            Some(cursor) => {
+                row.timestamp() > cursor.timestamp
+                    || (row.timestamp() == cursor.timestamp && !cursor.keys.contains(&row.key()))
+            }
+        }
+    }
+
+    /// Timestamp of the newest exported row in `partition`
+    pub fn timestamp(&self, partition: &str) -> Option<i64> {
+        self.partitions.get(partition).map(|c| c.timestamp)
+    }
+
+    fn advance<T: ExportRecord>(&mut self, rows: &[T]) {
+        for row in rows {
+            let cursor =
+                self.partitions
+                    .entry(row.partition())
+                    .or_insert_with(|| PartitionCursor {
+                        timestamp: row.timestamp(),
+                        keys: BTreeSet::new(),
+                    });
+            if row.timestamp() > cursor.timestamp {
+                cursor.timestamp = row.timestamp();
+                cursor.keys.clear();
+            }
+            cursor.keys.insert(row.key());
+        }
+        self.rows += rows.len() as u64;
+        self.parts += 1;
+    }
+}
+
+/// Incrementally exports rows into a directory of Parquet or CSV part files.
+///
+/// Every append writes rows newer than the last exported row of their
+/// [partition](ExportRecord::partition) as `part-NNNNNN.<ext>` files, oldest
+/// first, and then records its progress in [`CURSOR_FILE`]. Reopening the
+/// directory resumes from that cursor. The directory can be read directly
+/// with `pandas.read_parquet(dir)`.
+///
+/// A part written before a crash but not recorded in the cursor is
+/// overwritten by the next append, so rows are never duplicated.
+///
+/// # Example
+///
+/// ```no_run
+/// use polyoxide::export::{ExportFormat, Exporter};
+/// use polyoxide::polyoxide_data::{types::Trade, DataApi, Paginate};
+///
+/// # async fn example() -> Result<(), polyoxide::PolymarketError> {
+/// let data = DataApi::new()?;
+/// let mut exporter = Exporter::<Trade>::open("tape/trades", ExportFormat::Parquet)?;
+///
+/// let trades = data.trades().list().market(["0xabc"]).into_stream();
+/// let written = exporter.append_stream(trades).await?;
+/// println!("exported {written} new trades");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Exporter<T> {
+    dir: PathBuf,
+    format: ExportFormat,
+    rows_per_part: usize,
+    cursor: ExportCursor,
+    _rows: PhantomData<fn(T)>,
+}
+
+impl<T: ExportRecord> Exporter<T> {
+    /// Open (or create) an export directory and load its cursor
+    pub fn open(dir: impl AsRef<Path>, format: ExportFormat) -> Result<Self, ExportError> {
+        let dir = dir.as_ref().to_path_buf();
+        fs::create_dir_all(&dir)?;
+
+        let cursor = match fs::read(dir.join(CURSOR_FILE)) {
+            Ok(bytes) => serde_json::from_slice(&bytes)?,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => ExportCursor::default(),
+            Err(err) => return Err(err.into()),
+        };
+
+        Ok(Self {
+            dir,
+            format,
+            rows_per_part: DEFAULT_ROWS_PER_PART,
+            cursor,
+            _rows: PhantomData,
+        })
+    }
+
+    /// Set the maximum number of rows per part file
+    pub fn rows_per_part(mut self, rows: usize) -> Self {
+        self.rows_per_part = rows.max(1);
+        self
+    }
+
+    /// Export progress so far
+    pub fn cursor(&self) -> &ExportCursor {
+        &self.cursor
+    }
+
+    /// Timestamp of the newest exported row across all partitions
+    pub fn last_timestamp(&self) -> Option<i64> {
+        self.cursor.partitions.values().map(|c| c.timestamp).max()
+    }
+
+    /// Timestamp of the newest exported row in `partition`, to bound the
+    /// next fetch of that series
+    pub fn partition_timestamp(&self, partition: &str) -> Option<i64> {
+        self.cursor.timestamp(partition)
+    }
+
+    /// Paths of the part files written so far
+    pub fn parts(&self) -> Vec<PathBuf> {
+        (0..self.cursor.parts).map(|i| self.part_path(i)).collect()
+    }
+
+    /// Append the rows not exported yet, returning how many were written.
+    ///
+    /// Rows may arrive in any order; they are sorted by timestamp first.
+    pub fn append(&mut self, rows: impl IntoIterator<Item = T>) -> Result<usize, ExportError> {
+        let mut rows: Vec<T> = rows
+            .into_iter()
+            .filter(|row| self.cursor.is_new(row))
+            .collect();
+        rows.sort_by_key(|row| row.timestamp());
+
+        // Drop duplicates within the incoming rows themselves
+        let mut seen = BTreeSet::new();
+        rows.retain(|row| seen.insert((row.timestamp(), row.partition(), row.key())));
+
+        for chunk in rows.chunks(self.rows_per_part) {
+            self.write_part(chunk)?;
+            self.cursor.advance(chunk);
+            self.save_cursor()?;
+        }
+        Ok(rows.len())
+    }
+
+    /// Drain a paginated stream and append its new rows.
+    ///
+    /// The Data API lists newest first, so the whole stream is collected
+    /// before writing; bound it with the endpoint's own filters (for example
+    /// `start(exporter.last_timestamp())` on activity) to keep it short.
+    pub async fn append_stream<E>(
+        &mut self,
+        stream: impl Stream<Item = Result<T, E>>,
+    ) -> Result<usize, PolymarketError>
+    where
+        PolymarketError: From<E>,
+    {
+        let mut stream = std::pin::pin!(stream);
+        let mut rows = Vec::new();
+        while let Some(row) = stream.next().await {
+            let row = row?;
+            if self.cursor.is_new(&row) {
+                rows.push(row);
+            }
+        }
+        Ok(self.append(rows)?)
+    }
+
+    fn part_path(&self, index: u32) -> PathBuf {
+        self.dir
+            .join(format!("part-{index:06}.{}", self.format.extension()))
+    }
+
+    fn write_part(&self, rows: &[T]) -> Result<(), ExportError> {
+        let batch = T::to_batch(rows)?;
+        let path = self.part_path(self.cursor.parts);
+        let tmp = path.with_extension("tmp");
+        let file = File::create(&tmp)?;
+
+        match self.format {
+            ExportFormat::Parquet => {
+                let props = WriterProperties::builder()
+                    .set_compression(Compression::SNAPPY)
+                    .build();
+                let mut writer = ArrowWriter::try_new(file, T::schema(), Some(props))?;
+                writer.write(&batch)?;
+                writer.close()?;
+            }
+            ExportFormat::Csv => {
+                let mut writer = arrow::csv::WriterBuilder::new()
+                    .with_header(true)
+                    .build(file);
+                writer.write(&batch)?;
+            }
+        }
+
+        fs::rename(tmp, path)?;
+        Ok(())
+    }
+
+    fn save_cursor(&self) -> Result<(), ExportError> {
+        let path = self.dir.join(CURSOR_FILE);
+        let tmp = path.with_extension("tmp");
+        fs::write(&tmp, serde_json::to_vec_pretty(&self.cursor)?)?;
+        fs::rename(tmp, path)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        std::env::temp_dir().join(format!(
+            "polyoxide-export-{name}-{}-{nanos}",
+            std::process::id()
+        ))
+    }
+
+    fn trade(timestamp: i64, hash: &str) -> Trade {
+        serde_json::from_value(serde_json::json!({
+            "proxyWallet": "0xwallet",
+            "side": "BUY",
+            "asset": "111",
+            "conditionId": "0xabc",
+            "size": 10.0,
+            "price": 0.5,
+            "timestamp": timestamp,
+            "title": "Test?",
+            "slug": "test",
+            "outcome": "Yes",
+            "outcomeIndex": 0,
+            "transactionHash": hash,
+        }))
+        .unwrap()
+    }
+
+    fn parquet_rows(path: &Path) -> usize {
+        let reader = ParquetRecordBatchReaderBuilder::try_new(File::open(path).unwrap())
+            .unwrap()
+            .build()
+            .unwrap();
+        reader.map(|batch| batch.unwrap().num_rows()).sum()
+    }
+
+    // ── Batches ─────────────────────────────────────────────────
+
+    #[test]
+    fn trade_batch_matches_schema() {
+        let batch = Trade::to_batch(&[trade(1, "0x1"), trade(2, "0x2")]).unwrap();
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(batch.schema(), Trade::schema());
+        assert_eq!(batch.schema().field(0).name(), "timestamp");
+    }
+
+    #[test]
+    fn price_points_from_history() {
+        let history: Vec<PriceHistoryPoint> =
+            serde_json::from_str(r#"[{"t": 100, "p": 0.4}, {"t": 160, "p": 0.45}]"#).unwrap();
+        let points = PricePoint::from_history("111", &history);
+        assert_eq!(points[1].token_id, "111");
+        let batch = PricePoint::to_batch(&points).unwrap();
+        assert_eq!(batch.num_columns(), 3);
+        assert_eq!(batch.num_rows(), 2);
+    }
+
+    // ── Exporter ────────────────────────────────────────────────
+
+    #[test]
+    fn parquet_append_resumes_from_cursor() {
+        let dir = temp_dir("parquet");
+        let mut exporter = Exporter::<Trade>::open(&dir, ExportFormat::Parquet).unwrap();
+        assert_eq!(
+            exporter
+                .append(vec![trade(2, "0x2"), trade(1, "0x1")])
+                .unwrap(),
+            2
+        );
+        assert_eq!(exporter.last_timestamp(), Some(2));
+
+        // Reopen: overlapping rows are skipped, a new row at the same timestamp is kept
+        let mut exporter = Exporter::<Trade>::open(&dir, ExportFormat::Parquet).unwrap();
+        let written = exporter
+            .append(vec![
+                trade(1, "0x1"),
+                trade(2, "0x2"),
+                trade(2, "0x3"),
+                trade(3, "0x4"),
+            ])
+            .unwrap();
+        assert_eq!(written, 2);
+        assert_eq!(exporter.cursor().rows, 4);
+
+        let parts = exporter.parts();
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts.iter().map(|p| parquet_rows(p)).sum::<usize>(), 4);
+        assert_eq!(exporter.append(vec![trade(3, "0x4")]).unwrap(), 0);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn csv_parts_split_by_row_count() {
+        let dir = temp_dir("csv");
+        let mut exporter = Exporter::<PricePoint>::open(&dir, ExportFormat::Csv)
+            .unwrap()
+            .rows_per_part(2);
+        let points = (0..5)
+            .map(|t| PricePoint {
+                token_id: "111".into(),
+                timestamp: t,
+                price: 0.5,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(exporter.append(points).unwrap(), 5);
+
+        let parts = exporter.parts();
+        assert_eq!(parts.len(), 3);
+        let first = fs::read_to_string(&parts[0]).unwrap();
+        let mut lines = first.lines();
+        assert_eq!(lines.next(), Some("timestamp,token_id,price"));
+        assert_eq!(lines.count(), 2);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn partitions_resume_independently() {
+        let dir = temp_dir("partitions");
+        let point = |token: &str, timestamp| PricePoint {
+            token_id: token.into(),
+            timestamp,
+            price: 0.5,
+        };
+        let mut exporter = Exporter::<PricePoint>::open(&dir, ExportFormat::Csv).unwrap();
+        assert_eq!(
+            exporter.append((10..20).map(|t| point("111", t))).unwrap(),
+            10
+        );
+        // A second token covering an overlapping, older range is kept
+        assert_eq!(
+            exporter.append((5..15).map(|t| point("222", t))).unwrap(),
+            10
+        );
+        assert_eq!(exporter.partition_timestamp("111"), Some(19));
+        assert_eq!(exporter.partition_timestamp("222"), Some(14));
+        assert_eq!(exporter.last_timestamp(), Some(19));
+
+        let mut exporter = Exporter::<PricePoint>::open(&dir, ExportFormat::Csv).unwrap();
+        let written = exporter.append((12..18).map(|t| point("222", t))).unwrap();
+        assert_eq!(written, 3);
+        assert_eq!(exporter.cursor().rows, 23);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn append_stream_collects_rows() {
+        let dir = temp_dir("stream");
+        let mut exporter = Exporter::<Trade>::open(&dir, ExportFormat::Parquet).unwrap();
+        let rows = futures_util::stream::iter(vec![
+            Ok::<_, polyoxide_data::DataApiError>(trade(5, "0x5")),
+            Ok(trade(4, "0x4")),
+        ]);
+        assert_eq!(exporter.append_stream(rows).await.unwrap(), 2);
+        assert_eq!(exporter.last_timestamp(), Some(5));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -8,6 +8,12 @@
 //! - Type-safe API with idiomatic Rust patterns
 //! - EIP-712 order signing and HMAC authentication
 //! - Comprehensive market data and trading operations
+//! - [`Mirror`] to copy the trades of target wallets, with pluggable sizing and dry-run mode
+//! - Opt-in `export` feature writing trades, activity, positions and price history to Parquet or CSV
+//! - [`TradeWatcher`] to stream large-trade alerts enriched with market and trader metadata
+//! - [`PositionMonitor`] to report redeemable, mergeable and price-threshold events, with an
+//!   optional relayer auto-redeem/merge under the `relay` feature
+//! - [`MarketResolver`] to map slugs, condition IDs, token IDs and URLs to one market
 //!
 //! ## Example
 //!
@@ -68,12 +74,57 @@
 //! }
 //! ```
 
+#[cfg(all(feature = "clob", feature = "gamma"))]
+mod resolver;
+
+#[cfg(feature = "export")]
+pub mod export;
+#[cfg(all(feature = "clob", feature = "data"))]
+mod mirror;
+#[cfg(feature = "data")]
+mod monitor;
+#[cfg(all(feature = "clob", feature = "gamma"))]
+mod outcome_group;
+#[cfg(feature = "data")]
+mod poll;
+#[cfg(all(feature = "gamma", feature = "data"))]
+mod redeem;
+#[cfg(all(feature = "gamma", feature = "data"))]
+mod watcher;
+
+#[cfg(all(feature = "clob", feature = "data"))]
+pub use mirror::{
+    Mirror, MirrorOutcome, MirrorRecord, Sizing, SizingPolicy, DEFAULT_AUDIT_CAPACITY,
+    DEFAULT_LOOKBACK, DEFAULT_MIRROR_INTERVAL,
+};
+#[cfg(feature = "relay")]
+pub use monitor::RelayAction;
+#[cfg(feature = "data")]
+pub use monitor::{
+    ActionOutcome, Crossing, MonitorRecord, PositionAction, PositionEvent, PositionMonitor,
+    DEFAULT_MONITOR_INTERVAL,
+};
+#[cfg(all(feature = "clob", feature = "gamma"))]
+pub use outcome_group::{GroupArbitrage, GroupOutcome, OutcomeGroup, Quote};
+#[cfg(all(feature = "clob", feature = "gamma"))]
+pub use resolver::{MarketRef, MarketResolver, ResolvedMarket};
+
+#[cfg(all(feature = "gamma", feature = "data"))]
+pub use redeem::{redeemable_positions, RedeemablePosition};
+#[cfg(all(feature = "gamma", feature = "data"))]
+pub use watcher::{
+    TradeAlert, TradeThreshold, TradeWatcher, DEFAULT_WATCH_CACHE_TTL, DEFAULT_WATCH_INTERVAL,
+    DEFAULT_WATCH_LOOKBACK,
+};
+
 #[cfg(feature = "clob")]
 pub use polyoxide_clob;
 #[cfg(feature = "data")]
 pub use polyoxide_data;
 #[cfg(feature = "gamma")]
 pub use polyoxide_gamma;
+#[cfg(feature = "relay")]
+pub use polyoxide_relay;
 
 #[cfg(all(feature = "clob", feature = "gamma", feature = "data"))]
 use polyoxide_clob::{Account, Chain, Clob, ClobBuilder};
@@ -91,13 +142,31 @@ pub mod prelude {
         Account, Chain, Clob, ClobBuilder, ClobError, CreateOrderParams, Credentials, OrderKind,
         OrderSide, SignatureType,
     };
+    #[cfg(all(feature = "data", not(feature = "gamma")))]
+    pub use polyoxide_data::Paginate;
     #[cfg(feature = "data")]
     pub use polyoxide_data::{DataApi, DataApiError};
     #[cfg(feature = "gamma")]
+    pub use polyoxide_gamma::Paginate;
+    #[cfg(feature = "gamma")]
     pub use polyoxide_gamma::{Gamma, GammaError};
+    #[cfg(feature = "relay")]
+    pub use polyoxide_relay::RelayClient;
 
+    #[cfg(feature = "relay")]
+    pub use crate::RelayAction;
+    #[cfg(all(feature = "gamma", feature = "data"))]
+    pub use crate::{redeemable_positions, RedeemablePosition};
+    #[cfg(all(feature = "clob", feature = "gamma"))]
+    pub use crate::{MarketRef, MarketResolver, OutcomeGroup, ResolvedMarket};
+    #[cfg(all(feature = "clob", feature = "data"))]
+    pub use crate::{Mirror, MirrorRecord, Sizing, SizingPolicy};
     #[cfg(all(feature = "clob", feature = "gamma", feature = "data"))]
     pub use crate::{Polymarket, PolymarketBuilder, PolymarketError};
+    #[cfg(feature = "data")]
+    pub use crate::{PositionEvent, PositionMonitor};
+    #[cfg(all(feature = "gamma", feature = "data"))]
+    pub use crate::{TradeAlert, TradeThreshold, TradeWatcher};
 }
 
 /// Error types for Polymarket operations
@@ -118,9 +187,22 @@ pub enum PolymarketError {
     #[error("Gamma error: {0}")]
     Gamma(#[from] polyoxide_gamma::GammaError),
 
+    /// Export error
+    #[cfg(feature = "export")]
+    #[error("Export error: {0}")]
+    Export(#[from] export::ExportError),
+
     /// Configuration error
     #[error("Configuration error: {0}")]
     Config(String),
+
+    /// Identifier could not be parsed or does not point at a single market
+    #[error("Invalid market identifier: {0}")]
+    InvalidMarketId(String),
+
+    /// No market matches the identifier
+    #[error("Market not found: {0}")]
+    MarketNotFound(String),
 }
 
 /// Unified Polymarket client
@@ -146,6 +228,48 @@ impl Polymarket {
     pub fn builder(account: Account) -> PolymarketBuilder {
         PolymarketBuilder::new(account)
     }
+
+    /// Create a [`MarketResolver`] backed by this client's Gamma and CLOB clients.
+    ///
+    /// Each call starts with an empty cache; keep the resolver around to reuse it.
+    pub fn resolver(&self) -> MarketResolver {
+        MarketResolver::new(self.gamma.clone(), self.clob.clone())
+    }
+
+    /// Load the event with slug `event_slug` as an [`OutcomeGroup`] with its
+    /// order books fetched
+    pub async fn outcome_group(&self, event_slug: &str) -> Result<OutcomeGroup, PolymarketError> {
+        let event = self.gamma.events().get_by_slug(event_slug).send().await?;
+        let mut group = OutcomeGroup::from_event(event)?;
+        group.fetch_books(&self.clob).await?;
+        Ok(group)
+    }
+
+    /// Fetch `user`'s open positions and return those in markets that have
+    /// resolved or been voided, with their redemption payout.
+    ///
+    /// See [`redeemable_positions`] to match positions you already hold.
+    pub async fn redeemable_positions(
+        &self,
+        user: impl Into<String>,
+    ) -> Result<Vec<RedeemablePosition>, PolymarketError> {
+        use polyoxide_gamma::Paginate;
+
+        let positions = self.data.user(user).list_positions().all().await?;
+
+        let mut condition_ids: Vec<&str> =
+            positions.iter().map(|p| p.condition_id.as_str()).collect();
+        condition_ids.sort_unstable();
+        condition_ids.dedup();
+
+        let markets = self
+            .gamma
+            .markets()
+            .by_condition_ids(&condition_ids, |list| list.closed(true))
+            .await?;
+
+        Ok(redeemable_positions(positions, &markets))
+    }
 }
 
 /// Builder for Polymarket client
@@ -0,0 +1,613 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures_util::stream::BoxStream;
+use polyoxide_clob::{Clob, CreateOrderParams, OrderKind, OrderResponse, OrderSide, SignatureType};
+use polyoxide_data::{
+    types::{Activity, ActivityType},
+    DataApi, Paginate,
+};
+
+use crate::poll::{poll_stream, FeedCursor, Poller};
+use crate::PolymarketError;
+
+/// Default delay between polls
+pub const DEFAULT_MIRROR_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Default page size when fetching a target's trades
+pub const DEFAULT_LOOKBACK: u32 = 100;
+
+/// Default number of records kept in [`Mirror::audit_log`]
+pub const DEFAULT_AUDIT_CAPACITY: usize = 1000;
+
+/// Lowest and highest price a mirrored order may be placed at
+const MIN_PRICE: f64 = 0.001;
+const MAX_PRICE: f64 = 0.999;
+
+type RecordSink = Arc<dyn Fn(&MirrorRecord) + Send + Sync>;
+
+/// Decides how many shares to trade when copying a target's fill.
+///
+/// Return `None` to skip the fill. Closures of the form
+/// `Fn(&Activity) -> Option<f64>` implement this trait.
+pub trait SizingPolicy: Send + Sync {
+    /// Number of shares to trade for `fill`
+    fn size(&self, fill: &Activity) -> Option<f64>;
+}
+
+impl<F> SizingPolicy for F
+where
+    F: Fn(&Activity) -> Option<f64> + Send + Sync,
+{
+    fn size(&self, fill: &Activity) -> Option<f64> {
+        self(fill)
+    }
+}
+
+/// Built-in sizing policies
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Sizing {
+    /// Trade a fixed number of shares per fill
+    Fixed(f64),
+    /// Trade a fraction of the target's fill size
+    Proportional(f64),
+    /// Trade a fraction of the target's fill size, capped at a USDC notional
+    CappedNotional {
+        /// Fraction of the target's size
+        ratio: f64,
+        /// Maximum `size * price` per order
+        max_notional: f64,
+    },
+}
+
+impl SizingPolicy for Sizing {
+    fn size(&self, fill: &Activity) -> Option<f64> {
+        let size = match *self {
+            Self::Fixed(size) => size,
+            Self::Proportional(ratio) => fill.size * ratio,
+            Self::CappedNotional {
+                ratio,
+                max_notional,
+            } => {
+                let price = fill.price.filter(|p| *p > 0.0)?;
+                (fill.size * ratio).min(max_notional / price)
+            }
+        };
+        (size.is_finite() && size > 0.0).then_some(size)
+    }
+}
+
+/// What happened to a copied fill
+#[derive(Debug, Clone)]
+pub enum MirrorOutcome {
+    /// Dry-run mode: the order was built but not placed
+    DryRun,
+    /// The order was accepted by the CLOB
+    Placed(OrderResponse),
+    /// The CLOB rejected the order, or placing it failed
+    Failed(String),
+    /// The fill was not copied
+    Skipped(String),
+}
+
+/// Audit log entry for one target fill
+#[derive(Debug, Clone)]
+pub struct MirrorRecord {
+    /// Wallet that was copied
+    pub target: String,
+    /// The target's fill, as reported by the Data API
+    pub fill: Activity,
+    /// Order built from the fill, if sizing succeeded
+    pub order: Option<CreateOrderParams>,
+    /// Result of placing the order
+    pub outcome: MirrorOutcome,
+}
+
+impl MirrorRecord {
+    /// Whether an order reached the CLOB and was accepted
+    pub fn is_placed(&self) -> bool {
+        matches!(self.outcome, MirrorOutcome::Placed(_))
+    }
+}
+
+/// Copies the trades of target wallets onto the CLOB.
+///
+/// Each poll fetches every TRADE of every target since the newest fill seen,
+/// paging through the Data API as needed. Fills are deduplicated by
+/// transaction hash and asset, sized with a [`SizingPolicy`] and placed as
+/// limit orders at the target's fill price (optionally widened by
+/// [`Mirror::price_buffer`]). The first poll of a target only records a
+/// baseline, so history is never copied. Mirrors start in
+/// dry-run mode; call [`Mirror::dry_run`] with `false` to place orders.
+///
+/// Every fill produces a [`MirrorRecord`], passed to the [`Mirror::on_record`]
+/// callback. The most recent records are also kept in [`Mirror::audit_log`].
+///
+/// # Example
+///
+/// ```no_run
+/// use futures_util::StreamExt;
+/// use polyoxide::{Mirror, Sizing};
+/// use polyoxide::polyoxide_clob::{Account, Clob};
+/// use polyoxide::polyoxide_data::DataApi;
+///
+/// # async fn example() -> Result<(), polyoxide::PolymarketError> {
+/// let clob = Clob::from_account(Account::from_env()?)?;
+/// let mirror = Mirror::new(clob, DataApi::new()?)
+///     .target("0x56687bf447db6ffa42ffe2204a05edaa20f55839")
+///     .sizing(Sizing::CappedNotional { ratio: 0.1, max_notional: 50.0 })
+///     .on_record(|record| println!("{:?}", record.outcome));
+///
+/// let mut records = mirror.into_stream();
+/// while let Some(record) = records.next().await {
+///     record?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct Mirror {
+    clob: Clob,
+    data: DataApi,
+    targets: Vec<String>,
+    sizing: Arc<dyn SizingPolicy>,
+    interval: Duration,
+    lookback: u32,
+    dry_run: bool,
+    copy_sells: bool,
+    min_size: f64,
+    price_buffer: f64,
+    order_kind: OrderKind,
+    signature_type: Option<SignatureType>,
+    on_record: Option<RecordSink>,
+    cursors: HashMap<String, FeedCursor>,
+    audit: Vec<MirrorRecord>,
+    audit_capacity: usize,
+}
+
+impl Mirror {
+    /// Create a mirror with no targets, copying 1:1 in dry-run mode
+    pub fn new(clob: Clob, data: DataApi) -> Self {
+        Self {
+            clob,
+            data,
+            targets: Vec::new(),
+            sizing: Arc::new(Sizing::Proportional(1.0)),
+            interval: DEFAULT_MIRROR_INTERVAL,
+            lookback: DEFAULT_LOOKBACK,
+            dry_run: true,
+            copy_sells: true,
+            min_size: 0.0,
+            price_buffer: 0.0,
+            order_kind: OrderKind::Gtc,
+            signature_type: None,
+            on_record: None,
+            cursors: HashMap::new(),
+            audit: Vec::new(),
+            audit_capacity: DEFAULT_AUDIT_CAPACITY,
+        }
+    }
+
+    /// Add a wallet to copy
+    pub fn target(mut self, address: impl Into<String>) -> Self {
+        self.targets.push(address.into().to_ascii_lowercase());
+        self
+    }
+
+    /// Add several wallets to copy
+    pub fn targets(mut self, addresses: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        for address in addresses {
+            self = self.target(address);
+        }
+        self
+    }
+
+    /// Set the sizing policy (default: `Sizing::Proportional(1.0)`)
+    pub fn sizing(mut self, policy: impl SizingPolicy + 'static) -> Self {
+        self.sizing = Arc::new(policy);
+        self
+    }
+
+    /// Set the delay between polls
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Set the page size used when fetching a target's trades
+    pub fn lookback(mut self, limit: u32) -> Self {
+        self.lookback = limit;
+        self
+    }
+
+    /// Build orders without placing them (default: true)
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Copy the targets' sells as well as their buys (default: true)
+    pub fn copy_sells(mut self, copy: bool) -> Self {
+        self.copy_sells = copy;
+        self
+    }
+
+    /// Skip fills whose sized order is below this many shares
+    pub fn min_size(mut self, size: f64) -> Self {
+        self.min_size = size;
+        self
+    }
+
+    /// Pay up to this much above (buys) or below (sells) the target's price
+    pub fn price_buffer(mut self, buffer: f64) -> Self {
+        self.price_buffer = buffer;
+        self
+    }
+
+    /// Set the order type (default: GTC)
+    pub fn order_kind(mut self, kind: OrderKind) -> Self {
+        self.order_kind = kind;
+        self
+    }
+
+    /// Set the signature type used for placed orders
+    pub fn signature_type(mut self, signature_type: SignatureType) -> Self {
+        self.signature_type = Some(signature_type);
+        self
+    }
+
+    /// Call `sink` with every record, e.g. to persist the audit log
+    pub fn on_record(mut self, sink: impl Fn(&MirrorRecord) + Send + Sync + 'static) -> Self {
+        self.on_record = Some(Arc::new(sink));
+        self
+    }
+
+    /// Set how many records [`Mirror::audit_log`] keeps (default:
+    /// [`DEFAULT_AUDIT_CAPACITY`]); use [`Mirror::on_record`] to keep them all
+    pub fn audit_capacity(mut self, capacity: usize) -> Self {
+        self.audit_capacity = capacity;
+        self
+    }
+
+    /// The most recent records, oldest first
+    pub fn audit_log(&self) -> &[MirrorRecord] {
+        &self.audit
+    }
+
+    /// Poll every target once and copy any new fills.
+    ///
+    /// Returns the records produced by this poll, or the errors of targets
+    /// whose activity could not be fetched.
+    pub async fn poll(&mut self) -> Vec<Result<MirrorRecord, PolymarketError>> {
+        let mut results = Vec::new();
+        for target in self.targets.clone() {
+            let fills = match self.fetch(&target).await {
+                Ok(fills) => fills,
+                Err(err) => {
+                    results.push(Err(err));
+                    continue;
+                }
+            };
+            for fill in fills {
+                let record = self.copy(&target, fill).await;
+                self.log(&record);
+                results.push(Ok(record));
+            }
+        }
+        results
+    }
+
+    /// Start polling and stream the records.
+    ///
+    /// Fetch errors are yielded as `Err` items; polling continues on the next tick.
+    pub fn into_stream(self) -> BoxStream<'static, Result<MirrorRecord, PolymarketError>> {
+        poll_stream(self)
+    }
+
+    /// Pass `record` to the sink and append it to the bounded audit log
+    fn log(&mut self, record: &MirrorRecord) {
+        if let Some(sink) = &self.on_record {
+            sink(record);
+        }
+        self.audit.push(record.clone());
+        if self.audit.len() > self.audit_capacity {
+            let excess = self.audit.len() - self.audit_capacity;
+            self.audit.drain(..excess);
+        }
+    }
+
+    /// Fetch the target's fills that have not been seen yet, oldest first
+    async fn fetch(&mut self, target: &str) -> Result<Vec<Activity>, PolymarketError> {
+        let request = self
+            .data
+            .user(target)
+            .activity()
+            .activity_type([ActivityType::Trade])
+            .limit(self.lookback);
+        // The baseline only needs the newest fills; after that, page back to
+        // the cursor so a burst larger than one page is not lost
+        let fills = match self.cursors.get(target) {
+            Some(cursor) => request.start(cursor.timestamp).all().await?,
+            None => request.send().await?,
+        };
+
+        let baseline = !self.cursors.contains_key(target);
+        let cursor = self.cursors.entry(target.to_string()).or_default();
+        let fresh = cursor.advance(fills, |f| f.timestamp, fill_key);
+        Ok(if baseline { Vec::new() } else { fresh })
+    }
+
+    async fn copy(&self, target: &str, fill: Activity) -> MirrorRecord {
+        let (order, outcome) = match self.build_order(&fill) {
+            Err(reason) => (None, MirrorOutcome::Skipped(reason)),
+            Ok(order) if self.dry_run => (Some(order), MirrorOutcome::DryRun),
+            Ok(order) => {
+                let outcome = match self.clob.place_order(&order, None).await {
+                    Ok(response) if response.success => MirrorOutcome::Placed(response),
+                    Ok(response) => MirrorOutcome::Failed(
+                        response
+                            .error_msg
+                            .unwrap_or_else(|| "order rejected".to_string()),
+                    ),
+                    Err(err) => MirrorOutcome::Failed(err.to_string()),
+                };
+                (Some(order), outcome)
+            }
+        };
+
+        MirrorRecord {
+            target: target.to_string(),
+            fill,
+            order,
+            outcome,
+        }
+    }
+
+    /// Turn a target fill into order parameters, or explain why it is skipped
+    fn build_order(&self, fill: &Activity) -> Result<CreateOrderParams, String> {
+        let token_id = fill
+            .asset
+            .clone()
+            .filter(|a| !a.is_empty())
+            .ok_or("fill has no asset")?;
+        let price = fill.price.ok_or("fill has no price")?;
+        let side = match fill.side.as_deref().map(str::to_ascii_uppercase).as_deref() {
+            Some("BUY") => OrderSide::Buy,
+            Some("SELL") if self.copy_sells => OrderSide::Sell,
+            Some("SELL") => return Err("sells are not copied".to_string()),
+            _ => return Err("fill has no side".to_string()),
+        };
+
+        let size = self
+            .sizing
+            .size(fill)
+            .ok_or("sizing policy skipped the fill")?;
+        if size < self.min_size {
+            return Err(format!(
+                "size {size} is below the minimum {}",
+                self.min_size
+            ));
+        }
+
+        let price = match side {
+            OrderSide::Buy => price + self.price_buffer,
+            OrderSide::Sell => price - self.price_buffer,
+        }
+        .clamp(MIN_PRICE, MAX_PRICE);
+
+        Ok(CreateOrderParams {
+            token_id,
+            price,
+            size,
+            side,
+            order_type: self.order_kind,
+            post_only: false,
+            expiration: None,
+            funder: None,
+            signature_type: self.signature_type,
+        })
+    }
+}
+
+impl std::fmt::Debug for Mirror {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Mirror")
+            .field("targets", &self.targets)
+            .field("interval", &self.interval)
+            .field("dry_run", &self.dry_run)
+            .field("copy_sells", &self.copy_sells)
+            .field("min_size", &self.min_size)
+            .field("price_buffer", &self.price_buffer)
+            .field("order_kind", &self.order_kind)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Poller for Mirror {
+    type Item = MirrorRecord;
+
+    fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    async fn tick(&mut self) -> Vec<Result<MirrorRecord, PolymarketError>> {
+        self.poll().await
+    }
+}
+
+/// Dedup key of a fill: its transaction hash and asset, since one
+/// transaction can fill the same wallet in several tokens
+fn fill_key(fill: &Activity) -> String {
+    let asset = fill.asset.as_deref().unwrap_or_default();
+    match fill.transaction_hash.as_deref() {
+        Some(hash) if !hash.is_empty() => format!("{hash}:{asset}"),
+        _ => format!(
+            "{}:{asset}:{}:{}",
+            fill.timestamp, fill.size, fill.usdc_size
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fill(timestamp: i64, hash: &str, side: &str, size: f64, price: f64) -> Activity {
+        serde_json::from_value(serde_json::json!({
+            "proxyWallet": "0xtarget",
+            "timestamp": timestamp,
+            "conditionId": "0xabc",
+            "type": "TRADE",
+            "size": size,
+            "usdcSize": size * price,
+            "transactionHash": hash,
+            "price": price,
+            "asset": "111",
+            "side": side,
+            "outcomeIndex": 0,
+        }))
+        .unwrap()
+    }
+
+    fn mirror() -> Mirror {
+        let clob = Clob::public();
+        Mirror::new(clob, DataApi::new().unwrap()).target("0xTarget")
+    }
+
+    // ── Sizing ──────────────────────────────────────────────────
+
+    #[test]
+    fn sizing_policies() {
+        let fill = fill(1, "0x1", "BUY", 200.0, 0.5);
+        assert_eq!(Sizing::Fixed(10.0).size(&fill), Some(10.0));
+        assert_eq!(Sizing::Proportional(0.25).size(&fill), Some(50.0));
+        assert_eq!(
+            Sizing::CappedNotional {
+                ratio: 0.5,
+                max_notional: 20.0
+            }
+            .size(&fill),
+            Some(40.0)
+        );
+        assert_eq!(Sizing::Proportional(0.0).size(&fill), None);
+    }
+
+    #[test]
+    fn closure_sizing_policy() {
+        let policy = |fill: &Activity| (fill.size > 100.0).then_some(5.0);
+        assert_eq!(policy.size(&fill(1, "0x1", "BUY", 200.0, 0.5)), Some(5.0));
+        assert_eq!(policy.size(&fill(1, "0x1", "BUY", 50.0, 0.5)), None);
+    }
+
+    // ── Dedup ───────────────────────────────────────────────────
+
+    #[test]
+    fn cursor_dedupes_by_transaction_hash() {
+        let advance = |cursor: &mut FeedCursor, fills: Vec<Activity>| {
+            cursor.advance(fills, |f| f.timestamp, fill_key)
+        };
+        let mut cursor = FeedCursor::default();
+        let fresh = advance(
+            &mut cursor,
+            vec![
+                fill(2, "0x2", "BUY", 1.0, 0.5),
+                fill(1, "0x1", "BUY", 1.0, 0.5),
+            ],
+        );
+        assert_eq!(fresh.len(), 2);
+        assert_eq!(fresh[0].timestamp, 1);
+
+        // The boundary fill comes back on the next fetch
+        let fresh = advance(
+            &mut cursor,
+            vec![
+                fill(2, "0x2", "BUY", 1.0, 0.5),
+                fill(2, "0x3", "SELL", 1.0, 0.5),
+                fill(3, "0x4", "BUY", 1.0, 0.5),
+            ],
+        );
+        let hashes: Vec<_> = fresh
+            .iter()
+            .map(|f| f.transaction_hash.as_deref().unwrap())
+            .collect();
+        assert_eq!(hashes, vec!["0x3", "0x4"]);
+        assert!(advance(&mut cursor, vec![fill(1, "0x9", "BUY", 1.0, 0.5)]).is_empty());
+    }
+
+    // ── Orders ──────────────────────────────────────────────────
+
+    #[test]
+    fn build_order_applies_sizing_and_buffer() {
+        let mirror = mirror()
+            .sizing(Sizing::Proportional(0.1))
+            .price_buffer(0.02)
+            .order_kind(OrderKind::Fak);
+
+        let order = mirror
+            .build_order(&fill(1, "0x1", "BUY", 100.0, 0.5))
+            .unwrap();
+        assert_eq!(order.token_id, "111");
+        assert_eq!(order.side, OrderSide::Buy);
+        assert!((order.size - 10.0).abs() < 1e-9);
+        assert!((order.price - 0.52).abs() < 1e-9);
+        assert_eq!(order.order_type, OrderKind::Fak);
+
+        let order = mirror
+            .build_order(&fill(1, "0x1", "SELL", 100.0, 0.999))
+            .unwrap();
+        assert!((order.price - 0.979).abs() < 1e-9);
+    }
+
+    #[test]
+    fn build_order_skips() {
+        let mirror = mirror().copy_sells(false).min_size(5.0);
+        assert!(mirror
+            .build_order(&fill(1, "0x1", "SELL", 100.0, 0.5))
+            .is_err());
+        assert!(mirror
+            .build_order(&fill(1, "0x1", "BUY", 1.0, 0.5))
+            .is_err());
+        let mut no_price = fill(1, "0x1", "BUY", 100.0, 0.5);
+        no_price.price = None;
+        assert!(mirror.build_order(&no_price).is_err());
+    }
+
+    #[test]
+    fn dry_run_by_default() {
+        assert!(mirror().dry_run);
+        assert!(!mirror().dry_run(false).dry_run);
+    }
+
+    #[tokio::test]
+    async fn audit_log_is_bounded() {
+        let mut mirror = mirror().audit_capacity(2);
+        for timestamp in 1..=3 {
+            let record = mirror
+                .copy("0xtarget", fill(timestamp, "0x1", "BUY", 10.0, 0.5))
+                .await;
+            mirror.log(&record);
+        }
+        let kept: Vec<i64> = mirror
+            .audit_log()
+            .iter()
+            .map(|r| r.fill.timestamp)
+            .collect();
+        assert_eq!(kept, vec![2, 3]);
+    }
+
+    #[tokio::test]
+    async fn dry_run_records_without_placing() {
+        let mut mirror = mirror();
+        let record = mirror
+            .copy("0xtarget", fill(1, "0x1", "BUY", 10.0, 0.5))
+            .await;
+        assert!(matches!(record.outcome, MirrorOutcome::DryRun));
+        assert!(!record.is_placed());
+        assert_eq!(record.order.as_ref().unwrap().size, 10.0);
+        assert!(mirror.audit_log().is_empty());
+        mirror.log(&record);
+        assert_eq!(mirror.audit_log().len(), 1);
+        mirror.targets.clear();
+        assert!(mirror.poll().await.is_empty());
+    }
+}
@@ -0,0 +1,654 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures_util::future::BoxFuture;
+use futures_util::stream::BoxStream;
+use polyoxide_data::{types::Position, DataApi, Paginate};
+
+use crate::poll::{poll_stream, Poller};
+use crate::PolymarketError;
+
+/// Default delay between scans
+pub const DEFAULT_MONITOR_INTERVAL: Duration = Duration::from_secs(60);
+
+type RecordSink = Arc<dyn Fn(&MonitorRecord) + Send + Sync>;
+
+/// Direction in which a position's price crossed a threshold
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Crossing {
+    /// The price rose to or above the threshold
+    Above,
+    /// The price fell to or below the threshold
+    Below,
+}
+
+/// A change in the lifecycle of a wallet's positions
+#[derive(Debug, Clone)]
+pub enum PositionEvent {
+    /// The market resolved and the wallet's positions in it can be redeemed
+    Redeemable {
+        /// Condition ID of the market
+        condition_id: String,
+        /// The wallet's redeemable positions in the market
+        positions: Vec<Position>,
+    },
+    /// The wallet holds every outcome of a market, so full sets can be merged
+    Mergeable {
+        /// Condition ID of the market
+        condition_id: String,
+        /// The wallet's positions in the market, one per outcome
+        positions: Vec<Position>,
+        /// Number of full sets that can be merged (smallest outcome size)
+        amount: f64,
+    },
+    /// A position's `cur_price` crossed a configured threshold
+    PriceCrossed {
+        /// The position, with its new price
+        position: Box<Position>,
+        /// The threshold that was crossed
+        threshold: f64,
+        /// Price at the previous scan
+        previous: f64,
+        /// Direction of the crossing
+        direction: Crossing,
+    },
+}
+
+impl PositionEvent {
+    /// Condition ID of the market the event refers to
+    pub fn condition_id(&self) -> &str {
+        match self {
+            Self::Redeemable { condition_id, .. } | Self::Mergeable { condition_id, .. } => {
+                condition_id
+            }
+            Self::PriceCrossed { position, .. } => &position.condition_id,
+        }
+    }
+}
+
+/// Result of an automatic action taken on an event
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ActionOutcome {
+    /// The action was submitted; holds the relayer transaction ID
+    Submitted(String),
+    /// Submitting the action failed
+    Failed(String),
+    /// The action was not taken
+    Skipped(String),
+}
+
+/// Hook run on every [`PositionEvent`], e.g. to redeem or merge on-chain.
+///
+/// Return `None` for events the action does not handle.
+pub trait PositionAction: Send + Sync {
+    /// Act on `event`
+    fn act<'a>(&'a self, event: &'a PositionEvent) -> BoxFuture<'a, Option<ActionOutcome>>;
+}
+
+/// An event and the outcome of the action taken on it
+#[derive(Debug, Clone)]
+pub struct MonitorRecord {
+    /// What changed
+    pub event: PositionEvent,
+    /// Outcome of the configured action, if any ran
+    pub action: Option<ActionOutcome>,
+}
+
+/// Periodically scans a wallet's positions and reports lifecycle changes.
+///
+/// Each scan fetches every open position of the wallet from the Data API and
+/// emits a [`PositionEvent`] when a market becomes redeemable or mergeable, or
+/// when a position's `cur_price` crosses one of the configured thresholds.
+/// Redeemable and mergeable markets are reported once when first seen
+/// (including on the first scan), again if they stop and start qualifying,
+/// and on every scan while the action on them [fails](ActionOutcome::Failed).
+/// Price crossings are measured against the previous scan, so the first scan
+/// never reports one.
+///
+/// An optional [`PositionAction`] runs on every event; with the `relay`
+/// feature, [`RelayAction`](crate::RelayAction) submits the matching gasless
+/// redemption or merge.
+///
+/// # Example
+///
+/// ```no_run
+/// use futures_util::StreamExt;
+/// use polyoxide::PositionMonitor;
+/// use polyoxide::polyoxide_data::DataApi;
+///
+/// # async fn example() -> Result<(), polyoxide::PolymarketError> {
+/// let monitor = PositionMonitor::new(DataApi::new()?, "0x56687bf447db6ffa42ffe2204a05edaa20f55839")
+///     .price_threshold(0.9)
+///     .price_threshold(0.1)
+///     .on_record(|record| println!("{:?}", record.event));
+///
+/// let mut records = monitor.into_stream();
+/// while let Some(record) = records.next().await {
+///     record?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct PositionMonitor {
+    data: DataApi,
+    user: String,
+    interval: Duration,
+    thresholds: Vec<f64>,
+    action: Option<Arc<dyn PositionAction>>,
+    on_record: Option<RecordSink>,
+    redeemable: HashSet<String>,
+    mergeable: HashSet<String>,
+    prices: HashMap<String, f64>,
+}
+
+impl PositionMonitor {
+    /// Create a monitor for `user`'s positions with no price thresholds and no action
+    pub fn new(data: DataApi, user: impl Into<String>) -> Self {
+        Self {
+            data,
+            user: user.into(),
+            interval: DEFAULT_MONITOR_INTERVAL,
+            thresholds: Vec::new(),
+            action: None,
+            on_record: None,
+            redeemable: HashSet::new(),
+            mergeable: HashSet::new(),
+            prices: HashMap::new(),
+        }
+    }
+
+    /// Set the delay between scans
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Report positions whose `cur_price` crosses `price` in either direction
+    pub fn price_threshold(mut self, price: f64) -> Self {
+        self.thresholds.push(price);
+        self
+    }
+
+    /// Run `action` on every event
+    pub fn action(mut self, action: impl PositionAction + 'static) -> Self {
+        self.action = Some(Arc::new(action));
+        self
+    }
+
+    /// Call `sink` with every record
+    pub fn on_record(mut self, sink: impl Fn(&MonitorRecord) + Send + Sync + 'static) -> Self {
+        self.on_record = Some(Arc::new(sink));
+        self
+    }
+
+    /// Scan the wallet once and return the records of any new events
+    pub async fn scan(&mut self) -> Result<Vec<MonitorRecord>, PolymarketError> {
+        let positions = self.data.user(&self.user).list_positions().all().await?;
+        Ok(self.handle(positions).await)
+    }
+
+    /// Detect events in `positions` and run the action on each
+    async fn handle(&mut self, positions: Vec<Position>) -> Vec<MonitorRecord> {
+        let mut records = Vec::new();
+        for event in self.detect(positions) {
+            let action = match &self.action {
+                Some(action) => action.act(&event).await,
+                None => None,
+            };
+            if matches!(action, Some(ActionOutcome::Failed(_))) {
+                self.forget(&event);
+            }
+            let record = MonitorRecord { event, action };
+            if let Some(sink) = &self.on_record {
+                sink(&record);
+            }
+            records.push(record);
+        }
+        records
+    }
+
+    /// Report a redeemable or mergeable market again on the next scan, so a
+    /// failed action is retried
+    fn forget(&mut self, event: &PositionEvent) {
+        match event {
+            PositionEvent::Redeemable { condition_id, .. } => {
+                self.redeemable.remove(condition_id);
+            }
+            PositionEvent::Mergeable { condition_id, .. } => {
+                self.mergeable.remove(condition_id);
+            }
+            PositionEvent::PriceCrossed { .. } => {}
+        }
+    }
+
+    /// Start scanning and stream the records.
+    ///
+    /// Fetch errors are yielded as `Err` items; scanning continues on the next tick.
+    pub fn into_stream(self) -> BoxStream<'static, Result<MonitorRecord, PolymarketError>> {
+        poll_stream(self)
+    }
+
+    /// Compare `positions` with the previous scan and return the new events
+    fn detect(&mut self, positions: Vec<Position>) -> Vec<PositionEvent> {
+        let mut events = Vec::new();
+
+        for position in &positions {
+            let price = position.cur_price;
+            if let Some(previous) = self.prices.insert(position.asset.clone(), price) {
+                for &threshold in &self.thresholds {
+                    let direction = if previous < threshold && price >= threshold {
+                        Crossing::Above
+                    } else if previous > threshold && price <= threshold {
+                        Crossing::Below
+                    } else {
+                        continue;
+                    };
+                    events.push(PositionEvent::PriceCrossed {
+                        position: Box::new(position.clone()),
+                        threshold,
+                        previous,
+                        direction,
+                    });
+                }
+            }
+        }
+        let held: HashSet<&str> = positions.iter().map(|p| p.asset.as_str()).collect();
+        self.prices.retain(|asset, _| held.contains(asset.as_str()));
+
+        let mut by_condition: BTreeMap<String, Vec<Position>> = BTreeMap::new();
+        for position in positions {
+            by_condition
+                .entry(position.condition_id.clone())
+                .or_default()
+                .push(position);
+        }
+
+        let mut redeemable = HashSet::new();
+        let mut mergeable = HashSet::new();
+        for (condition_id, mut positions) in by_condition {
+            positions.sort_by_key(|p| p.outcome_index);
+
+            if positions.iter().any(|p| p.redeemable) {
+                redeemable.insert(condition_id.clone());
+                if !self.redeemable.contains(&condition_id) {
+                    events.push(PositionEvent::Redeemable {
+                        condition_id,
+                        positions: positions.into_iter().filter(|p| p.redeemable).collect(),
+                    });
+                }
+                continue;
+            }
+
+            let amount = positions
+                .iter()
+                .map(|p| p.size)
+                .fold(f64::INFINITY, f64::min);
+            if positions.len() > 1 && positions.iter().all(|p| p.mergeable) && amount > 0.0 {
+                mergeable.insert(condition_id.clone());
+                if !self.mergeable.contains(&condition_id) {
+                    events.push(PositionEvent::Mergeable {
+                        condition_id,
+                        positions,
+                        amount,
+                    });
+                }
+            }
+        }
+        self.redeemable = redeemable;
+        self.mergeable = mergeable;
+
+        events
+    }
+}
+
+impl Poller for PositionMonitor {
+    type Item = MonitorRecord;
+
+    fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    async fn tick(&mut self) -> Vec<Result<MonitorRecord, PolymarketError>> {
+        match self.scan().await {
+            Ok(records) => records.into_iter().map(Ok).collect(),
+            Err(err) => vec![Err(err)],
+        }
+    }
+}
+
+impl std::fmt::Debug for PositionMonitor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PositionMonitor")
+            .field("user", &self.user)
+            .field("interval", &self.interval)
+            .field("thresholds", &self.thresholds)
+            .field("action", &self.action.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "relay")]
+pub use relay_action::RelayAction;
+
+#[cfg(feature = "relay")]
+mod relay_action {
+    use alloy::primitives::U256;
+    use futures_util::future::BoxFuture;
+    use futures_util::FutureExt;
+    use polyoxide_data::types::Position;
+    use polyoxide_relay::RelayClient;
+
+    use super::{ActionOutcome, PositionAction, PositionEvent};
+
+    /// Base units per share of an outcome token (6 decimals)
+    const BASE_UNITS: f64 = 1_000_000.0;
+
+    /// [`PositionAction`] that submits gasless redemptions and merges through
+    /// the relayer for the monitored wallet.
+    ///
+    /// The relay client must be configured for the same wallet the monitor
+    /// scans. Price crossings are ignored.
+    #[derive(Debug, Clone)]
+    pub struct RelayAction {
+        relay: RelayClient,
+        redeem: bool,
+        merge: bool,
+    }
+
+    impl RelayAction {
+        /// Redeem resolved markets and merge full sets
+        pub fn new(relay: RelayClient) -> Self {
+            Self {
+                relay,
+                redeem: true,
+                merge: true,
+            }
+        }
+
+        /// Submit redemptions for redeemable markets (default: true)
+        pub fn redeem(mut self, redeem: bool) -> Self {
+            self.redeem = redeem;
+            self
+        }
+
+        /// Submit merges for mergeable markets (default: true)
+        pub fn merge(mut self, merge: bool) -> Self {
+            self.merge = merge;
+            self
+        }
+
+        async fn redeem_positions(
+            &self,
+            condition_id: &str,
+            positions: &[Position],
+        ) -> ActionOutcome {
+            let Ok(condition) = condition_id.parse() else {
+                return ActionOutcome::Skipped(format!("invalid condition ID {condition_id}"));
+            };
+            let neg_risk_amounts = positions.iter().any(|p| p.negative_risk).then(|| {
+                let mut amounts = vec![0u64; 2];
+                for position in positions {
+                    if let Some(slot) = amounts.get_mut(position.outcome_index as usize) {
+                        *slot = base_units(position.size);
+                    }
+                }
+                amounts.into_iter().map(U256::from).collect()
+            });
+            submitted(
+                self.relay
+                    .redeem_condition(condition, neg_risk_amounts)
+                    .await,
+            )
+        }
+
+        async fn merge_positions(
+            &self,
+            condition_id: &str,
+            positions: &[Position],
+            amount: f64,
+        ) -> ActionOutcome {
+            if positions.len() != 2 {
+                return ActionOutcome::Skipped("only binary markets can be merged".to_string());
+            }
+            let Ok(condition) = condition_id.parse() else {
+                return ActionOutcome::Skipped(format!("invalid condition ID {condition_id}"));
+            };
+            let units = base_units(amount);
+            if units == 0 {
+                return ActionOutcome::Skipped("nothing to merge".to_string());
+            }
+            let neg_risk = positions.iter().any(|p| p.negative_risk);
+            submitted(
+                self.relay
+                    .merge_condition(condition, U256::from(units), neg_risk)
+                    .await,
+            )
+        }
+    }
+
+    impl PositionAction for RelayAction {
+        fn act<'a>(&'a self, event: &'a PositionEvent) -> BoxFuture<'a, Option<ActionOutcome>> {
+            async move {
+                match event {
+                    PositionEvent::Redeemable {
+                        condition_id,
+                        positions,
+                    } if self.redeem => Some(self.redeem_positions(condition_id, positions).await),
+                    PositionEvent::Mergeable {
+                        condition_id,
+                        positions,
+                        amount,
+                    } if self.merge => {
+                        Some(self.merge_positions(condition_id, positions, *amount).await)
+                    }
+                    _ => None,
+                }
+            }
+            .boxed()
+        }
+    }
+
+    /// Shares to base units, rounded down so the amount never exceeds the balance
+    fn base_units(shares: f64) -> u64 {
+        (shares * BASE_UNITS).floor().max(0.0) as u64
+    }
+
+    fn submitted(
+        result: Result<polyoxide_relay::RelayerTransactionResponse, polyoxide_relay::RelayError>,
+    ) -> ActionOutcome {
+        match result {
+            Ok(response) => ActionOutcome::Submitted(response.transaction_id),
+            Err(err) => ActionOutcome::Failed(err.to_string()),
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn base_units_round_down() {
+            assert_eq!(base_units(1.5), 1_500_000);
+            assert_eq!(base_units(0.0000019), 1);
+            assert_eq!(base_units(-1.0), 0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position(
+        condition_id: &str,
+        outcome_index: u32,
+        size: f64,
+        cur_price: f64,
+        redeemable: bool,
+        mergeable: bool,
+    ) -> Position {
+        serde_json::from_value(serde_json::json!({
+            "proxyWallet": "0xwallet",
+            "asset": format!("{condition_id}-{outcome_index}"),
+            "conditionId": condition_id,
+            "size": size,
+            "avgPrice": 0.5,
+            "initialValue": size * 0.5,
+            "currentValue": size * cur_price,
+            "cashPnl": 0.0,
+            "percentPnl": 0.0,
+            "totalBought": size,
+            "realizedPnl": 0.0,
+            "percentRealizedPnl": 0.0,
+            "curPrice": cur_price,
+            "redeemable": redeemable,
+            "mergeable": mergeable,
+            "title": "Market",
+            "slug": "market",
+            "outcome": if outcome_index == 0 { "Yes" } else { "No" },
+            "outcomeIndex": outcome_index,
+            "oppositeOutcome": if outcome_index == 0 { "No" } else { "Yes" },
+            "oppositeAsset": format!("{condition_id}-{}", 1 - outcome_index),
+            "negativeRisk": false,
+        }))
+        .unwrap()
+    }
+
+    fn monitor() -> PositionMonitor {
+        PositionMonitor::new(DataApi::new().unwrap(), "0xwallet")
+    }
+
+    // ── Lifecycle ───────────────────────────────────────────────
+
+    #[test]
+    fn redeemable_reported_once() {
+        let mut monitor = monitor();
+        let positions = vec![position("0xa", 0, 10.0, 1.0, true, false)];
+
+        let events = monitor.detect(positions.clone());
+        assert!(matches!(
+            &events[..],
+            [PositionEvent::Redeemable { condition_id, positions }]
+                if condition_id == "0xa" && positions.len() == 1
+        ));
+        assert!(monitor.detect(positions).is_empty());
+    }
+
+    #[test]
+    fn mergeable_needs_every_outcome() {
+        let mut monitor = monitor();
+        assert!(monitor
+            .detect(vec![position("0xa", 0, 10.0, 0.5, false, true)])
+            .is_empty());
+
+        let events = monitor.detect(vec![
+            position("0xa", 1, 4.0, 0.5, false, true),
+            position("0xa", 0, 10.0, 0.5, false, true),
+        ]);
+        match &events[..] {
+            [PositionEvent::Mergeable {
+                positions, amount, ..
+            }] => {
+                assert_eq!(*amount, 4.0);
+                assert_eq!(positions[0].outcome_index, 0);
+            }
+            other => panic!("unexpected events {other:?}"),
+        }
+    }
+
+    #[test]
+    fn mergeable_fires_again_after_clearing() {
+        let mut monitor = monitor();
+        let both = || {
+            vec![
+                position("0xa", 0, 1.0, 0.5, false, true),
+                position("0xa", 1, 1.0, 0.5, false, true),
+            ]
+        };
+        assert_eq!(monitor.detect(both()).len(), 1);
+        assert!(monitor.detect(both()).is_empty());
+        assert!(monitor.detect(Vec::new()).is_empty());
+        assert_eq!(monitor.detect(both()).len(), 1);
+    }
+
+    // ── Actions ─────────────────────────────────────────────────
+
+    /// Fails the first `failures` calls, then submits
+    struct Flaky {
+        failures: std::sync::atomic::AtomicUsize,
+    }
+
+    impl PositionAction for Flaky {
+        fn act<'a>(&'a self, _event: &'a PositionEvent) -> BoxFuture<'a, Option<ActionOutcome>> {
+            use std::sync::atomic::Ordering;
+            let failed = self
+                .failures
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+                .is_ok();
+            Box::pin(async move {
+                Some(if failed {
+                    ActionOutcome::Failed("relayer down".to_string())
+                } else {
+                    ActionOutcome::Submitted("tx".to_string())
+                })
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn failed_action_is_retried_next_scan() {
+        let mut monitor = monitor().action(Flaky { failures: 1.into() });
+        let positions = || vec![position("0xa", 0, 10.0, 1.0, true, false)];
+
+        let records = monitor.handle(positions()).await;
+        assert_eq!(
+            records[0].action,
+            Some(ActionOutcome::Failed("relayer down".to_string()))
+        );
+
+        let records = monitor.handle(positions()).await;
+        assert_eq!(records.len(), 1);
+        assert_eq!(
+            records[0].action,
+            Some(ActionOutcome::Submitted("tx".to_string()))
+        );
+
+        assert!(monitor.handle(positions()).await.is_empty());
+    }
+
+    // ── Prices ──────────────────────────────────────────────────
+
+    #[test]
+    fn price_crossings_against_previous_scan() {
+        let mut monitor = monitor().price_threshold(0.9).price_threshold(0.2);
+
+        assert!(monitor
+            .detect(vec![position("0xa", 0, 1.0, 0.85, false, false)])
+            .is_empty());
+
+        let events = monitor.detect(vec![position("0xa", 0, 1.0, 0.92, false, false)]);
+        assert!(matches!(
+            &events[..],
+            [PositionEvent::PriceCrossed { threshold, previous, direction: Crossing::Above, .. }]
+                if *threshold == 0.9 && *previous == 0.85
+        ));
+
+        let events = monitor.detect(vec![position("0xa", 0, 1.0, 0.1, false, false)]);
+        let crossed: Vec<_> = events
+            .iter()
+            .map(|e| match e {
+                PositionEvent::PriceCrossed {
+                    threshold,
+                    direction,
+                    ..
+                } => (*threshold, *direction),
+                other => panic!("unexpected event {other:?}"),
+            })
+            .collect();
+        assert_eq!(
+            crossed,
+            vec![(0.9, Crossing::Below), (0.2, Crossing::Below)]
+        );
+    }
+}
@@ -0,0 +1,427 @@
+use std::collections::HashMap;
+
+use polyoxide_clob::{api::markets::OrderBook, Clob};
+use polyoxide_gamma::types::{Event, Market};
+use rust_decimal::Decimal;
+
+use crate::PolymarketError;
+
+/// Best bid and ask of one token, taken from its order book
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Quote {
+    pub bid: Option<Decimal>,
+    pub bid_size: Option<Decimal>,
+    pub ask: Option<Decimal>,
+    pub ask_size: Option<Decimal>,
+}
+
+impl Quote {
+    /// Top of book of `book`
+    pub fn from_book(book: &OrderBook) -> Self {
+        let bid = book.bids.iter().max_by_key(|l| l.price);
+        let ask = book.asks.iter().min_by_key(|l| l.price);
+        Self {
+            bid: bid.map(|l| l.price),
+            bid_size: bid.map(|l| l.size),
+            ask: ask.map(|l| l.price),
+            ask_size: ask.map(|l| l.size),
+        }
+    }
+
+    /// Midpoint of bid and ask, if both sides are quoted
+    pub fn mid(&self) -> Option<Decimal> {
+        Some((self.bid? + self.ask?) / Decimal::TWO)
+    }
+}
+
+/// One outcome of a multi-outcome event: a binary market with YES/NO tokens
+#[derive(Debug, Clone)]
+pub struct GroupOutcome {
+    /// Outcome label (`group_item_title`, falling back to the market question)
+    pub name: String,
+    pub yes_token_id: String,
+    pub no_token_id: String,
+    /// Top of the YES book, once fetched
+    pub yes: Quote,
+    /// Top of the NO book, once fetched
+    pub no: Quote,
+    pub market: Market,
+}
+
+impl GroupOutcome {
+    /// Implied probability of the outcome: YES midpoint, falling back to the
+    /// Gamma YES price when the book is one-sided or not fetched
+    pub fn implied_probability(&self) -> Option<Decimal> {
+        self.yes.mid().or_else(|| {
+            let index = yes_index(&self.market);
+            self.market.outcome_prices().get(index).copied()
+        })
+    }
+}
+
+/// Arbitrage gaps of an [`OutcomeGroup`] at the top of book.
+///
+/// Each value is `None` when any leg is missing a quote or the group is not
+/// [complete](OutcomeGroup::complete). Edges are per set
+/// (one share of every outcome) before fees; positive means profitable.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GroupArbitrage {
+    /// Cost of buying one YES share of every outcome
+    pub yes_ask_sum: Option<Decimal>,
+    /// Proceeds of selling one YES share of every outcome
+    pub yes_bid_sum: Option<Decimal>,
+    /// Cost of buying one NO share of every outcome
+    pub no_ask_sum: Option<Decimal>,
+    /// `1 - yes_ask_sum`: exactly one YES pays out, so a full YES set is worth 1
+    pub buy_all_yes: Option<Decimal>,
+    /// `yes_bid_sum - 1`: selling a full YES set against the 1 it will pay out
+    pub sell_all_yes: Option<Decimal>,
+    /// `(n - 1) - no_ask_sum`: a full NO set converts into `n - 1` collateral
+    pub buy_all_no: Option<Decimal>,
+    /// Sets executable at the quoted YES asks (smallest top-of-book size)
+    pub yes_sets_available: Option<Decimal>,
+    /// Sets executable at the quoted NO asks (smallest top-of-book size)
+    pub no_sets_available: Option<Decimal>,
+}
+
+/// A neg-risk event as a group of mutually exclusive outcomes.
+///
+/// Built from a Gamma [`Event`]; each open market becomes a [`GroupOutcome`]
+/// with its YES/NO token IDs. Markets that resolved NO are dropped; any other
+/// closed or archived market leaves the group incomplete. Call
+/// [`OutcomeGroup::fetch_books`] to load all order books in a single CLOB
+/// request, then inspect [`OutcomeGroup::implied_probability_sum`] and
+/// [`OutcomeGroup::arbitrage`].
+///
+/// # Example
+///
+/// ```no_run
+/// use polyoxide::{polyoxide_clob::Clob, polyoxide_gamma::Gamma, OutcomeGroup};
+///
+/// # async fn example() -> Result<(), polyoxide::PolymarketError> {
+/// let gamma = Gamma::new()?;
+/// let event = gamma.events().get_by_slug("presidential-election-winner-2028").send().await?;
+///
+/// let mut group = OutcomeGroup::from_event(event)?;
+/// group.fetch_books(&Clob::public()).await?;
+///
+/// let arb = group.arbitrage();
+/// if let Some(edge) = arb.buy_all_yes.filter(|e| e.is_sign_positive()) {
+///     println!("buy every YES for an edge of {edge} per set");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct OutcomeGroup {
+    pub event_id: String,
+    pub event_slug: Option<String>,
+    pub title: Option<String>,
+    pub neg_risk_market_id: Option<String>,
+    pub outcomes: Vec<GroupOutcome>,
+    /// Whether `outcomes` covers every way the event can still resolve; false
+    /// when a skipped market is unresolved or resolved YES
+    pub complete: bool,
+}
+
+impl OutcomeGroup {
+    /// Build the group from an event's open markets.
+    ///
+    /// Fails if the event is not neg-risk (its outcomes are then not mutually
+    /// exclusive) or a market does not expose both of its CLOB token IDs.
+    pub fn from_event(event: Event) -> Result<Self, PolymarketError> {
+        if event.neg_risk != Some(true) {
+            return Err(PolymarketError::InvalidMarketId(format!(
+                "event {} is not a neg-risk event",
+                event.id
+            )));
+        }
+
+        let mut complete = true;
+        let mut outcomes = Vec::new();
+        for market in event.markets {
+            if market.closed != Some(true) && market.archived != Some(true) {
+                outcomes.push(GroupOutcome::try_from(market)?);
+            } else if !resolved_no(&market) {
+                complete = false;
+            }
+        }
+
+        Ok(Self {
+            event_id: event.id,
+            event_slug: event.slug,
+            title: event.title,
+            neg_risk_market_id: event.neg_risk_market_id,
+            outcomes,
+            complete,
+        })
+    }
+
+    /// All YES and NO token IDs of the group
+    pub fn token_ids(&self) -> Vec<&str> {
+        self.outcomes
+            .iter()
+            .flat_map(|o| [o.yes_token_id.as_str(), o.no_token_id.as_str()])
+            .collect()
+    }
+
+    /// Outcome by name (case-insensitive)
+    pub fn outcome(&self, name: &str) -> Option<&GroupOutcome> {
+        self.outcomes
+            .iter()
+            .find(|o| o.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Fetch the order books of every YES and NO token in one request
+    pub async fn fetch_books(&mut self, clob: &Clob) -> Result<(), PolymarketError> {
+        if self.outcomes.is_empty() {
+            return Ok(());
+        }
+        let books = clob.markets().order_books(self.token_ids()).send().await?;
+        self.apply_books(&books.into_inner().into_values().collect::<Vec<_>>());
+        Ok(())
+    }
+
+    /// Update quotes from already-fetched order books (e.g. from the websocket)
+    pub fn apply_books(&mut self, books: &[OrderBook]) {
+        let quotes: HashMap<&str, Quote> = books
+            .iter()
+            .map(|b| (b.asset_id.as_str(), Quote::from_book(b)))
+            .collect();
+        for outcome in &mut self.outcomes {
+            if let Some(quote) = quotes.get(outcome.yes_token_id.as_str()) {
+                outcome.yes = *quote;
+            }
+            if let Some(quote) = quotes.get(outcome.no_token_id.as_str()) {
+                outcome.no = *quote;
+            }
+        }
+    }
+
+    /// Sum of the outcomes' implied probabilities; 1.0 for a fairly priced group
+    pub fn implied_probability_sum(&self) -> Option<Decimal> {
+        self.outcomes
+            .iter()
+            .map(GroupOutcome::implied_probability)
+            .sum()
+    }
+
+    /// Arbitrage gaps at the current top of book
+    pub fn arbitrage(&self) -> GroupArbitrage {
+        if self.outcomes.is_empty() || !self.complete {
+            return GroupArbitrage::default();
+        }
+        let n = Decimal::from(self.outcomes.len());
+        let sum = |f: fn(&GroupOutcome) -> Option<Decimal>| -> Option<Decimal> {
+            self.outcomes.iter().map(f).sum()
+        };
+        let min = |f: fn(&GroupOutcome) -> Option<Decimal>| -> Option<Decimal> {
+            self.outcomes
+                .iter()
+                .map(f)
+                .collect::<Option<Vec<_>>>()?
+                .into_iter()
+                .min()
+        };
+
+        let yes_ask_sum = sum(|o| o.yes.ask);
+        let yes_bid_sum = sum(|o| o.yes.bid);
+        let no_ask_sum = sum(|o| o.no.ask);
+
+        GroupArbitrage {
+            yes_ask_sum,
+            yes_bid_sum,
+            no_ask_sum,
+            buy_all_yes: yes_ask_sum.map(|s| Decimal::ONE - s),
+            sell_all_yes: yes_bid_sum.map(|s| s - Decimal::ONE),
+            buy_all_no: no_ask_sum.map(|s| n - Decimal::ONE - s),
+            yes_sets_available: min(|o| o.yes.ask_size),
+            no_sets_available: min(|o| o.no.ask_size),
+        }
+    }
+}
+
+impl TryFrom<Market> for GroupOutcome {
+    type Error = PolymarketError;
+
+    fn try_from(market: Market) -> Result<Self, Self::Error> {
+        let token_ids = market.clob_token_ids();
+        let yes = yes_index(&market);
+        let (Some(yes_token_id), Some(no_token_id)) =
+            (token_ids.get(yes).cloned(), token_ids.get(1 - yes).cloned())
+        else {
+            return Err(PolymarketError::InvalidMarketId(format!(
+                "market {} has no YES/NO token pair",
+                market.id
+            )));
+        };
+
+        let name = market
+            .group_item_title
+            .clone()
+            .filter(|t| !t.is_empty())
+            .unwrap_or_else(|| market.question.clone());
+
+        Ok(Self {
+            name,
+            yes_token_id,
+            no_token_id,
+            yes: Quote::default(),
+            no: Quote::default(),
+            market,
+        })
+    }
+}
+
+/// Whether a market settled with NO winning, so it can no longer pay out YES
+fn resolved_no(market: &Market) -> bool {
+    market
+        .resolution_state()
+        .winner()
+        .is_some_and(|w| w.name.eq_ignore_ascii_case("no"))
+}
+
+/// Index of the YES outcome; Gamma lists `["Yes", "No"]` but don't rely on it
+fn yes_index(market: &Market) -> usize {
+    market
+        .outcome_names()
+        .iter()
+        .position(|n| n.eq_ignore_ascii_case("yes"))
+        .filter(|&i| i < 2)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn market(id: &str, title: &str, yes: &str, no: &str, price: &str) -> serde_json::Value {
+        serde_json::json!({
+            "id": id,
+            "conditionId": format!("0x{id}"),
+            "description": "",
+            "question": format!("Will {title} win?"),
+            "groupItemTitle": title,
+            "marketMakerAddress": "",
+            "outcomes": "[\"Yes\", \"No\"]",
+            "outcomePrices": format!("[\"{price}\", \"0\"]"),
+            "clobTokenIds": format!("[\"{yes}\", \"{no}\"]"),
+        })
+    }
+
+    fn event() -> Event {
+        serde_json::from_value(serde_json::json!({
+            "id": "1",
+            "slug": "winner",
+            "negRisk": true,
+            "negRiskMarketID": "0xneg",
+            "markets": [
+                market("10", "Alice", "y1", "n1", "0.5"),
+                market("11", "Bob", "y2", "n2", "0.3"),
+                market("12", "Carol", "y3", "n3", "0.2"),
+                {
+                    "id": "13", "conditionId": "0x13", "description": "", "question": "Dave?",
+                    "marketMakerAddress": "", "closed": true,
+                    "outcomes": "[\"Yes\", \"No\"]", "outcomePrices": "[\"0\", \"1\"]"
+                }
+            ]
+        }))
+        .unwrap()
+    }
+
+    fn book(asset: &str, bid: &str, ask: &str, ask_size: &str) -> OrderBook {
+        serde_json::from_value(serde_json::json!({
+            "market": "0x",
+            "asset_id": asset,
+            "bids": [{"price": "0.01", "size": "1000"}, {"price": bid, "size": "100"}],
+            "asks": [{"price": "0.99", "size": "1000"}, {"price": ask, "size": ask_size}],
+            "timestamp": "0",
+            "hash": "",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_group_from_event() {
+        let group = OutcomeGroup::from_event(event()).unwrap();
+        assert!(group.complete);
+        assert_eq!(group.neg_risk_market_id.as_deref(), Some("0xneg"));
+        assert_eq!(group.outcomes.len(), 3, "market resolved NO is skipped");
+        let bob = group.outcome("bob").unwrap();
+        assert_eq!(bob.yes_token_id, "y2");
+        assert_eq!(bob.no_token_id, "n2");
+        assert_eq!(group.token_ids(), vec!["y1", "n1", "y2", "n2", "y3", "n3"]);
+    }
+
+    #[test]
+    fn test_group_rejects_market_without_tokens() {
+        let mut event = event();
+        event.markets[0].clob_token_ids = None;
+        assert!(matches!(
+            OutcomeGroup::from_event(event),
+            Err(PolymarketError::InvalidMarketId(_))
+        ));
+    }
+
+    #[test]
+    fn test_group_rejects_non_neg_risk_event() {
+        let mut event = event();
+        event.neg_risk = Some(false);
+        assert!(matches!(
+            OutcomeGroup::from_event(event),
+            Err(PolymarketError::InvalidMarketId(_))
+        ));
+        let mut event = self::event();
+        event.neg_risk = None;
+        assert!(OutcomeGroup::from_event(event).is_err());
+    }
+
+    #[test]
+    fn test_group_with_unresolved_closed_market_reports_no_arbitrage() {
+        let mut event = event();
+        event.markets[3].outcome_prices = Some("[\"0.4\", \"0.6\"]".into());
+        let mut group = OutcomeGroup::from_event(event).unwrap();
+        assert!(!group.complete);
+        assert_eq!(group.outcomes.len(), 3);
+
+        group.apply_books(&[
+            book("y1", "0.48", "0.50", "40"),
+            book("y2", "0.28", "0.30", "25"),
+            book("y3", "0.16", "0.18", "60"),
+        ]);
+        assert_eq!(group.arbitrage(), GroupArbitrage::default());
+    }
+
+    #[test]
+    fn test_implied_probability_falls_back_to_gamma_prices() {
+        let group = OutcomeGroup::from_event(event()).unwrap();
+        assert_eq!(group.implied_probability_sum(), Some(Decimal::ONE));
+        assert_eq!(group.arbitrage().yes_ask_sum, None);
+    }
+
+    #[test]
+    fn test_arbitrage_from_books() {
+        let mut group = OutcomeGroup::from_event(event()).unwrap();
+        group.apply_books(&[
+            book("y1", "0.48", "0.50", "40"),
+            book("y2", "0.28", "0.30", "25"),
+            book("y3", "0.16", "0.18", "60"),
+            book("n1", "0.49", "0.51", "10"),
+            book("n2", "0.69", "0.71", "10"),
+            book("n3", "0.80", "0.82", "5"),
+        ]);
+
+        let d = |s: &str| s.parse::<Decimal>().unwrap();
+        assert_eq!(group.outcomes[0].yes.mid(), Some(d("0.49")));
+        assert_eq!(group.implied_probability_sum(), Some(d("0.95")));
+
+        let arb = group.arbitrage();
+        assert_eq!(arb.yes_ask_sum, Some(d("0.98")));
+        assert_eq!(arb.buy_all_yes, Some(d("0.02")));
+        assert_eq!(arb.sell_all_yes, Some(d("-0.08")));
+        assert_eq!(arb.no_ask_sum, Some(d("2.04")));
+        assert_eq!(arb.buy_all_no, Some(d("-0.04")));
+        assert_eq!(arb.yes_sets_available, Some(d("25")));
+        assert_eq!(arb.no_sets_available, Some(d("5")));
+    }
+}
@@ -0,0 +1,131 @@
+use std::collections::VecDeque;
+use std::future::Future;
+use std::time::Duration;
+
+use futures_util::stream::{self, BoxStream, StreamExt};
+
+use crate::PolymarketError;
+
+/// A service that is polled on a fixed interval
+pub(crate) trait Poller: Send + 'static {
+    /// Item produced by a poll
+    type Item: Send + 'static;
+
+    /// Delay between polls
+    fn interval(&self) -> Duration;
+
+    /// Poll once, returning new items and errors oldest first
+    fn tick(&mut self) -> impl Future<Output = Vec<Result<Self::Item, PolymarketError>>> + Send;
+}
+
+/// Poll `poller` forever and stream its items.
+///
+/// The first poll runs immediately. Errors are yielded as `Err` items and
+/// polling continues on the next tick.
+pub(crate) fn poll_stream<P: Poller>(
+    poller: P,
+) -> BoxStream<'static, Result<P::Item, PolymarketError>> {
+    let state = (poller, VecDeque::new(), false);
+
+    stream::unfold(state, |(mut poller, mut pending, mut polled)| async move {
+        loop {
+            if let Some(item) = pending.pop_front() {
+                return Some((item, (poller, pending, polled)));
+            }
+            if polled {
+                tokio::time::sleep(poller.interval()).await;
+            }
+            pending.extend(poller.tick().await);
+            polled = true;
+        }
+    })
+    .boxed()
+}
+
+/// High-water mark of a feed listed newest first
+#[cfg(any(feature = "clob", feature = "gamma"))]
+#[derive(Debug, Default)]
+pub(crate) struct FeedCursor {
+    /// Timestamp of the newest item seen
+    pub(crate) timestamp: i64,
+    /// Items seen at `timestamp`, which the next fetch returns again
+    seen: std::collections::HashSet<String>,
+}
+
+#[cfg(any(feature = "clob", feature = "gamma"))]
+impl FeedCursor {
+    /// Record `items` and return those not seen before, oldest first
+    pub(crate) fn advance<T>(
+        &mut self,
+        mut items: Vec<T>,
+        timestamp: impl Fn(&T) -> i64,
+        key: impl Fn(&T) -> String,
+    ) -> Vec<T> {
+        items.sort_by_key(&timestamp);
+
+        let mut fresh = Vec::new();
+        for item in items {
+            let ts = timestamp(&item);
+            if ts < self.timestamp {
+                continue;
+            }
+            if ts > self.timestamp {
+                self.timestamp = ts;
+                self.seen.clear();
+            }
+            if self.seen.insert(key(&item)) {
+                fresh.push(item);
+            }
+        }
+        fresh
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Counter {
+        polls: u32,
+    }
+
+    impl Poller for Counter {
+        type Item = u32;
+
+        fn interval(&self) -> Duration {
+            Duration::from_millis(1)
+        }
+
+        async fn tick(&mut self) -> Vec<Result<u32, PolymarketError>> {
+            self.polls += 1;
+            match self.polls {
+                1 => vec![Ok(1), Ok(2)],
+                2 => vec![Err(PolymarketError::Config("down".into()))],
+                n => vec![Ok(n)],
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn stream_yields_items_and_errors_in_order() {
+        let items: Vec<_> = poll_stream(Counter { polls: 0 }).take(4).collect().await;
+        assert!(matches!(items[..2], [Ok(1), Ok(2)]));
+        assert!(items[2].is_err());
+        assert!(matches!(items[3], Ok(3)));
+    }
+
+    #[cfg(any(feature = "clob", feature = "gamma"))]
+    #[test]
+    fn cursor_returns_unseen_items_oldest_first() {
+        let mut cursor = FeedCursor::default();
+        let key = |item: &(i64, &str)| item.1.to_string();
+        let fresh = cursor.advance(vec![(2, "b"), (1, "a")], |i| i.0, key);
+        assert_eq!(fresh, vec![(1, "a"), (2, "b")]);
+
+        // The boundary item comes back on the next fetch
+        let fresh = cursor.advance(vec![(3, "d"), (2, "c"), (2, "b"), (1, "a")], |i| i.0, key);
+        assert_eq!(fresh, vec![(2, "c"), (3, "d")]);
+        assert_eq!(cursor.timestamp, 3);
+        assert!(cursor.advance(vec![(1, "z")], |i| i.0, key).is_empty());
+    }
+}
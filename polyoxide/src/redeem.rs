@@ -0,0 +1,168 @@
+use std::collections::HashMap;
+
+use polyoxide_data::types::Position;
+use polyoxide_gamma::types::{Market, ResolutionState};
+use rust_decimal::prelude::ToPrimitive;
+
+/// A position in a market that has resolved (or been voided) and can be
+/// redeemed for collateral
+#[derive(Debug, Clone)]
+pub struct RedeemablePosition {
+    /// The position, as reported by the Data API
+    pub position: Position,
+    /// The market the position belongs to
+    pub market: Market,
+    /// Final resolution state of the market ([`ResolutionState::Resolved`] or [`ResolutionState::Voided`])
+    pub state: ResolutionState,
+    /// Final value of one share of the position's outcome
+    pub payout_per_share: f64,
+    /// Collateral received on redemption (`size * payout_per_share`)
+    pub payout: f64,
+}
+
+impl RedeemablePosition {
+    /// Whether the position holds the winning outcome
+    pub fn is_winner(&self) -> bool {
+        self.state
+            .winner()
+            .and_then(|w| w.token_id.as_deref())
+            .map_or(self.payout_per_share >= 1.0, |token| {
+                token == self.position.asset
+            })
+    }
+}
+
+/// Match positions against Gamma markets by condition ID and keep those whose
+/// market has reached a final resolution.
+///
+/// Losing positions are included with a zero payout, since redeeming them
+/// still clears them from the wallet. Positions whose market is missing from
+/// `markets` or still unresolved are skipped.
+pub fn redeemable_positions(
+    positions: impl IntoIterator<Item = Position>,
+    markets: &[Market],
+) -> Vec<RedeemablePosition> {
+    let by_condition: HashMap<String, &Market> = markets
+        .iter()
+        .map(|m| (m.condition_id.to_ascii_lowercase(), m))
+        .collect();
+
+    positions
+        .into_iter()
+        .filter_map(|position| {
+            let market = by_condition.get(&position.condition_id.to_ascii_lowercase())?;
+            let state = market.resolution_state();
+            if !state.is_final() {
+                return None;
+            }
+            let payout_per_share = market
+                .payout(position.outcome_index as usize)?
+                .to_f64()
+                .unwrap_or_default();
+            Some(RedeemablePosition {
+                payout: position.size * payout_per_share,
+                payout_per_share,
+                state,
+                market: (*market).clone(),
+                position,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position(condition_id: &str, asset: &str, outcome_index: u32, size: f64) -> Position {
+        serde_json::from_value(serde_json::json!({
+            "proxyWallet": "0xwallet",
+            "asset": asset,
+            "conditionId": condition_id,
+            "size": size,
+            "avgPrice": 0.4,
+            "initialValue": 0.0,
+            "currentValue": 0.0,
+            "cashPnl": 0.0,
+            "percentPnl": 0.0,
+            "totalBought": 0.0,
+            "realizedPnl": 0.0,
+            "percentRealizedPnl": 0.0,
+            "curPrice": 0.0,
+            "redeemable": true,
+            "mergeable": false,
+            "title": "Test?",
+            "slug": "test",
+            "outcome": if outcome_index == 0 { "Yes" } else { "No" },
+            "outcomeIndex": outcome_index,
+            "oppositeOutcome": "",
+            "oppositeAsset": "",
+            "negativeRisk": false,
+        }))
+        .unwrap()
+    }
+
+    fn market(condition_id: &str, extra: serde_json::Value) -> Market {
+        let mut json = serde_json::json!({
+            "id": "1",
+            "conditionId": condition_id,
+            "description": "",
+            "question": "Test?",
+            "marketMakerAddress": "",
+            "outcomes": "[\"Yes\", \"No\"]",
+            "clobTokenIds": "[\"111\", \"222\"]",
+        });
+        json.as_object_mut()
+            .unwrap()
+            .extend(extra.as_object().unwrap().clone());
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn test_resolved_market_positions() {
+        let markets = vec![market(
+            "0xABC",
+            serde_json::json!({ "closed": true, "outcomePrices": "[\"1\", \"0\"]" }),
+        )];
+        let positions = vec![
+            position("0xabc", "111", 0, 10.0),
+            position("0xabc", "222", 1, 5.0),
+        ];
+
+        let redeemable = redeemable_positions(positions, &markets);
+        assert_eq!(redeemable.len(), 2);
+        assert!(redeemable[0].is_winner());
+        assert_eq!(redeemable[0].payout, 10.0);
+        assert!(!redeemable[1].is_winner());
+        assert_eq!(redeemable[1].payout, 0.0);
+    }
+
+    #[test]
+    fn test_voided_market_pays_split() {
+        let markets = vec![market(
+            "0xabc",
+            serde_json::json!({
+                "closed": true,
+                "outcomePrices": "[\"0.5\", \"0.5\"]",
+                "umaResolutionStatus": "resolved",
+            }),
+        )];
+        let redeemable = redeemable_positions(vec![position("0xabc", "111", 0, 8.0)], &markets);
+        assert_eq!(redeemable[0].state, ResolutionState::Voided);
+        assert_eq!(redeemable[0].payout, 4.0);
+        assert!(!redeemable[0].is_winner());
+    }
+
+    #[test]
+    fn test_unresolved_and_unknown_markets_skipped() {
+        let markets = vec![market(
+            "0xabc",
+            serde_json::json!({ "umaResolutionStatus": "proposed" }),
+        )];
+        let positions = vec![
+            position("0xabc", "111", 0, 1.0),
+            position("0xdef", "333", 0, 1.0),
+        ];
+        assert!(redeemable_positions(positions, &markets).is_empty());
+    }
+}
@@ -0,0 +1,575 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::{Arc, RwLock};
+
+use polyoxide_clob::Clob;
+use polyoxide_gamma::types::{Event, Market, Outcome};
+use polyoxide_gamma::Gamma;
+use rust_decimal::Decimal;
+
+use crate::PolymarketError;
+
+/// Any identifier that points at a single Polymarket market.
+///
+/// Parse one from a string with [`MarketRef::parse`] (or [`str::parse`]):
+///
+/// | Input | Variant |
+/// |---|---|
+/// | `0x` + 64 hex chars | [`MarketRef::ConditionId`] |
+/// | long decimal number (CLOB / Data API `asset`) | [`MarketRef::TokenId`] |
+/// | short decimal number | [`MarketRef::GammaId`] |
+/// | `https://polymarket.com/event/<event>/<market>` or `/market/<market>` | [`MarketRef::Slug`] |
+/// | `https://polymarket.com/event/<event>` | [`MarketRef::EventSlug`] |
+/// | anything else | [`MarketRef::Slug`] |
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MarketRef {
+    /// Gamma market ID (`Market.id`)
+    GammaId(String),
+    /// CTF condition ID (`0x`-prefixed, lowercase)
+    ConditionId(String),
+    /// CLOB token ID of one of the market's outcomes
+    TokenId(String),
+    /// Gamma market slug
+    Slug(String),
+    /// Slug of an event containing exactly one market
+    EventSlug(String),
+}
+
+/// Token IDs are 256-bit integers; Gamma market IDs are short database keys
+const MAX_GAMMA_ID_LEN: usize = 18;
+
+impl MarketRef {
+    /// Classify an identifier or polymarket.com URL
+    pub fn parse(input: &str) -> Result<Self, PolymarketError> {
+        let input = input.trim();
+        if input.is_empty() {
+            return Err(PolymarketError::InvalidMarketId(
+                "empty identifier".to_string(),
+            ));
+        }
+
+        if let Some(path) = polymarket_url_path(input) {
+            return Self::from_url_path(path)
+                .ok_or_else(|| PolymarketError::InvalidMarketId(input.to_string()));
+        }
+
+        if let Some(hex) = input
+            .strip_prefix("0x")
+            .or_else(|| input.strip_prefix("0X"))
+        {
+            if hex.len() == 64 && hex.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Ok(Self::ConditionId(format!("0x{}", hex.to_ascii_lowercase())));
+            }
+            return Err(PolymarketError::InvalidMarketId(input.to_string()));
+        }
+
+        if input.chars().all(|c| c.is_ascii_digit()) {
+            return Ok(if input.len() > MAX_GAMMA_ID_LEN {
+                Self::TokenId(input.to_string())
+            } else {
+                Self::GammaId(input.to_string())
+            });
+        }
+
+        Ok(Self::Slug(input.to_string()))
+    }
+
+    fn from_url_path(path: &str) -> Option<Self> {
+        let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+        // Skip any locale prefix such as `/es/event/...`
+        let pos = segments
+            .iter()
+            .position(|s| *s == "event" || *s == "market")?;
+
+        match (segments[pos], segments.get(pos + 1), segments.get(pos + 2)) {
+            ("event", Some(_), Some(market)) | ("market", Some(market), _) => {
+                Some(Self::Slug(market.to_string()))
+            }
+            ("event", Some(event), None) => Some(Self::EventSlug(event.to_string())),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for MarketRef {
+    type Err = PolymarketError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Path of a polymarket.com URL, without query string or fragment
+fn polymarket_url_path(input: &str) -> Option<&str> {
+    let rest = input
+        .strip_prefix("https://")
+        .or_else(|| input.strip_prefix("http://"))
+        .unwrap_or(input);
+    let rest = rest.strip_prefix("www.").unwrap_or(rest);
+    let rest = rest.strip_prefix("polymarket.com")?;
+    if !(rest.is_empty() || rest.starts_with('/')) {
+        return None;
+    }
+    let end = rest.find(['?', '#']).unwrap_or(rest.len());
+    Some(&rest[..end])
+}
+
+/// A market resolved across Gamma and the CLOB
+#[derive(Debug, Clone)]
+pub struct ResolvedMarket {
+    /// Gamma market ID
+    pub id: String,
+    /// CTF condition ID
+    pub condition_id: String,
+    /// UMA question ID
+    pub question_id: Option<String>,
+    /// Gamma market slug
+    pub slug: Option<String>,
+    /// Market question
+    pub question: String,
+    /// Parent event, if Gamma reports one
+    pub event: Option<Event>,
+    /// Outcomes with their prices and CLOB token IDs
+    pub outcomes: Vec<Outcome>,
+    /// Minimum price increment accepted by the CLOB
+    pub tick_size: Decimal,
+    /// Minimum order size accepted by the CLOB
+    pub min_order_size: Decimal,
+    /// Whether the market settles through the neg-risk adapter
+    pub neg_risk: bool,
+    /// Neg-risk market ID shared by the event's markets
+    pub neg_risk_market_id: Option<String>,
+    /// Whether the market is accepting trading
+    pub active: bool,
+    /// Whether the market is closed
+    pub closed: bool,
+    /// Full Gamma market record
+    pub market: Market,
+}
+
+impl ResolvedMarket {
+    /// CLOB token IDs of every outcome, in outcome order
+    pub fn token_ids(&self) -> Vec<&str> {
+        self.outcomes
+            .iter()
+            .filter_map(|o| o.token_id.as_deref())
+            .collect()
+    }
+
+    /// CLOB token ID of the outcome with the given name (case-insensitive)
+    pub fn token_id(&self, outcome: &str) -> Option<&str> {
+        self.outcomes
+            .iter()
+            .find(|o| o.name.eq_ignore_ascii_case(outcome))
+            .and_then(|o| o.token_id.as_deref())
+    }
+
+    /// Every identifier that resolves to this market
+    fn refs(&self) -> Vec<MarketRef> {
+        let mut refs = vec![
+            MarketRef::GammaId(self.id.clone()),
+            MarketRef::ConditionId(self.condition_id.to_ascii_lowercase()),
+        ];
+        refs.extend(self.slug.clone().map(MarketRef::Slug));
+        refs.extend(
+            self.token_ids()
+                .into_iter()
+                .map(|id| MarketRef::TokenId(id.to_string())),
+        );
+        refs
+    }
+}
+
+/// Resolves any market identifier to a [`ResolvedMarket`], caching the result.
+///
+/// Lookups go to Gamma for the market and its event, then to the CLOB for
+/// trading parameters. Every identifier of a resolved market is cached, so a
+/// later lookup by slug, condition ID, token ID or URL is served locally.
+/// Clones share the same cache.
+///
+/// # Example
+///
+/// ```no_run
+/// use polyoxide::MarketResolver;
+///
+/// # async fn example() -> Result<(), polyoxide::PolymarketError> {
+/// let resolver = MarketResolver::public()?;
+/// let market = resolver
+///     .resolve("https://polymarket.com/event/fed-decision-in-march/fed-rate-cut-in-march")
+///     .await?;
+/// println!("{} tick={} tokens={:?}", market.question, market.tick_size, market.token_ids());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct MarketResolver {
+    gamma: Gamma,
+    clob: Clob,
+    cache: Arc<ResolverCache>,
+}
+
+impl MarketResolver {
+    /// Create a resolver from existing clients
+    pub fn new(gamma: Gamma, clob: Clob) -> Self {
+        Self {
+            gamma,
+            clob,
+            cache: Arc::default(),
+        }
+    }
+
+    /// Create a resolver using default public Gamma and CLOB clients
+    pub fn public() -> Result<Self, PolymarketError> {
+        Ok(Self::new(Gamma::new()?, Clob::public()))
+    }
+
+    /// Resolve an identifier or polymarket.com URL, using the cache when possible
+    pub async fn resolve(&self, id: &str) -> Result<Arc<ResolvedMarket>, PolymarketError> {
+        self.resolve_ref(&MarketRef::parse(id)?).await
+    }
+
+    /// Resolve a parsed [`MarketRef`], using the cache when possible
+    pub async fn resolve_ref(
+        &self,
+        market_ref: &MarketRef,
+    ) -> Result<Arc<ResolvedMarket>, PolymarketError> {
+        if let Some(market) = self.cache.get(market_ref) {
+            return Ok(market);
+        }
+        self.refresh_ref(market_ref).await
+    }
+
+    /// Resolve an identifier, bypassing and then updating the cache
+    pub async fn refresh(&self, id: &str) -> Result<Arc<ResolvedMarket>, PolymarketError> {
+        self.refresh_ref(&MarketRef::parse(id)?).await
+    }
+
+    /// Drop every cached market
+    pub fn clear_cache(&self) {
+        self.cache.clear();
+    }
+
+    async fn refresh_ref(
+        &self,
+        market_ref: &MarketRef,
+    ) -> Result<Arc<ResolvedMarket>, PolymarketError> {
+        let (market, event) = self.fetch_gamma(market_ref).await?;
+        let clob_market = self.clob.markets().get(&market.condition_id).send().await?;
+
+        let event = event.or_else(|| market.events.first().cloned());
+        let resolved = Arc::new(ResolvedMarket {
+            id: market.id.clone(),
+            condition_id: market.condition_id.clone(),
+            question_id: market.question_id.clone(),
+            slug: market.slug.clone(),
+            question: market.question.clone(),
+            event,
+            outcomes: market.outcomes(),
+            tick_size: decimal_from_f64(clob_market.minimum_tick_size)?,
+            min_order_size: decimal_from_f64(clob_market.minimum_order_size)?,
+            neg_risk: clob_market.neg_risk.unwrap_or(false),
+            neg_risk_market_id: clob_market
+                .neg_risk_market_id
+                .or_else(|| market.neg_risk_market_id.clone()),
+            active: clob_market.active,
+            closed: clob_market.closed,
+            market,
+        });
+
+        self.cache.insert(market_ref, resolved.clone());
+        Ok(resolved)
+    }
+
+    /// Fetch the Gamma market, and its event when the lookup went through one
+    async fn fetch_gamma(
+        &self,
+        market_ref: &MarketRef,
+    ) -> Result<(Market, Option<Event>), PolymarketError> {
+        let markets = self.gamma.markets();
+        let not_found = || PolymarketError::MarketNotFound(format!("{:?}", market_ref));
+
+        let market = match market_ref {
+            MarketRef::GammaId(id) => markets.get(id).send().await?,
+            MarketRef::Slug(slug) => markets.get_by_slug(slug).send().await?,
+            MarketRef::ConditionId(id) => markets
+                .list()
+                .condition_ids([id])
+                .limit(1)
+                .send()
+                .await?
+                .into_iter()
+                .next()
+                .ok_or_else(not_found)?,
+            MarketRef::TokenId(id) => markets
+                .list()
+                .clob_token_ids([id])
+                .limit(1)
+                .send()
+                .await?
+                .into_iter()
+                .next()
+                .ok_or_else(not_found)?,
+            MarketRef::EventSlug(slug) => {
+                let mut event = self.gamma.events().get_by_slug(slug).send().await?;
+                if event.markets.len() != 1 {
+                    return Err(PolymarketError::InvalidMarketId(format!(
+                        "event '{}' has {} markets; use a market slug or URL",
+                        slug,
+                        event.markets.len()
+                    )));
+                }
+                let market = event.markets.remove(0);
+                return Ok((market, Some(event)));
+            }
+        };
+        Ok((market, None))
+    }
+}
+
+impl std::fmt::Debug for MarketResolver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MarketResolver")
+            .field("cache", &self.cache)
+            .finish_non_exhaustive()
+    }
+}
+
+fn decimal_from_f64(value: f64) -> Result<Decimal, PolymarketError> {
+    Decimal::try_from(value)
+        .map(|d| d.normalize())
+        .map_err(|e| PolymarketError::Config(format!("Invalid CLOB market parameter {value}: {e}")))
+}
+
+/// Identifier aliases and resolved markets, keyed by condition ID
+#[derive(Default)]
+struct ResolverCache {
+    inner: RwLock<CacheInner>,
+}
+
+#[derive(Default)]
+struct CacheInner {
+    aliases: HashMap<MarketRef, String>,
+    markets: HashMap<String, Arc<ResolvedMarket>>,
+}
+
+impl ResolverCache {
+    fn get(&self, market_ref: &MarketRef) -> Option<Arc<ResolvedMarket>> {
+        let inner = self.inner.read().unwrap_or_else(|e| e.into_inner());
+        let condition_id = inner.aliases.get(market_ref)?;
+        inner.markets.get(condition_id).cloned()
+    }
+
+    /// Cache a market under all of its identifiers plus the one used to look it up
+    fn insert(&self, market_ref: &MarketRef, market: Arc<ResolvedMarket>) {
+        let mut inner = self.inner.write().unwrap_or_else(|e| e.into_inner());
+        let condition_id = market.condition_id.to_ascii_lowercase();
+        for alias in market
+            .refs()
+            .into_iter()
+            .chain(std::iter::once(market_ref.clone()))
+        {
+            inner.aliases.insert(alias, condition_id.clone());
+        }
+        inner.markets.insert(condition_id, market);
+    }
+
+    fn clear(&self) {
+        let mut inner = self.inner.write().unwrap_or_else(|e| e.into_inner());
+        inner.aliases.clear();
+        inner.markets.clear();
+    }
+}
+
+impl std::fmt::Debug for ResolverCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let markets = self
+            .inner
+            .read()
+            .map(|inner| inner.markets.len())
+            .unwrap_or_default();
+        f.debug_struct("ResolverCache")
+            .field("markets", &markets)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONDITION_ID: &str = "0xAbCdEf0000000000000000000000000000000000000000000000000000000001";
+    const TOKEN_YES: &str =
+        "71321045679252212594626385532706912750332728571942532289631379312455583992563";
+    const TOKEN_NO: &str =
+        "48331043336612883890938759509493159234755048973500640148014422747788308965732";
+
+    fn resolved() -> Arc<ResolvedMarket> {
+        let market: Market = serde_json::from_value(serde_json::json!({
+            "id": "253591",
+            "conditionId": CONDITION_ID,
+            "slug": "will-it-rain",
+            "description": "",
+            "question": "Will it rain?",
+            "marketMakerAddress": "",
+            "outcomes": "[\"Yes\", \"No\"]",
+            "clobTokenIds": format!("[\"{TOKEN_YES}\", \"{TOKEN_NO}\"]"),
+        }))
+        .unwrap();
+
+        Arc::new(ResolvedMarket {
+            id: market.id.clone(),
+            condition_id: market.condition_id.clone(),
+            question_id: None,
+            slug: market.slug.clone(),
+            question: market.question.clone(),
+            event: None,
+            outcomes: market.outcomes(),
+            tick_size: decimal_from_f64(0.01).unwrap(),
+            min_order_size: decimal_from_f64(5.0).unwrap(),
+            neg_risk: false,
+            neg_risk_market_id: None,
+            active: true,
+            closed: false,
+            market,
+        })
+    }
+
+    // ── MarketRef ───────────────────────────────────────────────
+
+    #[test]
+    fn test_parse_condition_id_lowercases() {
+        assert_eq!(
+            MarketRef::parse(CONDITION_ID).unwrap(),
+            MarketRef::ConditionId(CONDITION_ID.to_ascii_lowercase())
+        );
+    }
+
+    #[test]
+    fn test_parse_bad_hex_is_invalid() {
+        assert!(matches!(
+            MarketRef::parse("0x1234"),
+            Err(PolymarketError::InvalidMarketId(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_numeric_ids() {
+        assert_eq!(
+            MarketRef::parse(TOKEN_YES).unwrap(),
+            MarketRef::TokenId(TOKEN_YES.into())
+        );
+        assert_eq!(
+            MarketRef::parse("253591").unwrap(),
+            MarketRef::GammaId("253591".into())
+        );
+    }
+
+    #[test]
+    fn test_parse_slug() {
+        assert_eq!(
+            " will-it-rain ".parse::<MarketRef>().unwrap(),
+            MarketRef::Slug("will-it-rain".into())
+        );
+    }
+
+    #[test]
+    fn test_parse_empty_is_invalid() {
+        assert!(MarketRef::parse("  ").is_err());
+    }
+
+    #[test]
+    fn test_parse_event_urls() {
+        assert_eq!(
+            MarketRef::parse("https://polymarket.com/event/fed-decision/fed-cuts-25bps?tid=1")
+                .unwrap(),
+            MarketRef::Slug("fed-cuts-25bps".into())
+        );
+        assert_eq!(
+            MarketRef::parse("https://www.polymarket.com/event/fed-decision#comments").unwrap(),
+            MarketRef::EventSlug("fed-decision".into())
+        );
+        assert_eq!(
+            MarketRef::parse("polymarket.com/es/event/fed-decision/").unwrap(),
+            MarketRef::EventSlug("fed-decision".into())
+        );
+    }
+
+    #[test]
+    fn test_parse_market_url() {
+        assert_eq!(
+            MarketRef::parse("https://polymarket.com/market/will-it-rain").unwrap(),
+            MarketRef::Slug("will-it-rain".into())
+        );
+    }
+
+    #[test]
+    fn test_parse_unsupported_url() {
+        assert!(matches!(
+            MarketRef::parse("https://polymarket.com/leaderboard"),
+            Err(PolymarketError::InvalidMarketId(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_other_host_is_slug() {
+        // Not a polymarket.com URL, so it's treated as an opaque slug
+        assert!(matches!(
+            MarketRef::parse("polymarket.community"),
+            Ok(MarketRef::Slug(_))
+        ));
+    }
+
+    // ── ResolvedMarket ──────────────────────────────────────────
+
+    #[test]
+    fn test_token_lookup() {
+        let market = resolved();
+        assert_eq!(market.token_ids(), vec![TOKEN_YES, TOKEN_NO]);
+        assert_eq!(market.token_id("yes"), Some(TOKEN_YES));
+        assert_eq!(market.token_id("Maybe"), None);
+    }
+
+    #[test]
+    fn test_decimal_from_f64_is_exact() {
+        assert_eq!(decimal_from_f64(0.01).unwrap().to_string(), "0.01");
+        assert_eq!(decimal_from_f64(5.0).unwrap().to_string(), "5");
+        assert!(decimal_from_f64(f64::NAN).is_err());
+    }
+
+    // ── ResolverCache ───────────────────────────────────────────
+
+    #[test]
+    fn test_cache_aliases_every_identifier() {
+        let cache = ResolverCache::default();
+        let lookup = MarketRef::EventSlug("rain-event".into());
+        cache.insert(&lookup, resolved());
+
+        for id in [CONDITION_ID, TOKEN_YES, TOKEN_NO, "253591", "will-it-rain"] {
+            let market_ref = MarketRef::parse(id).unwrap();
+            assert!(cache.get(&market_ref).is_some(), "missing alias {id}");
+        }
+        assert!(cache.get(&lookup).is_some());
+        assert!(cache.get(&MarketRef::Slug("other".into())).is_none());
+    }
+
+    #[test]
+    fn test_cache_clear() {
+        let cache = ResolverCache::default();
+        cache.insert(&MarketRef::GammaId("253591".into()), resolved());
+        cache.clear();
+        assert!(cache.get(&MarketRef::GammaId("253591".into())).is_none());
+    }
+
+    #[test]
+    fn test_clones_share_cache() {
+        let resolver = MarketResolver::public().unwrap();
+        let clone = resolver.clone();
+        resolver
+            .cache
+            .insert(&MarketRef::GammaId("253591".into()), resolved());
+        assert!(clone
+            .cache
+            .get(&MarketRef::GammaId("253591".into()))
+            .is_some());
+    }
+}
@@ -0,0 +1,517 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use futures_util::stream::BoxStream;
+use futures_util::TryStreamExt;
+use polyoxide_data::{
+    api::trades::ListTrades,
+    types::{Trade, TradeFilterType},
+    DataApi, Paginate,
+};
+use polyoxide_gamma::{
+    types::{Market, PublicProfile},
+    Gamma,
+};
+
+use crate::poll::{poll_stream, FeedCursor, Poller};
+use crate::PolymarketError;
+
+/// Default delay between polls
+pub const DEFAULT_WATCH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Default number of trades fetched per page
+pub const DEFAULT_WATCH_LOOKBACK: u32 = 500;
+
+/// Default time market metadata and trader profiles are cached
+pub const DEFAULT_WATCH_CACHE_TTL: Duration = Duration::from_secs(60 * 60);
+
+type AlertSink = Arc<dyn Fn(&TradeAlert) + Send + Sync>;
+
+/// Minimum trade size that raises an alert
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TradeThreshold {
+    /// USDC notional, `size * price`
+    Cash(f64),
+    /// Number of shares
+    Tokens(f64),
+}
+
+impl TradeThreshold {
+    /// Whether `trade` meets the threshold
+    pub fn matches(&self, trade: &Trade) -> bool {
+        match *self {
+            Self::Cash(min) => trade.size * trade.price >= min,
+            Self::Tokens(min) => trade.size >= min,
+        }
+    }
+
+    fn filter(&self) -> (TradeFilterType, f64) {
+        match *self {
+            Self::Cash(min) => (TradeFilterType::Cash, min),
+            Self::Tokens(min) => (TradeFilterType::Tokens, min),
+        }
+    }
+}
+
+/// Markets or events a [`TradeWatcher`] is limited to
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Scope {
+    All,
+    Markets(Vec<String>),
+    Events(Vec<String>),
+}
+
+/// A new trade above the watcher's threshold
+#[derive(Debug, Clone)]
+pub struct TradeAlert {
+    /// The trade, as reported by the Data API
+    pub trade: Trade,
+    /// USDC notional of the trade, `size * price`
+    pub notional: f64,
+    /// Gamma metadata of the traded market, if it could be fetched
+    pub market: Option<Market>,
+    /// The trader's public profile, if they have one
+    pub profile: Option<PublicProfile>,
+}
+
+impl TradeAlert {
+    /// Name to show for the trader: profile name, then the name on the trade,
+    /// then the trade's pseudonym, then the wallet address
+    pub fn trader(&self) -> &str {
+        self.profile
+            .as_ref()
+            .and_then(PublicProfile::display_name)
+            .or(self.trade.name.as_deref().filter(|n| !n.is_empty()))
+            .or(self.trade.pseudonym.as_deref().filter(|p| !p.is_empty()))
+            .unwrap_or(&self.trade.proxy_wallet)
+    }
+}
+
+/// Polls the Data API trades feed and alerts on large trades.
+///
+/// Each poll fetches the trades above the threshold, optionally scoped to
+/// markets or events, paging back until it reaches the trades seen by the
+/// previous poll. Trades are deduplicated on a timestamp and transaction hash
+/// cursor, so every trade is reported once. The first poll only records a
+/// baseline from the newest page, so history is never reported.
+///
+/// Alerts are enriched with the market's Gamma metadata and the trader's
+/// public profile. Both are cached for [`TradeWatcher::cache_ttl`] (a wallet
+/// without a profile is cached as `None`); lookups that fail leave the field
+/// empty rather than dropping the alert, and are retried on the next alert.
+///
+/// # Example
+///
+/// ```no_run
+/// use futures_util::StreamExt;
+/// use polyoxide::{TradeThreshold, TradeWatcher};
+/// use polyoxide::polyoxide_data::DataApi;
+/// use polyoxide::polyoxide_gamma::Gamma;
+///
+/// # async fn example() -> Result<(), polyoxide::PolymarketError> {
+/// let watcher = TradeWatcher::new(DataApi::new()?, Gamma::new()?)
+///     .threshold(TradeThreshold::Cash(10_000.0))
+///     .on_alert(|alert| {
+///         println!("{} traded ${:.0} on {}", alert.trader(), alert.notional, alert.trade.title)
+///     });
+///
+/// let mut alerts = watcher.into_stream();
+/// while let Some(alert) = alerts.next().await {
+///     alert?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct TradeWatcher {
+    data: DataApi,
+    gamma: Gamma,
+    threshold: TradeThreshold,
+    scope: Scope,
+    interval: Duration,
+    lookback: u32,
+    enrich: bool,
+    on_alert: Option<AlertSink>,
+    cursor: Option<FeedCursor>,
+    cache_ttl: Duration,
+    markets: HashMap<String, (Instant, Market)>,
+    profiles: HashMap<String, (Instant, Option<PublicProfile>)>,
+}
+
+impl TradeWatcher {
+    /// Create a watcher alerting on trades of at least $1,000 in any market
+    pub fn new(data: DataApi, gamma: Gamma) -> Self {
+        Self {
+            data,
+            gamma,
+            threshold: TradeThreshold::Cash(1_000.0),
+            scope: Scope::All,
+            interval: DEFAULT_WATCH_INTERVAL,
+            lookback: DEFAULT_WATCH_LOOKBACK,
+            enrich: true,
+            on_alert: None,
+            cursor: None,
+            cache_ttl: DEFAULT_WATCH_CACHE_TTL,
+            markets: HashMap::new(),
+            profiles: HashMap::new(),
+        }
+    }
+
+    /// Set the minimum trade size (default: `TradeThreshold::Cash(1000.0)`)
+    pub fn threshold(mut self, threshold: TradeThreshold) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Only watch these market condition IDs
+    ///
+    /// Note: Mutually exclusive with `events`; the last call wins
+    pub fn markets(mut self, condition_ids: impl IntoIterator<Item = impl ToString>) -> Self {
+        self.scope = Scope::Markets(condition_ids.into_iter().map(|s| s.to_string()).collect());
+        self
+    }
+
+    /// Only watch the markets of these event IDs
+    ///
+    /// Note: Mutually exclusive with `markets`; the last call wins
+    pub fn events(mut self, event_ids: impl IntoIterator<Item = impl ToString>) -> Self {
+        self.scope = Scope::Events(event_ids.into_iter().map(|s| s.to_string()).collect());
+        self
+    }
+
+    /// Set the delay between polls
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Set how many trades are fetched per page
+    pub fn lookback(mut self, limit: u32) -> Self {
+        self.lookback = limit;
+        self
+    }
+
+    /// Fetch market metadata and trader profiles for alerts (default: true)
+    pub fn enrich(mut self, enrich: bool) -> Self {
+        self.enrich = enrich;
+        self
+    }
+
+    /// Set how long market metadata and trader profiles are cached
+    /// (default [`DEFAULT_WATCH_CACHE_TTL`])
+    pub fn cache_ttl(mut self, ttl: Duration) -> Self {
+        self.cache_ttl = ttl;
+        self
+    }
+
+    /// Call `sink` with every alert
+    pub fn on_alert(mut self, sink: impl Fn(&TradeAlert) + Send + Sync + 'static) -> Self {
+        self.on_alert = Some(Arc::new(sink));
+        self
+    }
+
+    /// Poll the trades feed once and return alerts for new trades, oldest first
+    pub async fn poll(&mut self) -> Result<Vec<TradeAlert>, PolymarketError> {
+        let (filter_type, filter_amount) = self.threshold.filter();
+        let mut request = self
+            .data
+            .trades()
+            .list()
+            .taker_only(true)
+            .filter_type(filter_type)
+            .filter_amount(filter_amount)
+            .limit(self.lookback);
+        request = match &self.scope {
+            Scope::All => request,
+            Scope::Markets(ids) => request.market(ids),
+            Scope::Events(ids) => request.event_id(ids),
+        };
+        // The baseline only needs the newest trades; after that, page back to
+        // the cursor so a burst larger than one page is not lost
+        let trades = match &self.cursor {
+            Some(cursor) => trades_since(request, cursor.timestamp).await?,
+            None => request.send().await?,
+        };
+
+        let baseline = self.cursor.is_none();
+        let fresh = self.cursor.get_or_insert_with(FeedCursor::default).advance(
+            trades,
+            |t| t.timestamp,
+            trade_key,
+        );
+        if baseline {
+            return Ok(Vec::new());
+        }
+
+        let fresh: Vec<Trade> = fresh
+            .into_iter()
+            .filter(|t| self.threshold.matches(t))
+            .collect();
+        if self.enrich {
+            self.lookup(&fresh).await;
+        }
+
+        let alerts: Vec<TradeAlert> = fresh
+            .into_iter()
+            .map(|trade| TradeAlert {
+                notional: trade.size * trade.price,
+                market: self
+                    .markets
+                    .get(&trade.condition_id)
+                    .map(|(_, market)| market.clone()),
+                profile: self
+                    .profiles
+                    .get(&trade.proxy_wallet)
+                    .and_then(|(_, profile)| profile.clone()),
+                trade,
+            })
+            .collect();
+        if let Some(sink) = &self.on_alert {
+            alerts.iter().for_each(|alert| sink(alert));
+        }
+        Ok(alerts)
+    }
+
+    /// Start polling and stream the alerts.
+    ///
+    /// Fetch errors are yielded as `Err` items; polling continues on the next tick.
+    pub fn into_stream(self) -> BoxStream<'static, Result<TradeAlert, PolymarketError>> {
+        poll_stream(self)
+    }
+
+    /// Fill the market and profile caches for `trades`, ignoring lookup errors
+    async fn lookup(&mut self, trades: &[Trade]) {
+        let now = Instant::now();
+        expire(&mut self.markets, now, self.cache_ttl);
+        expire(&mut self.profiles, now, self.cache_ttl);
+
+        let mut condition_ids: Vec<&str> = trades
+            .iter()
+            .map(|t| t.condition_id.as_str())
+            .filter(|id| !self.markets.contains_key(*id))
+            .collect();
+        condition_ids.sort_unstable();
+        condition_ids.dedup();
+
+        let markets = self.gamma.markets();
+        if let Ok(markets) = markets.by_condition_ids(&condition_ids, |list| list).await {
+            for market in markets {
+                self.markets
+                    .insert(market.condition_id.clone(), (now, market));
+            }
+        }
+
+        for trade in trades {
+            let wallet = &trade.proxy_wallet;
+            if self.profiles.contains_key(wallet) {
+                continue;
+            }
+            // Wallets without a profile answer 404; other errors are retried
+            match self.gamma.user().profile(wallet).send().await {
+                Ok(profile) => {
+                    self.profiles.insert(wallet.clone(), (now, Some(profile)));
+                }
+                Err(err) if err.is_not_found() => {
+                    self.profiles.insert(wallet.clone(), (now, None));
+                }
+                Err(_) => {}
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for TradeWatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TradeWatcher")
+            .field("threshold", &self.threshold)
+            .field("scope", &self.scope)
+            .field("interval", &self.interval)
+            .field("lookback", &self.lookback)
+            .field("enrich", &self.enrich)
+            .field("cache_ttl", &self.cache_ttl)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Poller for TradeWatcher {
+    type Item = TradeAlert;
+
+    fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    async fn tick(&mut self) -> Vec<Result<TradeAlert, PolymarketError>> {
+        match self.poll().await {
+            Ok(alerts) => alerts.into_iter().map(Ok).collect(),
+            Err(err) => vec![Err(err)],
+        }
+    }
+}
+
+/// Page through `request`, newest first, until a page reaches back before
+/// `timestamp`
+async fn trades_since(request: ListTrades, timestamp: i64) -> Result<Vec<Trade>, PolymarketError> {
+    let mut pages = request.paginate().pages();
+    let mut trades = Vec::new();
+    while let Some(page) = pages.try_next().await? {
+        let reached = page.last().is_none_or(|t| t.timestamp < timestamp);
+        trades.extend(page);
+        if reached {
+            break;
+        }
+    }
+    Ok(trades)
+}
+
+/// Drop cache entries fetched more than `ttl` before `now`
+fn expire<V>(cache: &mut HashMap<String, (Instant, V)>, now: Instant, ttl: Duration) {
+    cache.retain(|_, (fetched, _)| now.duration_since(*fetched) <= ttl);
+}
+
+/// Dedup key of a trade: its transaction hash, asset and wallet, since one
+/// transaction settles several makers against a taker
+fn trade_key(trade: &Trade) -> String {
+    let prefix = match trade.transaction_hash.as_deref() {
+        Some(hash) if !hash.is_empty() => hash.to_string(),
+        _ => format!("{}:{}:{}", trade.timestamp, trade.size, trade.price),
+    };
+    format!("{prefix}:{}:{}", trade.asset, trade.proxy_wallet)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trade(timestamp: i64, hash: &str, wallet: &str, size: f64, price: f64) -> Trade {
+        serde_json::from_value(serde_json::json!({
+            "proxyWallet": wallet,
+            "side": "BUY",
+            "asset": "111",
+            "conditionId": "0xabc",
+            "size": size,
+            "price": price,
+            "timestamp": timestamp,
+            "title": "Market",
+            "slug": "market",
+            "outcome": "Yes",
+            "outcomeIndex": 0,
+            "transactionHash": hash,
+        }))
+        .unwrap()
+    }
+
+    // ── Threshold ───────────────────────────────────────────────
+
+    #[test]
+    fn threshold_matches_cash_and_tokens() {
+        let trade = trade(1, "0x1", "0xa", 2_000.0, 0.6);
+        assert!(TradeThreshold::Cash(1_200.0).matches(&trade));
+        assert!(!TradeThreshold::Cash(1_201.0).matches(&trade));
+        assert!(TradeThreshold::Tokens(2_000.0).matches(&trade));
+        assert!(!TradeThreshold::Tokens(2_001.0).matches(&trade));
+    }
+
+    #[test]
+    fn threshold_query_filter() {
+        assert_eq!(
+            TradeThreshold::Tokens(5.0).filter(),
+            (TradeFilterType::Tokens, 5.0)
+        );
+        assert_eq!(
+            TradeThreshold::Cash(5.0).filter(),
+            (TradeFilterType::Cash, 5.0)
+        );
+    }
+
+    // ── Dedup ───────────────────────────────────────────────────
+
+    fn advance(cursor: &mut FeedCursor, trades: Vec<Trade>) -> Vec<Trade> {
+        cursor.advance(trades, |t| t.timestamp, trade_key)
+    }
+
+    #[test]
+    fn cursor_dedupes_across_polls() {
+        let mut cursor = FeedCursor::default();
+        let fresh = advance(
+            &mut cursor,
+            vec![
+                trade(2, "0x2", "0xa", 1.0, 0.5),
+                trade(1, "0x1", "0xa", 1.0, 0.5),
+            ],
+        );
+        assert_eq!(
+            fresh.iter().map(|t| t.timestamp).collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+
+        let fresh = advance(
+            &mut cursor,
+            vec![
+                trade(3, "0x3", "0xa", 1.0, 0.5),
+                trade(2, "0x2", "0xa", 1.0, 0.5),
+                trade(1, "0x1", "0xa", 1.0, 0.5),
+            ],
+        );
+        assert_eq!(fresh.len(), 1);
+        assert_eq!(fresh[0].timestamp, 3);
+    }
+
+    #[test]
+    fn cursor_keeps_wallets_in_one_transaction() {
+        let mut cursor = FeedCursor::default();
+        let fresh = advance(
+            &mut cursor,
+            vec![
+                trade(1, "0x1", "0xa", 1.0, 0.5),
+                trade(1, "0x1", "0xb", 1.0, 0.5),
+            ],
+        );
+        assert_eq!(fresh.len(), 2);
+        assert!(advance(&mut cursor, vec![trade(1, "0x1", "0xb", 1.0, 0.5)]).is_empty());
+    }
+
+    // ── Cache ───────────────────────────────────────────────────
+
+    #[test]
+    fn expired_cache_entries_are_dropped() {
+        let start = Instant::now();
+        let mut cache = HashMap::new();
+        cache.insert("old".to_string(), (start, 1));
+        cache.insert("new".to_string(), (start + Duration::from_secs(60), 2));
+
+        expire(
+            &mut cache,
+            start + Duration::from_secs(90),
+            Duration::from_secs(60),
+        );
+        assert_eq!(cache.keys().collect::<Vec<_>>(), ["new"]);
+    }
+
+    // ── Alerts ──────────────────────────────────────────────────
+
+    #[test]
+    fn alert_trader_prefers_profile_name() {
+        let mut alert = TradeAlert {
+            trade: trade(1, "0x1", "0xwallet", 1.0, 0.5),
+            notional: 0.5,
+            market: None,
+            profile: None,
+        };
+        assert_eq!(alert.trader(), "0xwallet");
+
+        alert.trade.pseudonym = Some("Quiet-Fox".into());
+        assert_eq!(alert.trader(), "Quiet-Fox");
+
+        alert.trade.name = Some("trader-name".into());
+        assert_eq!(alert.trader(), "trader-name");
+
+        alert.profile = Some(
+            serde_json::from_value(serde_json::json!({
+                "name": "whale",
+                "displayUsernamePublic": true,
+            }))
+            .unwrap(),
+        );
+        assert_eq!(alert.trader(), "whale");
+    }
+}